@@ -200,6 +200,19 @@ impl<'a> Children<'a> for VectorObject<'a> {
             }),
         );
 
+        // Offsets inside a nested flatbuffer are relative, so its root can be
+        // followed directly from the start of the vector data
+        if let Some(root) = self.nested_root {
+            callback(
+                Some(Cow::Borrowed("#root")),
+                Object::Offset(OffsetObject {
+                    offset: self.offset + 4,
+                    kind: crate::OffsetObjectKind::Table(root),
+                }),
+            );
+            return;
+        }
+
         for i in 0..self.len(buffer).unwrap_or(0) {
             if let Ok(Some(value)) = self.read(i, buffer) {
                 callback(Some(Cow::Owned(i.to_string())), value);
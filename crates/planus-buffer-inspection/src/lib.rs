@@ -220,6 +220,11 @@ pub enum OffsetObjectKind<'a> {
     VTable(DeclarationIndex),
     Table(DeclarationIndex),
     Vector(&'a Type),
+    /// A `[ubyte]` vector containing a nested flatbuffer with the given root table
+    NestedFlatbuffer {
+        type_: &'a Type,
+        root: DeclarationIndex,
+    },
     UnionVectorTags {
         declaration: DeclarationIndex,
     },
@@ -306,6 +311,8 @@ pub struct EnumObject {
 pub struct VectorObject<'a> {
     pub offset: ByteIndex,
     pub type_: &'a Type,
+    /// The root table of the nested flatbuffer stored in this vector, if any
+    pub nested_root: Option<DeclarationIndex>,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -383,7 +390,18 @@ impl<'a> OffsetObject<'a> {
                 offset,
                 declaration,
             })),
-            OffsetObjectKind::Vector(type_) => Ok(Object::Vector(VectorObject { offset, type_ })),
+            OffsetObjectKind::Vector(type_) => Ok(Object::Vector(VectorObject {
+                offset,
+                type_,
+                nested_root: None,
+            })),
+            OffsetObjectKind::NestedFlatbuffer { type_, root } => {
+                Ok(Object::Vector(VectorObject {
+                    offset,
+                    type_,
+                    nested_root: Some(root),
+                }))
+            }
             OffsetObjectKind::UnionVector {
                 declaration,
                 tags_offset,
@@ -419,6 +437,11 @@ impl<'a> OffsetObject<'a> {
             OffsetObjectKind::Vector(type_) => {
                 Cow::Owned(format!("&[{}]", declarations.format_type_kind(&type_.kind)))
             }
+            OffsetObjectKind::NestedFlatbuffer { type_, root } => Cow::Owned(format!(
+                "&[{}] (nested {})",
+                declarations.format_type_kind(&type_.kind),
+                declarations.get_declaration(root).0
+            )),
             OffsetObjectKind::UnionVector { declaration, .. } => Cow::Owned(format!(
                 "&[{}]",
                 declarations.get_declaration(declaration).0
@@ -551,7 +574,11 @@ impl TableObject {
             }
             TypeKind::Vector(ref type_) => Object::Offset(OffsetObject {
                 offset,
-                kind: OffsetObjectKind::Vector(type_),
+                kind: if let Some(root) = field_decl.nested_flatbuffer {
+                    OffsetObjectKind::NestedFlatbuffer { type_, root }
+                } else {
+                    OffsetObjectKind::Vector(type_)
+                },
             }),
             TypeKind::Array(ref _type_, _size) => todo!(),
             TypeKind::SimpleType(type_) => match type_ {
@@ -814,7 +841,15 @@ impl<'a> VectorObject<'a> {
     }
 
     fn type_name(&self, declarations: &Declarations) -> String {
-        format!("[{}]", declarations.format_type_kind(&self.type_.kind))
+        if let Some(root) = self.nested_root {
+            format!(
+                "[{}] (nested {})",
+                declarations.format_type_kind(&self.type_.kind),
+                declarations.get_declaration(root).0
+            )
+        } else {
+            format!("[{}]", declarations.format_type_kind(&self.type_.kind))
+        }
     }
 }
 
@@ -840,3 +875,109 @@ impl UnionVectorValuesObject {
         format!("[{}]", declarations.get_declaration(self.declaration).0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{children::Children, object_info::ObjectName};
+
+    fn declarations(schema: &str) -> Declarations {
+        let path = std::env::temp_dir().join(format!(
+            "planus-buffer-inspection-{}.fbs",
+            std::process::id()
+        ));
+        std::fs::write(&path, schema).unwrap();
+        let declarations = planus_translation::translate_files(&[&path]).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        declarations
+    }
+
+    fn children<'a>(
+        object: &Object<'a>,
+        buffer: &InspectableFlatbuffer<'a>,
+    ) -> Vec<(Option<String>, Object<'a>)> {
+        let mut children = Vec::new();
+        object.children(buffer, |name, child| {
+            children.push((name.map(|name| name.into_owned()), child))
+        });
+        children
+    }
+
+    #[test]
+    fn nested_flatbuffer() {
+        let declarations = declarations(
+            r#"
+            table Inner { id: uint; }
+            table Outer { payload: [ubyte] (nested_flatbuffer: "Inner"); }
+            root_type Outer;
+            "#,
+        );
+        let declaration = |name: &str| {
+            declarations
+                .iter_declarations()
+                .find(|(_, path, _)| path.0.last().unwrap() == name)
+                .unwrap()
+                .0
+        };
+        let outer = declaration("Outer");
+        let inner = declaration("Inner");
+
+        #[rustfmt::skip]
+        let data = [
+            // Root offset of the outer buffer
+            12, 0, 0, 0,
+            // Vtable of Outer
+            6, 0, 8, 0, 4, 0, 0, 0,
+            // Outer, with the offset to the payload vector
+            8, 0, 0, 0, 4, 0, 0, 0,
+            // The payload vector, containing a complete Inner buffer
+            20, 0, 0, 0,
+            12, 0, 0, 0,
+            6, 0, 8, 0, 4, 0, 0, 0,
+            8, 0, 0, 0, 5, 0, 0, 0,
+        ];
+        let buffer = InspectableFlatbuffer {
+            declarations: &declarations,
+            buffer: &data,
+        };
+
+        let table = TableObject {
+            offset: 12,
+            declaration: outer,
+        };
+        let Some(Object::Offset(payload)) = table.get_field(&buffer, 0).unwrap() else {
+            panic!("expected an offset to the payload");
+        };
+        assert!(matches!(
+            payload.kind,
+            OffsetObjectKind::NestedFlatbuffer { root, .. } if root == inner
+        ));
+
+        let vector = payload.follow_offset(&buffer).unwrap();
+        let Object::Vector(vector_object) = vector else {
+            panic!("expected the payload vector");
+        };
+        assert_eq!(vector_object.nested_root, Some(inner));
+        assert_eq!(
+            vector_object.print_object(&buffer),
+            "[ubyte; 20] (nested Inner)"
+        );
+
+        // The children of the vector are its length and the nested root
+        let children = children(&vector, &buffer);
+        let (name, Object::Offset(root)) = children.last().unwrap() else {
+            panic!("expected an offset to the nested root");
+        };
+        assert_eq!(name.as_deref(), Some("#root"));
+        assert_eq!(root.kind, OffsetObjectKind::Table(inner));
+
+        let Object::Table(nested) = root.follow_offset(&buffer).unwrap() else {
+            panic!("expected the nested root table");
+        };
+        assert_eq!(nested.offset, 36);
+        let Some(Object::Integer(id)) = nested.get_field(&buffer, 0).unwrap() else {
+            panic!("expected the id field");
+        };
+        assert_eq!(id.read(&buffer).unwrap(), IntegerLiteral::U32(5));
+    }
+}
@@ -176,6 +176,12 @@ impl<'a> ObjectName<'a> for VectorObject<'a> {
             "?".to_string()
         };
 
+        if let Some(root) = self.nested_root {
+            let (path, _) = buffer.declarations.get_declaration(root);
+            let path = path.0.last().unwrap();
+            return format!("[ubyte; {len}] (nested {path})");
+        }
+
         if let TypeKind::Table(declaration_index)
         | TypeKind::Union(declaration_index)
         | TypeKind::SimpleType(SimpleType::Enum(declaration_index))
@@ -2,7 +2,7 @@ use std::{io::Write, path::PathBuf, process::ExitCode};
 
 use clap::{Parser, ValueHint};
 use color_eyre::Result;
-use planus_codegen::generate_rust;
+use planus_codegen::{generate_rust_with_options, RustOptions};
 use planus_translation::translate_files_with_options;
 
 /// Generate rust code
@@ -19,6 +19,21 @@ pub struct Command {
     /// Run rustfmt on the generated code
     #[clap(long, default_value_t = true, action = clap::ArgAction::Set)]
     format: bool,
+
+    /// Add an `Unknown` variant to generated enums, so values added in newer
+    /// versions of the schema can be read and written back unchanged
+    #[clap(long)]
+    open_enums: bool,
+
+    /// Add an `Unknown` variant to generated unions, so values of types added
+    /// in newer versions of the schema can be read and written back unchanged
+    #[clap(long)]
+    open_unions: bool,
+
+    /// Only generate code for reading buffers, which can be used with the
+    /// `planus` crate compiled without the `alloc` feature
+    #[clap(long)]
+    no_alloc: bool,
 }
 
 impl Command {
@@ -29,7 +44,15 @@ impl Command {
             return Ok(ExitCode::FAILURE);
         };
 
-        let res = generate_rust(&declarations, self.format)?;
+        let res = generate_rust_with_options(
+            &declarations,
+            self.format,
+            RustOptions {
+                open_enums: self.open_enums,
+                open_unions: self.open_unions,
+                no_alloc: self.no_alloc,
+            },
+        )?;
         let mut file = std::fs::File::create(&self.output_filename)?;
         file.write_all(res.as_bytes())?;
         file.flush()?;
@@ -294,6 +294,7 @@ pub trait Backend {
         decl: &Union,
     ) -> Self::UnionInfo;
 
+    #[allow(clippy::too_many_arguments)]
     fn generate_table_field(
         &mut self,
         translation_context: &mut DeclarationTranslationContext<'_, '_, Self>,
@@ -302,6 +303,7 @@ pub trait Backend {
         field_name: &str,
         field: &TableField,
         resolved_type: ResolvedType<'_, Self>,
+        nested_flatbuffer: Option<ResolvedType<'_, Self>>,
     ) -> Self::TableFieldInfo;
 
     fn generate_struct_field(
@@ -157,6 +157,15 @@ impl<F> BackendTableFields<F> {
                     &field.type_,
                     &decl_path.clone_pop(),
                 );
+                let nested_flatbuffer = field.nested_flatbuffer.map(|index| {
+                    translate_type_index(
+                        translation_context,
+                        declarations,
+                        full_translated_decls,
+                        index,
+                        &decl_path.clone_pop(),
+                    )
+                });
                 (
                     index,
                     (
@@ -171,6 +180,7 @@ impl<F> BackendTableFields<F> {
                             field_name,
                             field,
                             translated_type,
+                            nested_flatbuffer,
                         ),
                     ),
                 )
@@ -219,6 +219,7 @@ impl Backend for DotBackend {
         field_name: &str,
         field: &intermediate::TableField,
         resolved_type: ResolvedType<'_, Self>,
+        _nested_flatbuffer: Option<ResolvedType<'_, Self>>,
     ) -> TableField {
         let (type_, type_ref) = get_name(&resolved_type);
 
@@ -19,21 +19,52 @@ mod dot;
 mod rust;
 mod templates;
 
+/// Options for [`generate_rust_with_options`].
+#[derive(Copy, Clone, Default, Debug)]
+pub struct RustOptions {
+    /// Add an `Unknown` variant to all enums, which holds values not known by
+    /// the schema instead of failing to read them.
+    pub open_enums: bool,
+    /// Add an `Unknown` variant to all unions, which holds values with tags
+    /// not known by the schema instead of failing to read them.
+    pub open_unions: bool,
+    /// Only generate the code for reading buffers, leaving out the owned
+    /// types of tables and unions as well as everything used for
+    /// serialization. The generated code can then be used with `planus`
+    /// compiled without the `alloc` feature.
+    pub no_alloc: bool,
+}
+
 pub fn generate_rust(declarations: &Declarations, format: bool) -> eyre::Result<String> {
+    generate_rust_with_options(declarations, format, RustOptions::default())
+}
+
+pub fn generate_rust_with_options(
+    declarations: &Declarations,
+    format: bool,
+    options: RustOptions,
+) -> eyre::Result<String> {
     let default_analysis = run_analysis(declarations, &mut rust::analysis::DefaultAnalysis);
     let eq_analysis = run_analysis(declarations, &mut rust::analysis::EqAnalysis);
     let infallible_analysis = run_analysis(
         declarations,
         &mut rust::analysis::InfallibleConversionAnalysis,
     );
-    let output = run_backend(
+    let zero_copy_analysis = run_analysis(declarations, &mut rust::analysis::ZeroCopyAnalysis);
+    let mut output = run_backend(
         &mut RustBackend {
             default_analysis,
             eq_analysis,
             infallible_analysis,
+            zero_copy_analysis,
+            root_types: declarations.root_types.clone(),
+            open_enums: options.open_enums,
+            open_unions: options.open_unions,
+            alloc: !options.no_alloc,
         },
         declarations,
     );
+    rust::add_any_root(&mut output, !options.no_alloc);
     let res = templates::rust::Namespace(&output).render().unwrap();
     if format {
         let res = rust::format_string(&res, Some(1_000_000))?;
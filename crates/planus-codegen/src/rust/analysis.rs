@@ -266,3 +266,48 @@ impl DeclarationAnalysis for InfallibleConversionAnalysis {
         }
     }
 }
+
+/// Finds the structs whose in-memory layout can match their serialized
+/// layout, which is the case when they only contain integers, floats and
+/// other such structs.
+pub struct ZeroCopyAnalysis;
+impl DeclarationAnalysis for ZeroCopyAnalysis {
+    type State = bool;
+
+    fn new_state(
+        &mut self,
+        _declarations: &Declarations,
+        _decl_id: DeclarationIndex,
+        declaration: &Declaration,
+    ) -> Self::State {
+        matches!(declaration.kind, DeclarationKind::Struct(_))
+    }
+
+    fn update_state(
+        &mut self,
+        _declarations: &Declarations,
+        decl_id: DeclarationIndex,
+        declaration: &Declaration,
+        zero_copy: &mut [Self::State],
+    ) -> WasChanged {
+        if !zero_copy[decl_id.0] {
+            return WasChanged::NoChange;
+        }
+
+        let DeclarationKind::Struct(decl) = &declaration.kind else {
+            return WasChanged::NoChange;
+        };
+        let cur_zero_copy = decl.fields.values().all(|field| match &field.type_ {
+            SimpleType::Struct(decl_id) => zero_copy[decl_id.0],
+            SimpleType::Integer(_) | SimpleType::Float(_) => true,
+            SimpleType::Bool | SimpleType::Enum(_) => false,
+        });
+
+        if cur_zero_copy != zero_copy[decl_id.0] {
+            zero_copy[decl_id.0] = cur_zero_copy;
+            WasChanged::Changed
+        } else {
+            WasChanged::NoChange
+        }
+    }
+}
@@ -2,6 +2,7 @@ pub mod analysis;
 
 use std::{
     borrow::Cow,
+    collections::BTreeMap,
     io::Write,
     process::{Command, Stdio},
 };
@@ -13,9 +14,12 @@ use planus_types::{
     intermediate::{self, AbsolutePath, AssignMode, DeclarationIndex, Literal, TableFieldTagKind},
 };
 
-use super::backend::{
-    Backend, DeclarationNames, DeclarationTranslationContext, NamespaceNames, RelativeNamespace,
-    ResolvedType,
+use super::{
+    backend::{
+        Backend, DeclarationNames, DeclarationTranslationContext, NamespaceNames,
+        RelativeNamespace, ResolvedType,
+    },
+    backend_translation::{BackendDeclaration, BackendNamespace},
 };
 
 #[derive(Debug, Clone)]
@@ -23,20 +27,55 @@ pub struct RustBackend {
     pub default_analysis: Vec<bool>,
     pub eq_analysis: Vec<bool>,
     pub infallible_analysis: Vec<bool>,
+    pub zero_copy_analysis: Vec<bool>,
+    pub root_types: Vec<intermediate::RootType>,
+    pub open_enums: bool,
+    pub open_unions: bool,
+    pub alloc: bool,
 }
 
 #[derive(Clone, Debug)]
 pub struct Namespace {
     pub name: String,
+    pub any_root: Option<AnyRoot>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AnyRoot {
+    pub name: String,
+    pub variants: Vec<AnyRootVariant>,
+    /// Whether the owned types are generated, otherwise the file identifiers
+    /// are found on the `Ref` types
+    pub alloc: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct AnyRootVariant {
+    pub name: String,
+    pub owned_path: String,
+    pub ref_path: String,
 }
 
 #[derive(Clone, Debug)]
 pub struct Table {
     pub owned_name: String,
     pub ref_name: String,
+    pub mut_name: String,
     pub builder_name: String,
     pub should_do_default: bool,
     pub should_do_eq: bool,
+    pub root_type: Option<RootType>,
+    /// Whether the owned type and the builder are generated
+    pub alloc: bool,
+    /// The getter names of the fields, reserved before any derived accessor
+    /// names so that those never rename a real field
+    pub field_names: BTreeMap<String, String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RootType {
+    pub file_identifier: Option<String>,
+    pub file_extension: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -56,7 +95,25 @@ pub struct TableField {
     pub serialize_default: Option<Cow<'static, str>>,
     pub deserialize_default: Option<Cow<'static, str>>,
     pub try_from_code: String,
+    pub try_from_budgeted_code: String,
     pub is_copy: bool,
+    pub key_type: Option<String>,
+    pub nested_flatbuffer: Option<NestedFlatbuffer>,
+    pub flexbuffer_name: Option<String>,
+    /// The name of the setter on the `Mut` type, for fields that can be
+    /// changed in place
+    pub setter_name: Option<String>,
+    /// The name of the getter that skips error checking on verified buffers
+    pub unchecked_name: String,
+    pub access_method: &'static str,
+    pub offset64: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct NestedFlatbuffer {
+    pub name: String,
+    pub owned_type: String,
+    pub ref_type: String,
 }
 
 #[derive(Clone, Debug)]
@@ -66,6 +123,11 @@ pub struct Struct {
     pub should_do_default: bool,
     pub should_do_eq: bool,
     pub should_do_infallible_conversion: bool,
+    /// Whether the struct has the same layout in memory as in the buffer,
+    /// so vectors of it can be viewed as slices
+    pub zero_copy: bool,
+    /// Whether the code for serializing the struct is generated
+    pub alloc: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -74,13 +136,23 @@ pub struct StructField {
     pub owned_type: String,
     pub getter_return_type: String,
     pub getter_code: String,
+    /// Whether the getter returns a `Result`, which is the case for enums
+    pub getter_is_fallible: bool,
+    /// Whether the getter returns the `Ref` type of a nested struct
+    pub getter_is_struct: bool,
     pub can_do_infallible_conversion: bool,
+    pub key_type: Option<String>,
 }
 
 #[derive(Clone, Debug)]
 pub struct Enum {
     pub name: String,
     pub repr_type: String,
+    /// Whether the enum has a variant holding unknown values
+    pub open: bool,
+    pub unknown_name: String,
+    /// Whether the code for serializing the enum is generated
+    pub alloc: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -97,6 +169,10 @@ pub struct Union {
     pub ref_name_with_lifetime: String,
     pub should_do_eq: bool,
     pub should_do_infallible_conversion: bool,
+    pub open: bool,
+    pub unknown_name: String,
+    /// Whether the owned type and the builder are generated
+    pub alloc: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -285,7 +361,10 @@ impl Backend for RustBackend {
         let name = namespace_name.0.last().map_or_else(String::new, |name| {
             reserve_module_name(name, namespace_names)
         });
-        Namespace { name }
+        Namespace {
+            name,
+            any_root: None,
+        }
     }
 
     fn generate_table(
@@ -294,15 +373,48 @@ impl Backend for RustBackend {
         _translated_namespaces: &[Self::NamespaceInfo],
         decl_id: DeclarationIndex,
         decl_name: &AbsolutePath,
-        _decl: &intermediate::Table,
+        decl: &intermediate::Table,
     ) -> Table {
         let decl_name = decl_name.0.last().unwrap();
+        let root_type = self
+            .root_types
+            .iter()
+            .find(|root_type| root_type.declaration == decl_id)
+            .map(|root_type| {
+                // Make sure no field getter collides with the helper on the ref type
+                declaration_names
+                    .declaration_names
+                    .try_reserve("name", "read_root");
+                RootType {
+                    file_identifier: root_type
+                        .file_identifier
+                        .map(|file_identifier| format!("*b\"{}\"", file_identifier.escape_ascii())),
+                    file_extension: root_type
+                        .file_extension
+                        .as_ref()
+                        .map(|file_extension| format!("{file_extension:?}")),
+                }
+            });
+        let field_names = decl
+            .fields
+            .keys()
+            .map(|field_name| {
+                (
+                    field_name.clone(),
+                    reserve_field_name(field_name, "name", declaration_names),
+                )
+            })
+            .collect();
         Table {
             owned_name: reserve_type_name(decl_name, declaration_names),
             ref_name: reserve_type_name(&format!("{decl_name}Ref"), declaration_names),
+            mut_name: reserve_type_name(&format!("{decl_name}Mut"), declaration_names),
             builder_name: reserve_type_name(&format!("{decl_name}Builder"), declaration_names),
             should_do_default: self.default_analysis[decl_id.0],
             should_do_eq: self.eq_analysis[decl_id.0],
+            root_type,
+            alloc: self.alloc,
+            field_names,
         }
     }
 
@@ -321,6 +433,8 @@ impl Backend for RustBackend {
             should_do_default: self.default_analysis[decl_id.0],
             should_do_eq: self.eq_analysis[decl_id.0],
             should_do_infallible_conversion: self.infallible_analysis[decl_id.0],
+            zero_copy: self.zero_copy_analysis[decl_id.0],
+            alloc: self.alloc,
         }
     }
 
@@ -333,9 +447,20 @@ impl Backend for RustBackend {
         decl: &intermediate::Enum,
     ) -> Enum {
         let decl_name = decl_name.0.last().unwrap();
+        let mut unknown_name = "Unknown".to_string();
+        while decl
+            .variants
+            .values()
+            .any(|variant| variant.name.to_upper_camel_case() == unknown_name)
+        {
+            unknown_name.push('_');
+        }
         Enum {
             name: reserve_type_name(decl_name, declaration_names),
             repr_type: format!("{:?}", decl.type_).to_lowercase(),
+            open: self.open_enums,
+            unknown_name,
+            alloc: self.alloc,
         }
     }
 
@@ -350,10 +475,18 @@ impl Backend for RustBackend {
         let decl_name = decl_name.0.last().unwrap();
         let ref_name = reserve_type_name(&format!("{decl_name}Ref"), declaration_names);
         let builder_name = reserve_type_name(&format!("{decl_name}Builder"), declaration_names);
+        let mut unknown_name = "Unknown".to_string();
+        while decl
+            .variants
+            .keys()
+            .any(|key| key.to_upper_camel_case() == unknown_name)
+        {
+            unknown_name.push('_');
+        }
         Union {
             owned_name: reserve_type_name(decl_name, declaration_names),
             builder_name,
-            ref_name_with_lifetime: if decl.variants.is_empty() {
+            ref_name_with_lifetime: if decl.variants.is_empty() && !self.open_unions {
                 ref_name.clone()
             } else {
                 format!("{ref_name}<'a>")
@@ -361,20 +494,27 @@ impl Backend for RustBackend {
             ref_name,
             should_do_eq: self.eq_analysis[decl_id.0],
             should_do_infallible_conversion: self.infallible_analysis[decl_id.0],
+            open: self.open_unions,
+            unknown_name,
+            alloc: self.alloc,
         }
     }
 
     fn generate_table_field(
         &mut self,
         translation_context: &mut DeclarationTranslationContext<'_, '_, Self>,
-        _parent_info: &Self::TableInfo,
+        parent_info: &Self::TableInfo,
         _parent: &intermediate::Table,
         field_name: &str,
         field: &intermediate::TableField,
         resolved_type: ResolvedType<'_, Self>,
+        nested_flatbuffer: Option<ResolvedType<'_, Self>>,
     ) -> TableField {
-        let name = reserve_field_name(
-            field_name,
+        let name = parent_info.field_names[field_name].clone();
+        // The names of all fields were reserved in `generate_table`, so the
+        // extra getters are renamed instead of the fields they collide with
+        let unchecked_name = reserve_field_name(
+            &format!("{field_name}_unchecked"),
             "name",
             &mut translation_context.declaration_names,
         );
@@ -396,6 +536,8 @@ impl Backend for RustBackend {
         let mut serialize_default: Option<Cow<'static, str>> = None;
         let mut deserialize_default: Option<Cow<'static, str>> = None;
         let mut impl_default_code: Cow<'static, str> = "::core::default::Default::default()".into();
+        let mut try_from_budgeted_code =
+            format!("::planus::TryFromBudgeted::try_from_budgeted(value.{name}()?, budget)?");
         let mut try_from_code = if matches!(field.assign_mode, AssignMode::Optional) {
             format!(
                 r#"
@@ -481,6 +623,7 @@ impl Backend for RustBackend {
                         try_from_code = format!(
                             "::planus::alloc::boxed::Box::new(::core::convert::TryInto::try_into(value.{name}()?)?)"
                         );
+                        try_from_budgeted_code = format!("budget.boxed(value.{name}()?)?");
                     }
                     AssignMode::Optional => {
                         read_type = format!(
@@ -500,6 +643,9 @@ impl Backend for RustBackend {
                                 }}
                             "#
                         );
+                        try_from_budgeted_code = format!(
+                            "value.{name}()?.map(|value| budget.boxed(value)).transpose()?"
+                        );
                     }
                     AssignMode::HasDefault(..) => unreachable!(),
                 }
@@ -659,19 +805,10 @@ impl Backend for RustBackend {
                             "::planus::Vector<'a, ::core::result::Result<{}, ::planus::errors::UnknownEnumTag>>",
                             format_relative_namespace(relative_namespace, &info.name)
                         ),
-                        ResolvedType::Union(_, union, info, relative_namespace) => {
-                            if union.variants.is_empty() {
-                                format!(
-                                    "::planus::UnionVector<'a, {}>",
-                                    format_relative_namespace(relative_namespace, &info.ref_name)
-                                )
-                            } else {
-                                format!(
-                                    "::planus::UnionVector<'a, {}<'a>>",
-                                    format_relative_namespace(relative_namespace, &info.ref_name)
-                                )
-                            }
-                        },
+                        ResolvedType::Union(_, _, info, relative_namespace) => format!(
+                            "::planus::UnionVector<'a, {}>",
+                            format_relative_namespace(relative_namespace, &info.ref_name_with_lifetime)
+                        ),
                         ResolvedType::Vector(_) => {
                             unreachable!("This should have been rejected in type-check")
                         }
@@ -701,8 +838,16 @@ impl Backend for RustBackend {
                 let read_name = vector_read_type(&type_);
                 let owned_name = vector_owned_type(&type_);
                 is_copy = false;
-                primitive_size = 4;
-                vtable_type = format!("::planus::Offset<[{offset_name}]>");
+                if field.vector64 {
+                    primitive_size = 8;
+                    vtable_type = format!("::planus::Vector64Offset<{offset_name}>");
+                } else if field.offset64 {
+                    primitive_size = 8;
+                    vtable_type = format!("::planus::Offset64<[{offset_name}]>");
+                } else {
+                    primitive_size = 4;
+                    vtable_type = format!("::planus::Offset<[{offset_name}]>");
+                }
 
                 let is_byte_slice = matches!(
                     &*type_,
@@ -773,13 +918,19 @@ impl Backend for RustBackend {
             ResolvedType::Array(_, _) => todo!(),
             ResolvedType::String => {
                 is_copy = false;
-                primitive_size = 4;
-                vtable_type = "::planus::Offset<str>".to_string();
+                let offset_type = if field.offset64 {
+                    primitive_size = 8;
+                    "Offset64"
+                } else {
+                    primitive_size = 4;
+                    "Offset"
+                };
+                vtable_type = format!("::planus::{offset_type}<str>");
                 match &field.assign_mode {
                     AssignMode::Required => {
                         read_type = "&'a ::core::primitive::str".to_string();
                         owned_type = "::planus::alloc::string::String".to_string();
-                        create_trait = "WriteAs<::planus::Offset<str>>".to_string();
+                        create_trait = format!("WriteAs<::planus::{offset_type}<str>>");
                         try_from_code = format!("::core::convert::Into::into(value.{name}()?)");
                     }
                     AssignMode::Optional => {
@@ -787,8 +938,9 @@ impl Backend for RustBackend {
                             "::core::option::Option<&'a ::core::primitive::str>".to_string();
                         owned_type =
                             "::core::option::Option<::planus::alloc::string::String>".to_string();
-                        create_trait =
-                            "WriteAsOptional<::planus::Offset<::core::primitive::str>>".to_string();
+                        create_trait = format!(
+                            "WriteAsOptional<::planus::{offset_type}<::core::primitive::str>>"
+                        );
                         try_from_code = format!(
                             r#"
                                 value.{name}()?.map(::core::convert::Into::into)
@@ -798,9 +950,9 @@ impl Backend for RustBackend {
                     AssignMode::HasDefault(Literal::String(s)) => {
                         read_type = "&'a ::core::primitive::str".to_string();
                         owned_type = "::planus::alloc::string::String".to_string();
-                        create_trait =
-                            "WriteAsDefault<::planus::Offset<::core::primitive::str>, ::core::primitive::str>"
-                                .to_string();
+                        create_trait = format!(
+                            "WriteAsDefault<::planus::{offset_type}<::core::primitive::str>, ::core::primitive::str>"
+                        );
 
                         impl_default_code = format!("::core::convert::Into::into({s:?})").into();
                         serialize_default = Some(format!("{s:?}").into());
@@ -877,6 +1029,47 @@ impl Backend for RustBackend {
                 }
             }
         }
+        let key_type = field.key.then(|| {
+            if matches!(field.type_.kind, intermediate::TypeKind::String) {
+                "str".to_string()
+            } else {
+                owned_type.clone()
+            }
+        });
+        let nested_flatbuffer = nested_flatbuffer.map(|nested_flatbuffer| {
+            let ResolvedType::Table(_, _, info, relative_namespace) = nested_flatbuffer else {
+                unreachable!("This should have been rejected in type-check")
+            };
+            let owned_type =
+                format_relative_namespace(&relative_namespace, &info.owned_name).to_string();
+            let ref_type =
+                format_relative_namespace(&relative_namespace, &info.ref_name).to_string();
+            NestedFlatbuffer {
+                name: reserve_field_name(
+                    &format!("{field_name}_nested"),
+                    "name",
+                    &mut translation_context.declaration_names,
+                ),
+                owned_type,
+                ref_type,
+            }
+        });
+        let flexbuffer_name = field.flexbuffer.then(|| {
+            reserve_field_name(
+                &format!("{field_name}_flexbuffer"),
+                "name",
+                &mut translation_context.declaration_names,
+            )
+        });
+        // Scalars, enums and structs are stored inline in the table, so they
+        // can be overwritten without changing the layout of the buffer
+        let setter_name = is_copy.then(|| {
+            reserve_field_name(
+                &format!("set_{field_name}"),
+                "setter_name",
+                &mut translation_context.declaration_names,
+            )
+        });
         TableField {
             name,
             name_with_as,
@@ -893,7 +1086,21 @@ impl Backend for RustBackend {
             serialize_default,
             deserialize_default,
             try_from_code,
+            try_from_budgeted_code,
             is_copy,
+            key_type,
+            nested_flatbuffer,
+            flexbuffer_name,
+            setter_name,
+            unchecked_name,
+            access_method: if field.vector64 {
+                "access_vector64"
+            } else if field.offset64 {
+                "access_offset64"
+            } else {
+                "access"
+            },
+            offset64: field.offset64,
         }
     }
 
@@ -903,7 +1110,7 @@ impl Backend for RustBackend {
         parent_info: &Self::StructInfo,
         _parent: &intermediate::Struct,
         field_name: &str,
-        _field: &intermediate::StructField,
+        field: &intermediate::StructField,
         resolved_type: ResolvedType<'_, Self>,
     ) -> StructField {
         let name = reserve_field_name(
@@ -911,6 +1118,8 @@ impl Backend for RustBackend {
             "name",
             &mut translation_context.declaration_names,
         );
+        let getter_is_fallible = matches!(resolved_type, ResolvedType::Enum(..));
+        let getter_is_struct = matches!(resolved_type, ResolvedType::Struct(..));
         let StructFieldType {
             owned_type,
             getter_return_type,
@@ -919,9 +1128,12 @@ impl Backend for RustBackend {
         } = self.struct_field_type(resolved_type, parent_info, &name);
         StructField {
             name,
+            key_type: field.key.then(|| owned_type.clone()),
             owned_type,
             getter_return_type,
             getter_code,
+            getter_is_fallible,
+            getter_is_struct,
             can_do_infallible_conversion,
         }
     }
@@ -1045,6 +1257,86 @@ fn float_type(type_: &FloatType) -> &'static str {
     }
 }
 
+/// Adds an `AnyRoot` enum to the root namespace covering every root type
+/// that has a file identifier.
+pub fn add_any_root(root: &mut BackendNamespace<RustBackend>, alloc: bool) {
+    fn collect_variants<'a>(
+        namespace: &'a BackendNamespace<RustBackend>,
+        path: &str,
+        variants: &mut Vec<AnyRootVariant>,
+        file_identifiers: &mut Vec<&'a str>,
+    ) {
+        for declaration in &namespace.declarations {
+            if let BackendDeclaration::Table(table) = declaration {
+                if let Some(RootType {
+                    file_identifier: Some(file_identifier),
+                    ..
+                }) = &table.info.root_type
+                {
+                    file_identifiers.push(file_identifier);
+                    let mut name = table.info.owned_name.clone();
+                    while variants.iter().any(|variant| variant.name == name) {
+                        name.push('_');
+                    }
+                    variants.push(AnyRootVariant {
+                        name,
+                        owned_path: format!("{path}{}", table.info.owned_name),
+                        ref_path: format!("{path}{}", table.info.ref_name),
+                    });
+                }
+            }
+        }
+        for child in &namespace.children {
+            collect_variants(
+                child,
+                &format!("{path}{}::", child.info.name),
+                variants,
+                file_identifiers,
+            );
+        }
+    }
+
+    let mut variants = Vec::new();
+    let mut file_identifiers = Vec::new();
+    collect_variants(root, "self::", &mut variants, &mut file_identifiers);
+    if variants.is_empty() {
+        return;
+    }
+    // Buffers can only be told apart if every root type has its own file
+    // identifier, so the dispatcher is left out for schemas reusing one
+    file_identifiers.sort_unstable();
+    if file_identifiers.windows(2).any(|pair| pair[0] == pair[1]) {
+        return;
+    }
+
+    let mut name = "AnyRoot".to_string();
+    while root.declarations.iter().any(|declaration| {
+        let names: &[&str] = match declaration {
+            BackendDeclaration::Table(inner) => &[
+                &inner.info.owned_name,
+                &inner.info.ref_name,
+                &inner.info.builder_name,
+            ],
+            BackendDeclaration::Struct(inner) => &[&inner.info.owned_name, &inner.info.ref_name],
+            BackendDeclaration::Enum(inner) => &[&inner.info.name],
+            BackendDeclaration::Union(inner) => &[
+                &inner.info.owned_name,
+                &inner.info.ref_name,
+                &inner.info.builder_name,
+            ],
+            BackendDeclaration::RpcService(_) => &[],
+        };
+        names.contains(&name.as_str())
+    }) {
+        name.push('_');
+    }
+    root.info.any_root = Some(AnyRoot {
+        name,
+        variants,
+        alloc,
+    });
+}
+
 pub fn format_string(s: &str, max_width: Option<u64>) -> eyre::Result<String> {
     // Probe rustfmt up front so a missing binary yields a clear error.
     let version_status = Command::new("rustfmt")
@@ -440,10 +440,18 @@ impl CstConverter<'_> {
                 self.handle_invalid_docstrings(&decl.semicolon.token_metadata);
 
                 self.check_file_identifier();
+                let file_identifier = self.convert_string_literal(&decl.file_identifier);
+                if file_identifier.value.len() != 4 {
+                    self.emit_error(
+                        ErrorKind::MISC_SEMANTIC_ERROR,
+                        [Label::primary(self.schema.file_id, file_identifier.span)],
+                        Some("The file_identifier must be exactly 4 bytes"),
+                    );
+                }
                 if self.schema.file_identifier.is_none() {
                     self.schema.file_identifier = Some((
                         decl.keyword.span.merge(decl.semicolon.span),
-                        self.convert_string_literal(&decl.file_identifier),
+                        file_identifier,
                     ));
                 }
                 None
@@ -1171,6 +1179,8 @@ impl CstConverter<'_> {
                     CppStrFlexCtor => MetadataValueKind::CppStrFlexCtor,
                     NativeInline => MetadataValueKind::NativeInline,
                     Flexbuffer => MetadataValueKind::Flexbuffer,
+                    Offset64 => MetadataValueKind::Offset64,
+                    Vector64 => MetadataValueKind::Vector64,
                     Idempotent => MetadataValueKind::Idempotent,
                     _ => return bail(),
                 },
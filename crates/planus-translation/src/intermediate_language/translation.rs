@@ -22,6 +22,16 @@ pub struct Translator<'a> {
     declarations: IndexMap<AbsolutePath, Declaration>,
     namespaces: IndexMap<AbsolutePath, Namespace>,
     descriptions: Vec<TypeDescription>,
+    ast_root_types: Vec<AstRootType>,
+}
+
+struct AstRootType {
+    file_id: FileId,
+    namespace: AbsolutePath,
+    span: Span,
+    type_: ast::Type,
+    file_identifier: Option<[u8; 4]>,
+    file_extension: Option<String>,
 }
 
 #[derive(Clone)]
@@ -60,6 +70,7 @@ impl<'a> Translator<'a> {
             declarations: Default::default(),
             descriptions: Default::default(),
             namespaces: Default::default(),
+            ast_root_types: Default::default(),
         }
     }
 
@@ -130,6 +141,23 @@ impl<'a> Translator<'a> {
             })
         }
 
+        if let Some((span, type_)) = &schema.root_type {
+            self.ast_root_types.push(AstRootType {
+                file_id: schema.file_id,
+                namespace: namespace_path.clone(),
+                span: *span,
+                type_: type_.clone(),
+                file_identifier: schema
+                    .file_identifier
+                    .as_ref()
+                    .and_then(|(_span, literal)| literal.value.as_bytes().try_into().ok()),
+                file_extension: schema
+                    .file_extension
+                    .as_ref()
+                    .map(|(_span, literal)| literal.value.clone()),
+            });
+        }
+
         while let Some(last) = namespace_path.pop() {
             match self.namespaces.entry(namespace_path) {
                 Entry::Occupied(mut entry) => {
@@ -560,6 +588,7 @@ impl<'a> Translator<'a> {
                 }
             }
         }
+        self.check_single_key(current_file_id, decl);
 
         let fields = decl
             .fields
@@ -594,13 +623,25 @@ impl<'a> Translator<'a> {
             )
         }
 
+        let mut key = false;
         for m in &field.metadata.values {
-            self.emit_metadata_support_error(
-                current_file_id,
-                m,
-                "struct fields",
-                m.kind.accepted_on_struct_fields(),
-            );
+            match m.kind {
+                MetadataValueKind::Key => {
+                    if type_.kind.is_scalar() {
+                        key = true;
+                    } else {
+                        self.emit_key_type_error(current_file_id, m, &type_);
+                    }
+                }
+                _ => {
+                    self.emit_metadata_support_error(
+                        current_file_id,
+                        m,
+                        "struct fields",
+                        m.kind.accepted_on_struct_fields(),
+                    );
+                }
+            }
         }
         match type_.kind {
             TypeKind::Table(_) => {
@@ -646,6 +687,7 @@ impl<'a> Translator<'a> {
                     offset: u32::MAX,
                     size: u32::MAX,
                     padding_after_field: u32::MAX,
+                    key,
                     docstrings: field.docstrings.clone(),
                 },
             )),
@@ -855,6 +897,11 @@ impl<'a> Translator<'a> {
         };
         let mut required = false;
         let mut deprecated = false;
+        let mut key = false;
+        let mut nested_flatbuffer = None;
+        let mut flexbuffer = false;
+        let mut offset64 = false;
+        let mut vector64 = false;
         let mut vtable_index = *next_vtable_index;
         let mut forced_alignment = None;
 
@@ -892,6 +939,27 @@ impl<'a> Translator<'a> {
                     }
                 }
                 MetadataValueKind::Deprecated => deprecated = true,
+                MetadataValueKind::Key => {
+                    if !type_.kind.is_scalar() && !matches!(type_.kind, TypeKind::String) {
+                        self.emit_key_type_error(current_file_id, m, &type_);
+                    } else if explicit_null {
+                        self.ctx.emit_error(
+                            ErrorKind::MISC_SEMANTIC_ERROR,
+                            [
+                                Label::secondary(current_file_id, m.span)
+                                    .with_message("field was declared as key here"),
+                                Label::primary(
+                                    current_file_id,
+                                    field.assignment.as_ref().unwrap().span,
+                                )
+                                .with_message("field was declared optional here"),
+                            ],
+                            Some("Key fields cannot be optional"),
+                        );
+                    } else {
+                        key = true;
+                    }
+                }
                 MetadataValueKind::Id(ast::IntegerLiteral {
                     span,
                     value,
@@ -930,6 +998,31 @@ impl<'a> Translator<'a> {
                         forced_alignment = Some((value, m.span));
                     }
                 }
+                MetadataValueKind::NestedFlatbuffer(literal) => {
+                    if self.check_byte_vector_attribute(
+                        current_file_id,
+                        m,
+                        &type_,
+                        "nested_flatbuffer",
+                    ) {
+                        nested_flatbuffer = self.lookup_nested_flatbuffer(
+                            current_namespace,
+                            current_file_id,
+                            literal,
+                        );
+                    }
+                }
+                MetadataValueKind::Flexbuffer => {
+                    flexbuffer =
+                        self.check_byte_vector_attribute(current_file_id, m, &type_, "flexbuffer");
+                }
+                MetadataValueKind::Offset64 => {
+                    offset64 |= self.check_64_bit_attribute(current_file_id, m, &type_, true);
+                }
+                MetadataValueKind::Vector64 => {
+                    vector64 = self.check_64_bit_attribute(current_file_id, m, &type_, false);
+                    offset64 |= vector64;
+                }
                 _ => {
                     self.emit_metadata_support_error(
                         current_file_id,
@@ -949,6 +1042,14 @@ impl<'a> Translator<'a> {
 
         *max_vtable_size = (*max_vtable_size).max(2 * *next_vtable_index + 4);
 
+        if key && deprecated {
+            self.ctx.emit_error(
+                ErrorKind::MISC_SEMANTIC_ERROR,
+                [Label::primary(current_file_id, field.span)],
+                Some("Key fields cannot be deprecated"),
+            );
+        }
+
         let assign_mode = match (required, explicit_null, default_value) {
             (true, false, None) => AssignMode::Required,
             (false, false, None) if type_.kind.is_enum() => {
@@ -983,10 +1084,106 @@ impl<'a> Translator<'a> {
             object_alignment: u32::MAX,
             forced_alignment,
             deprecated,
+            key,
+            nested_flatbuffer,
+            flexbuffer,
+            offset64,
+            vector64,
             docstrings: field.docstrings.clone(),
         })
     }
 
+    fn check_byte_vector_attribute(
+        &self,
+        current_file_id: FileId,
+        m: &ast::MetadataValue,
+        type_: &Type,
+        attribute: &str,
+    ) -> bool {
+        let is_byte_vector = matches!(
+            &type_.kind,
+            TypeKind::Vector(inner)
+                if matches!(inner.kind, TypeKind::SimpleType(SimpleType::Integer(ast::IntegerType::U8)))
+        );
+        if !is_byte_vector {
+            self.ctx.emit_error(
+                ErrorKind::TYPE_ERROR,
+                [
+                    Label::secondary(current_file_id, type_.span).with_message(format!(
+                        "only [ubyte] fields support the '{attribute}' attribute"
+                    )),
+                    Label::primary(current_file_id, m.span)
+                        .with_message(format!("{attribute} attribute was here")),
+                ],
+                Some(&format!("Unsupported {attribute} attribute")),
+            );
+        }
+        is_byte_vector
+    }
+
+    /// Checks that the `offset64` or `vector64` attribute is used on a type
+    /// that can be stored behind a 64-bit offset.
+    fn check_64_bit_attribute(
+        &self,
+        current_file_id: FileId,
+        m: &ast::MetadataValue,
+        type_: &Type,
+        allow_strings: bool,
+    ) -> bool {
+        let (attribute, supported_types) = if allow_strings {
+            ("offset64", "strings and vectors of scalars or structs")
+        } else {
+            ("vector64", "vectors of scalars or structs")
+        };
+        let is_supported = match &type_.kind {
+            TypeKind::Vector(inner) => matches!(inner.kind, TypeKind::SimpleType(_)),
+            TypeKind::String => allow_strings,
+            _ => false,
+        };
+        if !is_supported {
+            self.ctx.emit_error(
+                ErrorKind::TYPE_ERROR,
+                [
+                    Label::secondary(current_file_id, type_.span).with_message(format!(
+                        "only {supported_types} support the '{attribute}' attribute"
+                    )),
+                    Label::primary(current_file_id, m.span)
+                        .with_message(format!("{attribute} attribute was here")),
+                ],
+                Some(&format!("Unsupported {attribute} attribute")),
+            );
+        }
+        is_supported
+    }
+
+    fn lookup_nested_flatbuffer(
+        &self,
+        current_namespace: &AbsolutePath,
+        current_file_id: FileId,
+        literal: &ast::StringLiteral,
+    ) -> Option<DeclarationIndex> {
+        let namespace_path = NamespacePath {
+            span: literal.span,
+            parts: literal
+                .value
+                .split('.')
+                .map(|part| self.ctx.intern(part))
+                .collect(),
+        };
+        match self.lookup_path(current_namespace, current_file_id, &namespace_path)? {
+            TypeKind::Table(index) => Some(index),
+            _ => {
+                self.ctx.emit_error(
+                    ErrorKind::TYPE_ERROR,
+                    [Label::primary(current_file_id, literal.span)
+                        .with_message("this does not refer to a table")],
+                    Some("The root of a nested flatbuffer must be a table"),
+                );
+                None
+            }
+        }
+    }
+
     fn translate_rpc_service(
         &self,
         _current_namespace: &AbsolutePath,
@@ -1019,6 +1216,42 @@ impl<'a> Translator<'a> {
         );
     }
 
+    fn emit_key_type_error(&self, current_file_id: FileId, m: &ast::MetadataValue, type_: &Type) {
+        self.ctx.emit_error(
+            ErrorKind::TYPE_ERROR,
+            [
+                Label::secondary(current_file_id, type_.span)
+                    .with_message("only scalars, enums and strings can be used as keys"),
+                Label::primary(current_file_id, m.span).with_message("key attribute was here"),
+            ],
+            Some("Unsupported key attribute"),
+        );
+    }
+
+    fn check_single_key(&self, current_file_id: FileId, decl: &ast::Struct) {
+        let mut first_key = None;
+        for field in decl.fields.values() {
+            for m in &field.metadata.values {
+                if let MetadataValueKind::Key = &m.kind {
+                    if let Some(first_key) = first_key {
+                        self.ctx.emit_error(
+                            ErrorKind::MISC_SEMANTIC_ERROR,
+                            [
+                                Label::secondary(current_file_id, first_key)
+                                    .with_message("first key attribute was here"),
+                                Label::primary(current_file_id, m.span)
+                                    .with_message("additional key attribute was here"),
+                            ],
+                            Some("Only a single field can have the key attribute"),
+                        );
+                    } else {
+                        first_key = Some(m.span);
+                    }
+                }
+            }
+        }
+    }
+
     fn translate_table(
         &self,
         current_namespace: &AbsolutePath,
@@ -1037,6 +1270,7 @@ impl<'a> Translator<'a> {
                 m.kind.accepted_on_tables(),
             );
         }
+        self.check_single_key(current_file_id, decl);
 
         let mut has_id_error = false;
         let mut first_with_id = None;
@@ -1450,6 +1684,7 @@ impl<'a> Translator<'a> {
                 for field in decl_kind.fields.values_mut() {
                     let mut tag_kind = TableFieldTagKind::None;
                     let (value_size, mut alignment) = match &field.type_.kind {
+                        TypeKind::String | TypeKind::Vector(_) if field.offset64 => (8, 8),
                         TypeKind::Table(_) | TypeKind::String => (4, 4),
                         TypeKind::Vector(inner) => {
                             if matches!(&inner.kind, TypeKind::Union(_)) {
@@ -1565,8 +1800,62 @@ impl<'a> Translator<'a> {
             assert!(parents.is_empty());
         }
         self.resolve_table_sizes();
+        let root_types = self.translate_root_types();
+
+        Declarations::new(self.namespaces, self.declarations, root_types)
+    }
 
-        Declarations::new(self.namespaces, self.declarations)
+    fn translate_root_types(&self) -> Vec<RootType> {
+        let mut root_types: Vec<RootType> = Vec::new();
+        for ast_root_type in &self.ast_root_types {
+            let Some(type_) = self.translate_type(
+                &ast_root_type.namespace,
+                ast_root_type.file_id,
+                &ast_root_type.type_,
+            ) else {
+                continue;
+            };
+            let TypeKind::Table(declaration) = type_.kind else {
+                self.ctx.emit_error(
+                    ErrorKind::TYPE_ERROR,
+                    [Label::primary(
+                        ast_root_type.file_id,
+                        ast_root_type.type_.span,
+                    )],
+                    Some("The root_type must be a table"),
+                );
+                continue;
+            };
+            let root_type = RootType {
+                file_id: ast_root_type.file_id,
+                span: ast_root_type.span,
+                declaration,
+                file_identifier: ast_root_type.file_identifier,
+                file_extension: ast_root_type.file_extension.clone(),
+            };
+            if let Some(previous) = root_types
+                .iter()
+                .find(|previous| previous.declaration == declaration)
+            {
+                if previous.file_identifier != root_type.file_identifier
+                    || previous.file_extension != root_type.file_extension
+                {
+                    self.ctx.emit_error(
+                        ErrorKind::MISC_SEMANTIC_ERROR,
+                        [
+                            Label::secondary(previous.file_id, previous.span)
+                                .with_message("first declaration was here"),
+                            Label::primary(root_type.file_id, root_type.span)
+                                .with_message("additional declaration was here"),
+                        ],
+                        Some("Cannot use a table as root_type with different file identifiers or file extensions"),
+                    );
+                }
+                continue;
+            }
+            root_types.push(root_type);
+        }
+        root_types
     }
 
     pub fn default_value_for_type(&self, type_: &Type) -> Option<Literal> {
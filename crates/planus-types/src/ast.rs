@@ -201,6 +201,8 @@ pub enum MetadataValueKind {
     NativeInline,
     NativeDefault(StringLiteral),
     Flexbuffer,
+    Offset64,
+    Vector64,
 
     Streaming(StringLiteral),
     Idempotent,
@@ -231,6 +233,8 @@ pub enum MetadataValueKindKey {
     NativeInline,
     NativeDefault,
     Flexbuffer,
+    Offset64,
+    Vector64,
 
     Streaming,
     Idempotent,
@@ -261,6 +265,8 @@ impl MetadataValueKindKey {
             "native_inline" => Some(Self::NativeInline),
             "native_default" => Some(Self::NativeDefault),
             "flexbuffer" => Some(Self::Flexbuffer),
+            "offset64" => Some(Self::Offset64),
+            "vector64" => Some(Self::Vector64),
             "streaming" => Some(Self::Streaming),
             "idempotent" => Some(Self::Idempotent),
             _ => None,
@@ -280,6 +286,8 @@ impl MetadataValueKindKey {
             | Self::CppStrFlexCtor
             | Self::NativeInline
             | Self::Flexbuffer
+            | Self::Offset64
+            | Self::Vector64
             | Self::Idempotent => "should not have an argument",
             Self::ForceAlign | Self::Id => "should have an integer argument",
             Self::NativeType
@@ -301,7 +309,15 @@ impl MetadataValueKind {
     pub fn is_supported(&self) -> bool {
         matches!(
             self,
-            Self::ForceAlign(_) | Self::Required | Self::Deprecated | Self::Id(_)
+            Self::ForceAlign(_)
+                | Self::Required
+                | Self::Deprecated
+                | Self::Key
+                | Self::Id(_)
+                | Self::NestedFlatbuffer(_)
+                | Self::Flexbuffer
+                | Self::Offset64
+                | Self::Vector64
         )
     }
 
@@ -374,6 +390,8 @@ impl MetadataValueKind {
                 | Self::NativeInline
                 | Self::NativeDefault(_)
                 | Self::Flexbuffer
+                | Self::Offset64
+                | Self::Vector64
         )
     }
 
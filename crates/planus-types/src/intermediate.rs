@@ -99,14 +99,27 @@ impl<'a, 'b> From<&'b &'a NamespaceIndex> for NamespaceIndex {
 pub struct Declarations {
     pub namespaces: IndexMap<AbsolutePath, Namespace>,
     pub declarations: IndexMap<AbsolutePath, Declaration>,
+    pub root_types: Vec<RootType>,
     pub children: Vec<Vec<DeclarationIndex>>,
     pub parents: Vec<Vec<DeclarationIndex>>,
 }
 
+/// A `root_type` declaration, together with the `file_identifier` and
+/// `file_extension` declared in the same file.
+#[derive(Clone, Debug)]
+pub struct RootType {
+    pub file_id: FileId,
+    pub span: Span,
+    pub declaration: DeclarationIndex,
+    pub file_identifier: Option<[u8; 4]>,
+    pub file_extension: Option<String>,
+}
+
 impl Declarations {
     pub fn new(
         mut namespaces: IndexMap<AbsolutePath, Namespace>,
         declarations: IndexMap<AbsolutePath, Declaration>,
+        root_types: Vec<RootType>,
     ) -> Self {
         namespaces.entry(AbsolutePath::ROOT_PATH).or_default();
         let children = declarations
@@ -132,6 +145,7 @@ impl Declarations {
         Self {
             namespaces,
             declarations,
+            root_types,
             children,
             parents,
         }
@@ -150,6 +164,13 @@ impl Declarations {
         self.declarations.get_index(index.0).unwrap()
     }
 
+    /// Returns the `root_type` declaration for a table, if it has one.
+    pub fn get_root_type(&self, index: DeclarationIndex) -> Option<&RootType> {
+        self.root_types
+            .iter()
+            .find(|root_type| root_type.declaration == index)
+    }
+
     pub fn iter_declarations(
         &self,
     ) -> impl Iterator<Item = (DeclarationIndex, &AbsolutePath, &Declaration)> {
@@ -266,6 +287,19 @@ pub struct TableField {
     pub object_alignment: u32,
     pub forced_alignment: Option<(u32, Span)>,
     pub deprecated: bool,
+    /// Whether the field has the `key` attribute.
+    pub key: bool,
+    /// The root table of the buffer stored in this field, if it has the
+    /// `nested_flatbuffer` attribute.
+    pub nested_flatbuffer: Option<DeclarationIndex>,
+    /// Whether the field has the `flexbuffer` attribute.
+    pub flexbuffer: bool,
+    /// Whether the field is stored behind a 64-bit offset, because it has the
+    /// `offset64` or `vector64` attribute.
+    pub offset64: bool,
+    /// Whether the field is a vector with a 64-bit length, because it has the
+    /// `vector64` attribute.
+    pub vector64: bool,
     pub docstrings: Docstrings,
 }
 
@@ -308,6 +342,8 @@ pub struct StructField {
     pub offset: u32,
     pub size: u32,
     pub padding_after_field: u32,
+    /// Whether the field has the `key` attribute.
+    pub key: bool,
     pub docstrings: Docstrings,
 }
 
@@ -1,4 +1,6 @@
-use core::{alloc::Layout, mem::MaybeUninit, ptr::NonNull};
+#[cfg(feature = "alloc")]
+use core::alloc::Layout;
+use core::{mem::MaybeUninit, ptr::NonNull};
 
 pub struct BackVec {
     // This is a `Vec<u8>`, that is written from the back instead of the front.
@@ -6,6 +8,20 @@ pub struct BackVec {
     // Offset of the last written byte
     offset: usize,
     capacity: usize,
+    // Whether the memory is borrowed from the caller, in which case it is
+    // never reallocated or deallocated
+    fixed: bool,
+    // The length the data is not allowed to grow beyond
+    max_len: usize,
+    // Number of bytes that did not fit into a fixed buffer or within
+    // `max_len`. Once this is non-zero, all further writes are only counted,
+    // so the length keeps matching the length of the data that should have
+    // been written.
+    overflow: usize,
+    // Number of bytes counted as written without being stored, see
+    // `add_sparse_bytes`
+    #[cfg(test)]
+    sparse: usize,
 }
 
 // SAFETY: BackVec behaves like a Vec<u8>, and can thus implement
@@ -20,6 +36,7 @@ impl core::fmt::Debug for BackVec {
 }
 
 impl BackVec {
+    #[cfg(feature = "alloc")]
     pub fn with_capacity(capacity: usize) -> Self {
         let capacity = capacity.max(16);
         Self {
@@ -31,34 +48,192 @@ impl BackVec {
             },
             offset: capacity,
             capacity,
+            fixed: false,
+            max_len: usize::MAX,
+            overflow: 0,
+            #[cfg(test)]
+            sparse: 0,
         }
     }
 
+    /// Takes over the allocation of `vec`, discarding its contents.
+    #[cfg(feature = "alloc")]
+    pub fn from_vec(vec: alloc::vec::Vec<u8>) -> Self {
+        if vec.capacity() == 0 {
+            return Self::with_capacity(0);
+        }
+        let mut vec = core::mem::ManuallyDrop::new(vec);
+        let capacity = vec.capacity();
+        Self {
+            // SAFETY: The pointer of a `Vec` is never null
+            ptr: unsafe { NonNull::new_unchecked(vec.as_mut_ptr()) },
+            offset: capacity,
+            capacity,
+            fixed: false,
+            max_len: usize::MAX,
+            overflow: 0,
+            #[cfg(test)]
+            sparse: 0,
+        }
+    }
+
+    /// Writes into `buffer` without ever growing it.
+    ///
+    /// # Safety
+    ///
+    /// The returned value must not be used after `buffer` is no longer
+    /// borrowed.
+    pub unsafe fn from_buffer(buffer: &mut [u8]) -> Self {
+        Self {
+            // SAFETY: The pointer of a slice is never null
+            ptr: NonNull::new_unchecked(buffer.as_mut_ptr()),
+            offset: buffer.len(),
+            capacity: buffer.len(),
+            fixed: true,
+            max_len: buffer.len(),
+            overflow: 0,
+            #[cfg(test)]
+            sparse: 0,
+        }
+    }
+
+    /// Moves the contents to the front of the allocation and returns it as a
+    /// `Vec`, without reallocating.
+    #[cfg(feature = "alloc")]
+    pub fn into_vec(self) -> alloc::vec::Vec<u8> {
+        assert!(!self.fixed);
+        let this = core::mem::ManuallyDrop::new(self);
+        let len = this.len();
+        unsafe {
+            core::ptr::copy(this.ptr.as_ptr().add(this.offset), this.ptr.as_ptr(), len);
+            // SAFETY: The allocation was made by the global allocator with
+            // an alignment of 1 and a size of `capacity`, which is the same as
+            // a `Vec<u8>` with that capacity, and the first `len` bytes are
+            // initialized
+            alloc::vec::Vec::from_raw_parts(this.ptr.as_ptr(), len, this.capacity)
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn is_fixed(&self) -> bool {
+        self.fixed
+    }
+
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Sets the length the data is not allowed to grow beyond. For fixed
+    /// buffers, this can not be larger than the capacity.
+    pub fn set_max_len(&mut self, max_len: usize) {
+        self.max_len = if self.fixed {
+            max_len.min(self.capacity)
+        } else {
+            max_len
+        };
+    }
+
+    /// Returns the number of bytes that did not fit into a fixed buffer or
+    /// within the maximum length.
+    pub fn overflow(&self) -> usize {
+        self.overflow
+    }
+
     pub fn clear(&mut self) {
         self.offset = self.capacity;
+        self.overflow = 0;
+        #[cfg(test)]
+        {
+            self.sparse = 0;
+        }
+    }
+
+    /// Counts `count` bytes as written at the current position without
+    /// storing them, so tests can create buffers larger than could be
+    /// allocated. Offsets across these bytes are computed as usual, but the
+    /// data can not be read back. The buffer can not be truncated to a length
+    /// before these bytes.
+    #[cfg(test)]
+    pub fn add_sparse_bytes(&mut self, count: usize) {
+        self.sparse += count;
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        assert!(len <= self.len());
+        #[cfg(test)]
+        let len = len - self.sparse;
+        let written = self.written();
+        if len >= written {
+            self.overflow = len - written;
+        } else {
+            self.overflow = 0;
+            self.offset = self.capacity - len;
+        }
     }
 
     pub fn len(&self) -> usize {
+        let len = self.written().wrapping_add(self.overflow);
+        #[cfg(test)]
+        let len = len + self.sparse;
+        len
+    }
+
+    fn written(&self) -> usize {
         debug_assert!(self.capacity >= self.offset);
         self.capacity.wrapping_sub(self.offset)
     }
 
+    /// Returns the written bytes, which does not include the bytes that did
+    /// not fit into a fixed buffer or within the maximum length.
     pub fn as_slice(&self) -> &[u8] {
-        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr().add(self.offset), self.len()) }
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr().add(self.offset), self.written()) }
     }
 
+    /// Returns the `count` written bytes that start `back` bytes from the
+    /// back of the buffer. Their position stays the same as more bytes are
+    /// written in front of them.
+    pub fn back_slice_mut(&mut self, back: usize, count: usize) -> &mut [u8] {
+        assert!(count <= back && back <= self.written());
+        unsafe {
+            core::slice::from_raw_parts_mut(self.ptr.as_ptr().add(self.capacity - back), count)
+        }
+    }
+
+    /// Makes room for `capacity` more bytes, returning false if they do not
+    /// fit and should only be counted.
     #[inline]
-    pub fn reserve(&mut self, capacity: usize) {
+    pub fn reserve(&mut self, capacity: usize) -> bool {
+        // The maximum length only needs to be checked when the buffer could
+        // grow beyond it without reallocating, such as after taking over a
+        // large allocation in `from_vec`
+        if capacity > self.offset || self.overflow != 0 || self.capacity > self.max_len {
+            return self.reserve_slow(capacity);
+        }
+        true
+    }
+
+    #[cold]
+    fn reserve_slow(&mut self, capacity: usize) -> bool {
+        if self.overflow != 0 || capacity > self.max_len.saturating_sub(self.written()) {
+            return false;
+        }
+        // Fixed buffers never grow, since their maximum length is at most
+        // their capacity, and without an allocator all buffers are fixed
+        #[cfg(feature = "alloc")]
         if capacity > self.offset {
             self.grow(capacity);
-            assert!(capacity <= self.offset);
         }
+        true
     }
 
+    #[cfg(feature = "alloc")]
     fn grow(&mut self, capacity: usize) {
-        let len = self.len();
+        let len = self.written();
         let needed = len.checked_add(capacity).unwrap();
-        let new_capacity = needed.max(self.capacity.saturating_mul(2));
+        let new_capacity = needed.max(self.capacity.saturating_mul(2).min(self.max_len));
         let new_offset = new_capacity.checked_sub(len).unwrap();
 
         unsafe {
@@ -85,7 +260,10 @@ impl BackVec {
 
     #[inline]
     pub fn extend_from_slice(&mut self, buffer: &[u8]) {
-        self.reserve(buffer.len());
+        if !self.reserve(buffer.len()) {
+            self.overflow = self.overflow.checked_add(buffer.len()).unwrap();
+            return;
+        }
         let new_offset = self.offset.wrapping_sub(buffer.len());
         unsafe {
             core::ptr::copy_nonoverlapping(
@@ -99,29 +277,85 @@ impl BackVec {
 
     #[inline]
     pub fn extend_with_zeros(&mut self, count: usize) {
-        self.reserve(count);
+        if !self.reserve(count) {
+            self.overflow = self.overflow.checked_add(count).unwrap();
+            return;
+        }
         let new_offset = self.offset.wrapping_sub(count);
         unsafe { core::ptr::write_bytes(self.ptr.as_ptr().add(new_offset), 0, count) }
         self.offset = new_offset;
     }
 
+    /// If the bytes do not fit into a fixed buffer or within the maximum
+    /// length, `f` is not called.
     pub unsafe fn extend_write(&mut self, count: usize, f: impl FnOnce(&mut [MaybeUninit<u8>])) {
-        self.reserve(count);
+        if !self.reserve(count) {
+            self.overflow = self.overflow.checked_add(count).unwrap();
+            return;
+        }
         let new_offset = self.offset.wrapping_sub(count);
         let ptr = self.ptr.as_ptr().add(new_offset) as *mut MaybeUninit<u8>;
         let slice = core::slice::from_raw_parts_mut(ptr, count);
         f(slice);
         self.offset = new_offset;
     }
+
+    /// Sets aside `size` bytes aligned to `align` at the front of the unused
+    /// part of a fixed buffer and returns a pointer to them, or `None` if they
+    /// do not fit. Until the returned [`Front`] is given to
+    /// [`restore_front`](Self::restore_front), the buffer behaves as if these
+    /// bytes were not part of it, so they are never written.
+    pub fn take_front(&mut self, size: usize, align: usize) -> Option<(NonNull<u8>, Front)> {
+        debug_assert!(self.fixed);
+        let padding = self.ptr.as_ptr().align_offset(align);
+        let taken = padding.checked_add(size)?;
+        if taken > self.offset {
+            return None;
+        }
+        let front = Front {
+            taken,
+            max_len: self.max_len,
+        };
+        // SAFETY: `padding + size` is at most `offset`, so both pointers are
+        // within the buffer
+        let ptr = unsafe {
+            let ptr = NonNull::new_unchecked(self.ptr.as_ptr().add(padding));
+            self.ptr = NonNull::new_unchecked(self.ptr.as_ptr().add(taken));
+            ptr
+        };
+        self.capacity -= taken;
+        self.offset -= taken;
+        self.max_len = self.max_len.min(self.capacity);
+        Some((ptr, front))
+    }
+
+    /// Gives back the bytes set aside by [`take_front`](Self::take_front).
+    pub fn restore_front(&mut self, front: Front) {
+        // SAFETY: The bytes before the pointer were part of the buffer
+        self.ptr = unsafe { NonNull::new_unchecked(self.ptr.as_ptr().sub(front.taken)) };
+        self.capacity += front.taken;
+        self.offset += front.taken;
+        self.max_len = front.max_len;
+    }
+}
+
+/// Bytes set aside at the front of a fixed buffer by [`BackVec::take_front`].
+#[must_use]
+pub struct Front {
+    taken: usize,
+    max_len: usize,
 }
 
 impl Drop for BackVec {
     fn drop(&mut self) {
-        unsafe {
-            alloc::alloc::dealloc(
-                self.ptr.as_ptr(),
-                Layout::from_size_align_unchecked(self.capacity, 1),
-            );
+        #[cfg(feature = "alloc")]
+        if !self.fixed {
+            unsafe {
+                alloc::alloc::dealloc(
+                    self.ptr.as_ptr(),
+                    Layout::from_size_align_unchecked(self.capacity, 1),
+                );
+            }
         }
     }
 }
@@ -185,4 +419,48 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_vec_roundtrip() {
+        let mut backvec = BackVec::from_vec(alloc::vec::Vec::with_capacity(100));
+        assert_eq!(backvec.capacity(), 100);
+        assert_eq!(backvec.len(), 0);
+        backvec.extend_from_slice(b"world");
+        backvec.extend_from_slice(b"hello ");
+        let vec = backvec.into_vec();
+        assert_eq!(vec, b"hello world");
+        assert_eq!(vec.capacity(), 100);
+
+        let mut backvec = BackVec::from_vec(vec);
+        assert_eq!(backvec.len(), 0);
+        backvec.extend_from_slice(b"again");
+        assert_eq!(backvec.into_vec(), b"again");
+
+        let backvec = BackVec::from_vec(alloc::vec::Vec::new());
+        assert!(backvec.capacity() > 0);
+    }
+
+    #[test]
+    fn test_fixed_buffer() {
+        let mut buffer = [0xff; 8];
+        let mut backvec = unsafe { BackVec::from_buffer(&mut buffer) };
+        backvec.extend_from_slice(b"world");
+        assert!(backvec.reserve(3));
+        assert!(!backvec.reserve(4));
+        backvec.extend_from_slice(b"hello ");
+        backvec.extend_with_zeros(2);
+        assert_eq!(backvec.len(), 13);
+        assert_eq!(backvec.overflow(), 8);
+        assert_eq!(backvec.as_slice(), b"world");
+
+        backvec.truncate(7);
+        assert_eq!(backvec.overflow(), 2);
+        backvec.truncate(5);
+        assert_eq!(backvec.overflow(), 0);
+        backvec.extend_from_slice(b"abc");
+        assert_eq!(backvec.as_slice(), b"abcworld");
+        assert_eq!(backvec.capacity(), 8);
+        drop(backvec);
+        assert_eq!(&buffer, b"abcworld");
+    }
 }
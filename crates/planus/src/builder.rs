@@ -1,10 +1,11 @@
 use core::{marker::PhantomData, mem::MaybeUninit};
 
 use crate::{
-    backvec::BackVec, Offset, Primitive, UnionVectorOffset, WriteAsOffset, WriteAsUnionVector,
+    backvec::BackVec, errors::BuilderError, unknown_union::UNKNOWN_UNION_ALIGNMENT, Error, Offset,
+    Offset64, Primitive, UnionVectorOffset, UnknownUnionRef, Vector64Offset, VectorWrite,
+    WriteAsOffset, WriteAsUnion, WriteAsUnionVector,
 };
 
-#[derive(Debug)]
 /// Builder for serializing flatbuffers.
 ///
 ///
@@ -16,8 +17,20 @@ use crate::{
 /// let weapon = Weapon::create(&mut builder, "Axe", 24);
 /// builder.finish(weapon, None);
 /// ```
-pub struct Builder {
+pub type Builder = BufferBuilder<'static>;
+
+#[derive(Debug)]
+/// Builder for serializing flatbuffers, which can write into a buffer
+/// borrowed for the lifetime `'buf`.
+///
+/// Builders created using [`from_buffer`] write into a buffer borrowed from
+/// the caller and do not need an allocator. All other builders own their
+/// buffer and are usually referred to using the [`Builder`] alias.
+///
+/// [`from_buffer`]: Self::from_buffer
+pub struct BufferBuilder<'buf> {
     pub(crate) inner: BackVec,
+    phantom: PhantomData<&'buf mut [u8]>,
 
     #[cfg(feature = "vtable-cache")]
     vtable_cache: crate::builder_cache::Cache<crate::builder_cache::VTable>,
@@ -60,23 +73,112 @@ pub struct Builder {
     delayed_bytes: usize,
     alignment_mask: usize,
 
+    // The error for the first object that could not be written correctly,
+    // such as one containing an offset that did not fit in 32 bits. This is
+    // reported when finishing, unless the object is rolled back.
+    invalid_object: Option<BuilderError>,
+    // The error for the value that could not be read when the invalid object
+    // is a copy of a value from another buffer.
+    copy_error: Option<Error>,
+
+    // The data written for the previous unknown union value, which later
+    // values can refer into.
+    unknown_union_data: Option<UnknownUnionData>,
+
+    // The length of the buffer before the first object that can be reached
+    // through 32-bit offsets. Everything written after it must stay within
+    // 4 GiB, so the offsets between those objects fit in 32 bits.
+    offset32_start: Option<usize>,
+    // Whether an object behind a 64-bit offset is being written, which is
+    // not counted towards the 4 GiB limit.
+    writing_offset64: bool,
+
     #[cfg(debug_assertions)]
     // Bytes missing to be written by a call to prepare_write
     missing_bytes: usize,
 }
 
-impl Default for Builder {
+#[derive(Copy, Clone, Debug)]
+struct UnknownUnionData {
+    offset: usize,
+    len: usize,
+    // The position of the data in the buffer it was copied from
+    position: usize,
+    // The address of the end of the buffer the data was copied from, which
+    // is only used to guess whether values come from the same buffer
+    source_end: usize,
+}
+
+/// A saved state of a [`BufferBuilder`], created by
+/// [`BufferBuilder::checkpoint`] and restored by
+/// [`BufferBuilder::rollback_to`].
+#[derive(Copy, Clone, Debug)]
+pub struct Checkpoint {
+    len: usize,
+    delayed_bytes: usize,
+    alignment_mask: usize,
+    valid: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl Default for BufferBuilder<'_> {
     fn default() -> Self {
         Self::with_capacity(0)
     }
 }
 
-impl Builder {
+#[cfg(feature = "alloc")]
+impl BufferBuilder<'_> {
     /// Creates a new Builder.
     pub fn new() -> Self {
         Self::with_capacity(0)
     }
 
+    /// Creates a new builder with a specific internal capacity already allocated.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from_back_vec(BackVec::with_capacity(capacity))
+    }
+
+    /// Creates a new builder that does not let the serialized data grow
+    /// beyond `max_size` bytes.
+    ///
+    /// Once the data does not fit, the remaining writes are discarded, the
+    /// `try_*` methods such as [`try_finish`] return
+    /// [`BuilderError::MaxSizeExceeded`] and [`finish`] panics. The builder
+    /// can be used again after calling [`clear`], or after rolling back to a
+    /// [`Checkpoint`] created before the data grew too large.
+    ///
+    /// With a maximum size of at most `u32::MAX`, the 32-bit offsets in the
+    /// buffer can never overflow, so writing to the builder never panics.
+    ///
+    /// # Examples
+    /// ```
+    /// use planus::{errors::BuilderError, Builder};
+    /// use planus_example::monster_generated::my_game::sample::Weapon;
+    /// let mut builder = Builder::with_max_size(64);
+    /// let name = builder.try_create_string("Axe").unwrap();
+    /// assert!(matches!(
+    ///     builder.try_create_string("Axe".repeat(100)),
+    ///     Err(BuilderError::MaxSizeExceeded { max_size: 64, .. })
+    /// ));
+    ///
+    /// // The failed call did not change the builder
+    /// let weapon = Weapon::create(&mut builder, name, 24);
+    /// builder.try_finish(weapon, None).unwrap();
+    /// ```
+    ///
+    /// [`try_finish`]: Self::try_finish
+    /// [`finish`]: Self::finish
+    /// [`clear`]: Self::clear
+    /// [`BuilderError::MaxSizeExceeded`]: crate::errors::BuilderError::MaxSizeExceeded
+    pub fn with_max_size(max_size: usize) -> Self {
+        let mut inner = BackVec::with_capacity(0);
+        inner.set_max_len(max_size);
+        Self::from_back_vec(inner)
+    }
+}
+
+impl<'buf> BufferBuilder<'buf> {
     /// Gets the length of the internal buffer in bytes.
     pub fn len(&self) -> usize {
         self.inner.len()
@@ -87,13 +189,54 @@ impl Builder {
         self.len() == 0
     }
 
-    /// Creates a new builder with a specific internal capacity already allocated.
-    pub fn with_capacity(capacity: usize) -> Self {
+    /// Creates a new builder that writes into `buffer` instead of allocating
+    /// memory for the serialized data.
+    ///
+    /// The buffer is written from the back, so a finished buffer ends up at
+    /// the end of `buffer`. The buffer is never grown. If the serialized data
+    /// does not fit, the remaining writes are discarded and [`try_finish`]
+    /// returns [`BuilderError::BufferTooSmall`]. The vtable, string and bytes
+    /// caches are not used by these builders, since they would allocate.
+    ///
+    /// # Examples
+    /// ```
+    /// use planus::{errors::BuilderError, BufferBuilder, ReadAsRoot};
+    /// use planus_example::monster_generated::my_game::sample::{Weapon, WeaponRef};
+    /// let mut buffer = [0; 64];
+    /// let mut builder = BufferBuilder::from_buffer(&mut buffer);
+    /// let weapon = Weapon::create(&mut builder, "Axe", 24);
+    /// let data = builder.try_finish(weapon, None).unwrap();
+    /// assert_eq!(WeaponRef::read_as_root(data).unwrap().damage().unwrap(), 24);
+    ///
+    /// let mut buffer = [0; 16];
+    /// let mut builder = BufferBuilder::from_buffer(&mut buffer);
+    /// let weapon = Weapon::create(&mut builder, "Axe", 24);
+    /// assert!(matches!(
+    ///     builder.try_finish(weapon, None),
+    ///     Err(BuilderError::BufferTooSmall { .. })
+    /// ));
+    /// ```
+    ///
+    /// [`try_finish`]: Self::try_finish
+    /// [`BuilderError::BufferTooSmall`]: crate::errors::BuilderError::BufferTooSmall
+    pub fn from_buffer(buffer: &'buf mut [u8]) -> Self {
+        // SAFETY: The builder borrows the buffer for `'buf`, so it can not
+        // outlive it
+        Self::from_back_vec(unsafe { BackVec::from_buffer(buffer) })
+    }
+
+    fn from_back_vec(inner: BackVec) -> Self {
         Self {
-            inner: BackVec::with_capacity(capacity),
+            inner,
+            phantom: PhantomData,
 
             delayed_bytes: 0,
             alignment_mask: 0,
+            invalid_object: None,
+            copy_error: None,
+            unknown_union_data: None,
+            offset32_start: None,
+            writing_offset64: false,
 
             #[cfg(feature = "vtable-cache")]
             vtable_cache: crate::builder_cache::Cache::default(),
@@ -114,11 +257,491 @@ impl Builder {
         v.prepare(self)
     }
 
+    /// Serializes a string and returns the offset to it, or an error if it
+    /// does not fit within the size limits of the builder.
+    ///
+    /// On errors, the builder is left as it was before the call. See
+    /// [`try_finish`] for the size limits that apply.
+    ///
+    /// [`try_finish`]: Self::try_finish
+    pub fn try_create_string(
+        &mut self,
+        v: impl WriteAsOffset<str>,
+    ) -> Result<Offset<str>, BuilderError> {
+        self.try_write(|builder| v.prepare(builder))
+    }
+
     /// Serializes a slice and returns the offset to it
     pub fn create_vector<T>(&mut self, v: impl WriteAsOffset<[T]>) -> Offset<[T]> {
         v.prepare(self)
     }
 
+    /// Serializes a slice and returns the offset to it, or an error if it
+    /// does not fit within the size limits of the builder.
+    ///
+    /// On errors, the builder is left as it was before the call. See
+    /// [`try_finish`] for the size limits that apply.
+    ///
+    /// [`try_finish`]: Self::try_finish
+    pub fn try_create_vector<T>(
+        &mut self,
+        v: impl WriteAsOffset<[T]>,
+    ) -> Result<Offset<[T]>, BuilderError> {
+        self.try_write(|builder| v.prepare(builder))
+    }
+
+    /// Writes a copy of a value read from another buffer, such as a table
+    /// reference, and returns the offset to it.
+    ///
+    /// Writing a value read from an unverified buffer can run into parts of
+    /// the buffer that cannot be read. This returns the error for the first
+    /// of them, in which case the builder is left as it was before the call.
+    /// When writing such values in other ways, the error makes finishing the
+    /// buffer fail with [`BuilderError::InvalidCopySource`] instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use planus::{Builder, ReadAsRoot};
+    /// use planus_example::monster_generated::my_game::sample::{Weapon, WeaponRef};
+    /// let mut builder = Builder::new();
+    /// let weapon = Weapon::create(&mut builder, "Axe", 24);
+    /// let data = builder.finish(weapon, None).to_vec();
+    ///
+    /// let mut builder = Builder::new();
+    /// let weapon = builder.try_copy(WeaponRef::read_as_root(&data).unwrap()).unwrap();
+    /// let copied = builder.finish(weapon, None);
+    /// assert_eq!(copied, data);
+    ///
+    /// // A string running past the end of the buffer cannot be copied
+    /// let truncated = &data[..data.len() - 4];
+    /// let weapon = WeaponRef::read_as_root(truncated).unwrap();
+    /// assert!(builder.try_copy(weapon).is_err());
+    /// ```
+    ///
+    /// [`BuilderError::InvalidCopySource`]: crate::errors::BuilderError::InvalidCopySource
+    pub fn try_copy<T: ?Sized>(
+        &mut self,
+        value: impl WriteAsOffset<T>,
+    ) -> Result<Offset<T>, Error> {
+        let checkpoint = self.checkpoint();
+        let invalid_object = self.invalid_object.take();
+        let copy_error = self.copy_error.take();
+        let offset = value.prepare(self);
+        if let Some(error) = self.copy_error.take() {
+            self.rollback_to(checkpoint);
+            self.invalid_object = invalid_object;
+            self.copy_error = copy_error;
+            return Err(error);
+        }
+        if invalid_object.is_some() {
+            self.invalid_object = invalid_object;
+            self.copy_error = copy_error;
+        }
+        Ok(offset)
+    }
+
+    /// Serializes the values of an iterator as a vector and returns the offset
+    /// to it.
+    ///
+    /// This avoids having to collect the values into a temporary slice first,
+    /// for instance when serializing the results of a call to
+    /// [`Iterator::map`]. Scalars, enums, structs and offsets are written
+    /// directly into the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator returns a different number of elements than
+    /// its [`len`](ExactSizeIterator::len).
+    ///
+    /// # Examples
+    /// ```
+    /// use planus::Builder;
+    /// let names = ["Axe", "Sword"];
+    /// let mut builder = Builder::new();
+    /// let lengths = builder.create_vector_from_iter(names.iter().map(|name| name.len() as u32));
+    /// let names = builder.create_vector_from_iter(names.iter().map(|name| name.to_uppercase()));
+    /// ```
+    pub fn create_vector_from_iter<T, P, I>(&mut self, iter: I) -> Offset<[P]>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+        T: VectorWrite<P>,
+        P: Primitive,
+    {
+        let len =
+            self.write_vector_values(iter.into_iter(), P::ALIGNMENT_MASK.max(u32::ALIGNMENT_MASK));
+        let len = self.checked_len(len);
+
+        // SAFETY: The inner closure always initializes the entire buffer, because it calls `copy_from_slice` with an array of len 4
+        unsafe {
+            self.write_with(4, 0, |_buffer_position, bytes| {
+                let len = len.to_le_bytes().map(MaybeUninit::new);
+                bytes.copy_from_slice(&len);
+            });
+        }
+        self.current_offset()
+    }
+
+    /// Writes the values of a vector without its length and returns the
+    /// number of values.
+    ///
+    /// Values that can be prepared without writing to the builder are
+    /// written directly into the buffer, all others are collected first.
+    fn write_vector_values<T, P, I>(&mut self, mut iter: I, alignment_mask: usize) -> usize
+    where
+        I: ExactSizeIterator<Item = T>,
+        T: VectorWrite<P>,
+        P: Primitive,
+    {
+        let len = iter.len();
+        let count = if T::INLINE {
+            let size = T::STRIDE.checked_mul(len).unwrap();
+            let buffer_position = self.prepare_write(size, alignment_mask) as u64;
+            self.inner.extend_with_zeros(size);
+            #[cfg(debug_assertions)]
+            {
+                self.missing_bytes = self.missing_bytes.checked_sub(size).unwrap();
+            }
+            // The values are not stored if they did not fit, in which case
+            // they are only prepared to report any errors
+            let stored = self.inner.overflow() == 0;
+            let back = self.inner.as_slice().len();
+            let mut count = 0;
+            for (i, value) in iter.by_ref().take(len).enumerate() {
+                let value = value.prepare(self);
+                if stored {
+                    let bytes = self.inner.back_slice_mut(back - T::STRIDE * i, T::STRIDE);
+                    // SAFETY: `bytes` has room for the one value of length `T::STRIDE`
+                    unsafe {
+                        T::write_values(
+                            core::slice::from_ref(&value),
+                            bytes.as_mut_ptr().cast(),
+                            buffer_position - (T::STRIDE * i) as u64,
+                        );
+                    }
+                }
+                count += 1;
+            }
+            count
+        } else {
+            self.with_prepared_values(
+                iter.by_ref(),
+                len,
+                |builder, value| value.prepare(builder),
+                |builder, values, count| {
+                    // SAFETY: The inner closure always initializes the entire buffer, because it calls `write_values` with `count` values each of length `T::STRIDE`, or fills it with zeros.
+                    unsafe {
+                        builder.write_with(
+                            T::STRIDE.checked_mul(count).unwrap(),
+                            alignment_mask,
+                            |buffer_position, bytes| match values {
+                                Some(values) => {
+                                    T::write_values(values, bytes.as_mut_ptr(), buffer_position)
+                                }
+                                None => bytes.fill(MaybeUninit::new(0)),
+                            },
+                        );
+                    }
+                },
+            )
+        };
+        assert!(
+            count == len && iter.next().is_none(),
+            "the iterator returned a different number of elements than its length"
+        );
+        len
+    }
+
+    /// Prepares at most `len` values of an iterator, passes them to `write`
+    /// together with their number and returns that number.
+    ///
+    /// The values are collected in a temporary `Vec`, except when writing
+    /// into a fixed buffer. Then they are collected at the front of the
+    /// unused part of the buffer, where they stay while `write` runs. If they
+    /// do not fit there, `write` gets `None` and finishing the buffer fails.
+    fn with_prepared_values<I, V>(
+        &mut self,
+        iter: I,
+        len: usize,
+        mut prepare: impl FnMut(&mut Self, I::Item) -> V,
+        write: impl FnOnce(&mut Self, Option<&[V]>, usize),
+    ) -> usize
+    where
+        I: Iterator,
+    {
+        #[cfg(feature = "alloc")]
+        if !self.inner.is_fixed() {
+            let mut values = alloc::vec::Vec::with_capacity(len);
+            for item in iter.take(len) {
+                let value = prepare(self, item);
+                values.push(value);
+            }
+            write(self, Some(&values), values.len());
+            return values.len();
+        }
+
+        let size = core::mem::size_of::<V>().checked_mul(len).unwrap();
+        let Some((ptr, front)) = self.inner.take_front(size, core::mem::align_of::<V>()) else {
+            let needed = self.inner.len().saturating_add(size);
+            let mut count = 0;
+            for item in iter.take(len) {
+                prepare(self, item);
+                count += 1;
+            }
+            self.mark_invalid(BuilderError::BufferTooSmall {
+                capacity: self.inner.capacity(),
+                needed,
+            });
+            write(self, None, count);
+            return count;
+        };
+        let ptr = ptr.as_ptr().cast::<V>();
+        let overflow = self.inner.overflow();
+        let mut count = 0;
+        for item in iter.take(len) {
+            let value = prepare(self, item);
+            // SAFETY: The front of the buffer has room for `len` aligned
+            // values and is not written by the builder until it is restored
+            unsafe { ptr.add(count).write(value) };
+            count += 1;
+        }
+        // SAFETY: The first `count` values were initialized above
+        let values = unsafe { core::slice::from_raw_parts_mut(ptr, count) };
+        write(self, Some(values), count);
+        // SAFETY: The values are not used after this
+        unsafe { core::ptr::drop_in_place(values) };
+        self.inner.restore_front(front);
+        if self.inner.overflow() > overflow {
+            // The data would only have fit without the values set aside
+            self.mark_invalid(BuilderError::BufferTooSmall {
+                capacity: self.inner.capacity(),
+                needed: self.inner.len().saturating_add(size),
+            });
+        }
+        count
+    }
+
+    /// Sorts a slice by the `key` field of its elements, then serializes it
+    /// and returns the offset to it.
+    ///
+    /// Vectors created this way can be searched using [`Vector::lookup_by_key`].
+    ///
+    /// [`Vector::lookup_by_key`]: crate::Vector::lookup_by_key
+    #[cfg(feature = "alloc")]
+    pub fn create_vector_sorted_by_key<T, P>(&mut self, v: &mut [T]) -> Offset<[P]>
+    where
+        T: crate::Keyed + VectorWrite<P>,
+        P: Primitive,
+    {
+        use crate::KeyCompare;
+        v.sort_by(|a, b| a.key().compare_key(b.key()));
+        self.create_vector(&*v)
+    }
+
+    /// Serializes a slice as a vector with a 64-bit length and returns the
+    /// offset to it, for use with fields that have the `vector64` attribute.
+    ///
+    /// Objects behind 64-bit offsets should be created before any other
+    /// objects in the buffer. This keeps them at the end of the buffer, so
+    /// they do not come between a 32-bit offset and the object it points to.
+    ///
+    /// # Examples
+    /// ```
+    /// use planus::Builder;
+    /// let mut builder = Builder::new();
+    /// let offset = builder.create_vector64::<u16, u16>(&[1, 2, 3]);
+    /// ```
+    pub fn create_vector64<T, P>(&mut self, v: &[T]) -> Vector64Offset<P>
+    where
+        T: VectorWrite<P>,
+        P: Primitive,
+    {
+        self.create_vector64_from_iter(v)
+    }
+
+    pub(crate) fn create_vector64_from_iter<T, P, I>(&mut self, iter: I) -> Vector64Offset<P>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+        T: VectorWrite<P>,
+        P: Primitive,
+    {
+        let writing_offset64 = core::mem::replace(&mut self.writing_offset64, true);
+        let len =
+            self.write_vector_values(iter.into_iter(), P::ALIGNMENT_MASK.max(u64::ALIGNMENT_MASK));
+        // SAFETY: The inner closure always initializes the entire buffer,
+        // because it calls `copy_from_slice` with an array of len 8
+        unsafe {
+            self.write_with(8, 0, |_buffer_position, bytes| {
+                let len = (len as u64).to_le_bytes().map(MaybeUninit::new);
+                bytes.copy_from_slice(&len);
+            });
+        }
+        self.writing_offset64 = writing_offset64;
+        Vector64Offset {
+            offset: self.inner.len() as u64,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Writes a string or vector using `f` and returns a 64-bit offset to
+    /// it. The object does not count towards the 4 GiB that objects reachable
+    /// through 32-bit offsets must fit in, so it must not contain any 32-bit
+    /// offsets itself.
+    pub(crate) fn prepare_offset64<T: ?Sized>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Offset<T>,
+    ) -> Offset64<T> {
+        let writing_offset64 = core::mem::replace(&mut self.writing_offset64, true);
+        let offset = f(self);
+        self.writing_offset64 = writing_offset64;
+        // The object was just written, so it is less than 4 GiB from the end
+        let len = self.inner.len();
+        let distance = (len as u32).wrapping_sub(offset.offset);
+        Offset64 {
+            offset: (len - distance as usize) as u64,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Embeds a finished flatbuffer as a `[ubyte]` vector and returns the
+    /// offset to it, for use with fields that have the `nested_flatbuffer`
+    /// attribute.
+    ///
+    /// The nested buffer is placed so that it starts at a multiple of
+    /// `alignment`, which should be the alignment of the builder used to
+    /// create it as given by [`BufferBuilder::alignment`]. This keeps every object
+    /// in the nested buffer correctly aligned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alignment` is not a power of two.
+    ///
+    /// # Examples
+    /// ```
+    /// use planus::Builder;
+    /// use planus_example::monster_generated::my_game::sample::Weapon;
+    /// let mut nested_builder = Builder::new();
+    /// let weapon = Weapon::create(&mut nested_builder, "Axe", 24);
+    /// let nested = nested_builder.finish(weapon, None).to_vec();
+    ///
+    /// let mut builder = Builder::new();
+    /// let offset = builder.create_nested_flatbuffer(&nested, nested_builder.alignment());
+    /// ```
+    pub fn create_nested_flatbuffer(&mut self, buffer: &[u8], alignment: usize) -> Offset<[u8]> {
+        assert!(
+            alignment.is_power_of_two(),
+            "alignment must be a power of two"
+        );
+        let len = self.checked_len(buffer.len());
+        self.prepare_write(buffer.len(), (alignment - 1).max(u32::ALIGNMENT_MASK));
+        self.write(buffer);
+        self.prepare_write(4, u32::ALIGNMENT_MASK);
+        self.write(&len.to_le_bytes());
+        self.current_offset()
+    }
+
+    /// Writes a union value with an unknown type, given as a buffer created by
+    /// [`UnknownUnionRef::to_bytes`], and returns the offset to it.
+    ///
+    /// The value is written like [`copy_unknown_union_value`] writes the
+    /// value the buffer was created from. Returns
+    /// [`BuilderError::InvalidUnknownUnionValue`] if the root offset of the
+    /// buffer does not point inside it, in which case nothing is written.
+    ///
+    /// [`UnknownUnionRef::to_bytes`]: crate::UnknownUnionRef::to_bytes
+    /// [`copy_unknown_union_value`]: Self::copy_unknown_union_value
+    /// [`BuilderError::InvalidUnknownUnionValue`]: crate::errors::BuilderError::InvalidUnknownUnionValue
+    pub fn create_unknown_union_value(
+        &mut self,
+        buffer: &[u8],
+    ) -> Result<Offset<()>, BuilderError> {
+        let value =
+            UnknownUnionRef::from_bytes(buffer).ok_or(BuilderError::InvalidUnknownUnionValue)?;
+        Ok(self.copy_unknown_union_value(&value))
+    }
+
+    #[doc(hidden)]
+    /// Like [`create_unknown_union_value`](Self::create_unknown_union_value),
+    /// but makes finishing the buffer fail instead of returning the error.
+    pub fn prepare_unknown_union_value(&mut self, buffer: &[u8]) -> Offset<()> {
+        self.create_unknown_union_value(buffer)
+            .unwrap_or_else(|error| {
+                self.mark_invalid(error);
+                self.current_offset()
+            })
+    }
+
+    /// Writes a copy of a union value with an unknown type and returns the
+    /// offset to it.
+    ///
+    /// Everything from the value to the end of the buffer it was read from is
+    /// copied, keeping the alignment of its contents. When the copied data is
+    /// a part of the data written for the previous unknown union value, the
+    /// value refers into that instead. When another value from the same
+    /// buffer does not fit into it, the whole buffer is copied once, so
+    /// vectors of unknown union values are not copied over and over.
+    pub fn copy_unknown_union_value(&mut self, value: &UnknownUnionRef<'_>) -> Offset<()> {
+        let (data, data_position, root_offset) = value.copied_data();
+        let source_end = data.as_ptr_range().end as usize;
+        let data_offset = if let Some(offset) = self.find_unknown_union_data(data, data_position) {
+            offset
+        } else if self
+            .unknown_union_data
+            .is_some_and(|previous| previous.source_end == source_end)
+        {
+            let (source, source_position) = value.source();
+            let offset = self.write_unknown_union_data(source, source_position, source_end);
+            offset - (data_position - source_position)
+        } else {
+            self.write_unknown_union_data(data, data_position, source_end)
+        };
+        Offset {
+            offset: (data_offset - root_offset) as u32,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the offset of `data` if it is the end of the data written for
+    /// the previous unknown union value, with the same alignment.
+    fn find_unknown_union_data(&self, data: &[u8], data_position: usize) -> Option<usize> {
+        let previous = self.unknown_union_data?;
+        let skipped = previous.len.checked_sub(data.len())?;
+        let offset = previous.offset - skipped;
+        // Compare the bytes, as the buffer the previous value was copied from
+        // may not exist anymore
+        let buffer = self.inner.as_slice();
+        let start = buffer.len().checked_sub(offset)?;
+        ((previous.position + skipped) % UNKNOWN_UNION_ALIGNMENT
+            == data_position % UNKNOWN_UNION_ALIGNMENT
+            && self.inner.overflow() == 0
+            && buffer.get(start..start + data.len()) == Some(data))
+        .then_some(offset)
+    }
+
+    /// Writes the data of an unknown union value at the same position modulo
+    /// the alignment as it had in the buffer it was copied from, and returns
+    /// the offset to its start.
+    fn write_unknown_union_data(
+        &mut self,
+        data: &[u8],
+        data_position: usize,
+        source_end: usize,
+    ) -> usize {
+        let padding = data_position % UNKNOWN_UNION_ALIGNMENT;
+        self.prepare_write(padding + data.len(), UNKNOWN_UNION_ALIGNMENT - 1);
+        self.write(data);
+        let offset = self.inner.len();
+        self.write(&[0; UNKNOWN_UNION_ALIGNMENT][..padding]);
+        self.unknown_union_data = (self.inner.overflow() == 0).then_some(UnknownUnionData {
+            offset,
+            len: data.len(),
+            position: data_position,
+            source_end,
+        });
+        offset
+    }
+
     /// Serializes a slice of union values and returns the offset to it
     pub fn create_union_vector<T>(
         &mut self,
@@ -127,6 +750,183 @@ impl Builder {
         v.prepare(self)
     }
 
+    /// Serializes the union values of an iterator as a vector and returns the
+    /// offset to it.
+    ///
+    /// This is the union equivalent of [`create_vector_from_iter`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator returns a different number of elements than
+    /// its [`len`](ExactSizeIterator::len).
+    ///
+    /// [`create_vector_from_iter`]: Self::create_vector_from_iter
+    pub fn create_union_vector_from_iter<T, P, I>(&mut self, iter: I) -> UnionVectorOffset<P>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+        T: WriteAsUnion<P>,
+    {
+        let mut iter = iter.into_iter();
+        let len = iter.len();
+        let mut values_offset = self.current_offset();
+        let count = self.with_prepared_values(
+            iter.by_ref(),
+            len,
+            |builder, value| value.prepare(builder),
+            |builder, values, count| {
+                const STRIDE: usize = Offset::<()>::STRIDE;
+                // SAFETY: The inner closure always initializes the entire buffer, because it calls `write_values` for `count` values each of length `STRIDE`, or fills it with zeros.
+                unsafe {
+                    builder.write_with(
+                        STRIDE.checked_mul(count).unwrap(),
+                        Offset::<()>::ALIGNMENT_MASK,
+                        |buffer_position, bytes| match values {
+                            Some(values) => {
+                                for (i, value) in values.iter().enumerate() {
+                                    Offset::<()>::write_values(
+                                        core::slice::from_ref(&value.offset),
+                                        bytes.as_mut_ptr().add(STRIDE * i),
+                                        buffer_position - (STRIDE * i) as u64,
+                                    );
+                                }
+                            }
+                            None => bytes.fill(MaybeUninit::new(0)),
+                        },
+                    );
+                }
+
+                let len = builder.checked_len(count);
+                // SAFETY: The inner closure always initializes the entire buffer, because it calls `copy_from_slice` with an array of len 4
+                unsafe {
+                    builder.write_with(4, 0, |_buffer_position, bytes| {
+                        let len = len.to_le_bytes().map(MaybeUninit::new);
+                        bytes.copy_from_slice(&len);
+                    });
+                }
+                values_offset = builder.current_offset();
+
+                // SAFETY: The inner closure always initializes the entire buffer, because it writes one tag for each of the `count` values, or fills it with zeros.
+                unsafe {
+                    builder.write_with(count, u32::ALIGNMENT_MASK, |_buffer_position, bytes| {
+                        match values {
+                            Some(values) => {
+                                for (byte, value) in bytes.iter_mut().zip(values) {
+                                    *byte = MaybeUninit::new(value.tag);
+                                }
+                            }
+                            None => bytes.fill(MaybeUninit::new(0)),
+                        }
+                    });
+                }
+            },
+        );
+        assert!(
+            count == len && iter.next().is_none(),
+            "the iterator returned a different number of elements than its length"
+        );
+
+        let len = self.checked_len(count);
+        // SAFETY: The inner closure always initializes the entire buffer, because it calls `copy_from_slice` with an array of len 4
+        unsafe {
+            self.write_with(4, 0, |_buffer_position, bytes| {
+                let len = len.to_le_bytes().map(MaybeUninit::new);
+                bytes.copy_from_slice(&len);
+            });
+        }
+
+        let tags_offset = self.current_offset();
+        UnionVectorOffset {
+            tags_offset,
+            values_offset,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the largest alignment required by any object written so far.
+    ///
+    /// After calling [`finish`], this is the alignment the finished buffer
+    /// must be placed at for its contents to be correctly aligned.
+    ///
+    /// [`finish`]: Self::finish
+    pub fn alignment(&self) -> usize {
+        self.alignment_mask + 1
+    }
+
+    /// Returns a [`Checkpoint`] for the current state of the builder, which
+    /// can later be restored using [`rollback_to`].
+    ///
+    /// [`rollback_to`]: Self::rollback_to
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            len: self.inner.len(),
+            delayed_bytes: self.delayed_bytes,
+            alignment_mask: self.alignment_mask,
+            valid: self.invalid_object.is_none(),
+        }
+    }
+
+    /// Discards everything written since `checkpoint` was created.
+    ///
+    /// Any offsets returned after the checkpoint was created become invalid
+    /// and must not be used. Offsets returned before the checkpoint remain
+    /// valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is shorter than when the checkpoint was created,
+    /// which happens if the builder was cleared or rolled back to an earlier
+    /// checkpoint in the meantime.
+    ///
+    /// # Examples
+    /// ```
+    /// use planus::Builder;
+    /// use planus_example::monster_generated::my_game::sample::Weapon;
+    /// let mut builder = Builder::new();
+    /// let sword = Weapon::create(&mut builder, "Sword", 12);
+    ///
+    /// let checkpoint = builder.checkpoint();
+    /// let _axe = Weapon::create(&mut builder, "Axe", 24);
+    /// builder.rollback_to(checkpoint);
+    ///
+    /// builder.finish(sword, None);
+    /// ```
+    pub fn rollback_to(&mut self, checkpoint: Checkpoint) {
+        assert!(
+            checkpoint.len <= self.inner.len(),
+            "the checkpoint is not from the current state of the builder"
+        );
+        self.inner.truncate(checkpoint.len);
+        #[cfg(feature = "vtable-cache")]
+        self.vtable_cache.truncate(checkpoint.len);
+        #[cfg(feature = "string-cache")]
+        self.string_cache.truncate(checkpoint.len);
+        #[cfg(feature = "bytes-cache")]
+        self.bytes_cache.truncate(checkpoint.len);
+        if self
+            .unknown_union_data
+            .is_some_and(|data| data.offset > checkpoint.len)
+        {
+            self.unknown_union_data = None;
+        }
+        if self
+            .offset32_start
+            .is_some_and(|start| start >= checkpoint.len)
+        {
+            self.offset32_start = None;
+        }
+        self.delayed_bytes = checkpoint.delayed_bytes;
+        self.alignment_mask = checkpoint.alignment_mask;
+        if checkpoint.valid {
+            self.invalid_object = None;
+            self.copy_error = None;
+        }
+        #[cfg(debug_assertions)]
+        {
+            self.missing_bytes = 0;
+        }
+    }
+
     /// Resets the builders internal state and clears the internal buffer.
     pub fn clear(&mut self) {
         self.inner.clear();
@@ -138,12 +938,55 @@ impl Builder {
         self.bytes_cache.clear();
         self.delayed_bytes = 0;
         self.alignment_mask = 0;
+        self.invalid_object = None;
+        self.copy_error = None;
+        self.unknown_union_data = None;
+        self.offset32_start = None;
+        self.writing_offset64 = false;
         #[cfg(debug_assertions)]
         {
             self.missing_bytes = 0;
         }
     }
 
+    /// Records that the object just written contains an offset that did not
+    /// fit, so finishing the buffer fails.
+    pub(crate) fn mark_offset_out_of_range(&mut self) {
+        self.mark_invalid(BuilderError::OffsetOutOfRange);
+    }
+
+    /// Returns the length of a string or vector as written into the buffer,
+    /// and records an error if it does not fit in 32 bits.
+    pub(crate) fn checked_len(&mut self, len: usize) -> u32 {
+        u32::try_from(len).unwrap_or_else(|_| {
+            self.mark_invalid(BuilderError::LengthOutOfRange);
+            0
+        })
+    }
+
+    /// Records that the object just written is invalid, so finishing the
+    /// buffer fails with the given error.
+    fn mark_invalid(&mut self, error: BuilderError) {
+        self.invalid_object.get_or_insert(error);
+    }
+
+    /// Records that a value copied from another buffer could not be read, so
+    /// finishing the buffer fails.
+    pub(crate) fn mark_copy_error(&mut self, error: Error) {
+        if self.invalid_object.is_none() {
+            self.copy_error = Some(error);
+        }
+        self.mark_invalid(BuilderError::InvalidCopySource);
+    }
+
+    #[doc(hidden)]
+    /// Records that a value copied from another buffer could not be read and
+    /// returns an offset to use in place of it.
+    pub fn copy_failed<T: ?Sized>(&mut self, error: Error) -> Offset<T> {
+        self.mark_copy_error(error);
+        self.current_offset()
+    }
+
     pub(crate) fn prepare_write(&mut self, size: usize, alignment_mask: usize) -> usize {
         debug_assert!((alignment_mask + 1) & alignment_mask == 0); // Check that the alignment is a power of two
         #[cfg(debug_assertions)]
@@ -158,6 +1001,13 @@ impl Builder {
         // that we know we have enough space
         self.inner.extend_with_zeros(needed_padding);
 
+        if !self.writing_offset64 {
+            let start = *self.offset32_start.get_or_insert(self.inner.len());
+            if self.inner.len() - start + size > u32::MAX as usize {
+                self.mark_offset_out_of_range();
+            }
+        }
+
         debug_assert_eq!(self.delayed_bytes & alignment_mask, 0);
 
         #[cfg(debug_assertions)]
@@ -176,27 +1026,57 @@ impl Builder {
         }
     }
 
-    pub(crate) fn write_vtable(&mut self, vtable: &[u8]) -> usize {
+    /// Writes a vtable, or reuses an identical one. The table using it must
+    /// be written directly afterwards and be at most `max_table_size` bytes
+    /// including padding, so a reused vtable can be kept within reach of the
+    /// 32-bit signed offset pointing to it.
+    pub(crate) fn write_vtable(
+        &mut self,
+        vtable: &[u8],
+        #[allow(unused_variables)] max_table_size: usize,
+    ) -> usize {
         const VTABLE_ALIGNMENT: usize = 2;
         const VTABLE_ALIGNMENT_MASK: usize = VTABLE_ALIGNMENT - 1;
 
         #[cfg(feature = "vtable-cache")]
-        let hash = {
+        let hash = if self.caches_enabled() {
             let hash = self.vtable_cache.hash(vtable);
-            if let Some(offset) = self.vtable_cache.get(self.inner.as_slice(), hash, vtable) {
+            let max_distance = (1usize << 31).saturating_sub(max_table_size);
+            let min_offset = self.inner.len().saturating_sub(max_distance);
+            if let Some(offset) =
+                self.vtable_cache
+                    .get(self.inner.as_slice(), hash, vtable, min_offset)
+            {
                 return offset.into();
             }
-            hash
+            Some(hash)
+        } else {
+            None
         };
 
         let offset = self.prepare_write(vtable.len(), VTABLE_ALIGNMENT_MASK);
         self.write(vtable);
         #[cfg(feature = "vtable-cache")]
-        self.vtable_cache
-            .insert(hash, offset.try_into().unwrap(), self.inner.as_slice());
+        if let Some(hash) = hash.filter(|_| self.caches_enabled()) {
+            self.vtable_cache
+                .insert(hash, offset.into(), self.inner.as_slice());
+        }
         offset
     }
 
+    /// Returns whether the vtable, string and bytes caches should be used,
+    /// which is not the case when writing into a fixed buffer, once the data
+    /// no longer fits or while writing objects behind 64-bit offsets, which
+    /// 32-bit offsets must not point to.
+    #[cfg(any(
+        feature = "vtable-cache",
+        feature = "string-cache",
+        feature = "bytes-cache"
+    ))]
+    pub(crate) fn caches_enabled(&self) -> bool {
+        !self.inner.is_fixed() && self.inner.overflow() == 0 && !self.writing_offset64
+    }
+
     pub(crate) fn write(&mut self, buffer: &[u8]) {
         #[cfg(debug_assertions)]
         {
@@ -215,9 +1095,9 @@ impl Builder {
         &mut self,
         size: usize,
         alignment_mask: usize,
-        f: impl FnOnce(u32, &mut [MaybeUninit<u8>]),
+        f: impl FnOnce(u64, &mut [MaybeUninit<u8>]),
     ) {
-        let offset = self.prepare_write(size, alignment_mask) as u32;
+        let offset = self.prepare_write(size, alignment_mask) as u64;
         self.inner.extend_write(size, |bytes| f(offset, bytes));
         #[cfg(debug_assertions)]
         {
@@ -250,36 +1130,282 @@ impl Builder {
     /// };
     /// let data = builder.finish(&weapon, None);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data did not fit into the buffer given to
+    /// [`from_buffer`] or within the size given to [`with_max_size`], or if
+    /// it could not be written for one of the other reasons listed in
+    /// [`BuilderError`]. Use [`try_finish`] to get an error instead.
+    ///
+    /// [`from_buffer`]: Self::from_buffer
+    /// [`with_max_size`]: Self::with_max_size
+    /// [`try_finish`]: Self::try_finish
     pub fn finish<T>(
         &mut self,
         root: impl WriteAsOffset<T>,
         file_identifier: Option<[u8; 4]>,
     ) -> &[u8] {
         let root = root.prepare(self);
+        self.write_header(root, file_identifier, false);
+        if let Err(error) = self.check_overflow() {
+            panic!("{error}");
+        }
+        self.as_slice()
+    }
+
+    /// Finish writing the internal buffer and return a byte slice of it, or
+    /// an error if the data does not fit within the size limits of the
+    /// builder.
+    ///
+    /// This works like [`finish`], except that it returns an error instead
+    /// of panicking. On errors, the builder is left as it was before the
+    /// call.
+    ///
+    /// The data must fit into the buffer given to [`from_buffer`] and within
+    /// the size given to [`with_max_size`]. While running, this method and the
+    /// other `try_*` methods also limit the data to `u32::MAX` bytes, so the
+    /// 32-bit offsets in the buffer can not overflow. Buffers with objects
+    /// behind 64-bit offsets that are larger than that can only be finished
+    /// using [`finish`].
+    ///
+    /// [`from_buffer`]: Self::from_buffer
+    /// [`with_max_size`]: Self::with_max_size
+    /// [`finish`]: Self::finish
+    pub fn try_finish<T>(
+        &mut self,
+        root: impl WriteAsOffset<T>,
+        file_identifier: Option<[u8; 4]>,
+    ) -> Result<&[u8], BuilderError> {
+        self.try_write(|builder| {
+            let root = root.prepare(builder);
+            builder.write_header(root, file_identifier, false);
+        })?;
+        Ok(self.as_slice())
+    }
+
+    /// Finish writing the internal buffer and return a byte slice of it,
+    /// prefixed by its size.
+    ///
+    /// This works like [`finish`], except that the buffer starts with a `u32`
+    /// containing the length of the rest of the buffer. This is the format
+    /// produced by `flatc` for size-prefixed buffers, and it can be read back
+    /// using [`ReadAsRoot::read_as_root_size_prefixed`].
+    ///
+    /// # Examples
+    /// ```
+    /// use planus::{Builder, ReadAsRoot};
+    /// use planus_example::monster_generated::my_game::sample::{Weapon, WeaponRef};
+    /// let mut builder = Builder::new();
+    /// let weapon = Weapon::create(&mut builder, "Axe", 24);
+    /// let data = builder.finish_size_prefixed(weapon, None);
+    /// assert_eq!(data[..4], ((data.len() - 4) as u32).to_le_bytes());
+    ///
+    /// let weapon = WeaponRef::read_as_root_size_prefixed(data).unwrap();
+    /// assert_eq!(weapon.damage().unwrap(), 24);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`finish`], and if the buffer is larger
+    /// than 4 GiB. Use [`try_finish_size_prefixed`] to get an error instead.
+    ///
+    /// [`finish`]: Self::finish
+    /// [`try_finish_size_prefixed`]: Self::try_finish_size_prefixed
+    /// [`ReadAsRoot::read_as_root_size_prefixed`]: crate::ReadAsRoot::read_as_root_size_prefixed
+    pub fn finish_size_prefixed<T>(
+        &mut self,
+        root: impl WriteAsOffset<T>,
+        file_identifier: Option<[u8; 4]>,
+    ) -> &[u8] {
+        let root = root.prepare(self);
+        self.write_header(root, file_identifier, true);
+        if let Err(error) = self.check_overflow() {
+            panic!("{error}");
+        }
+        self.as_slice()
+    }
 
+    /// Finish writing the internal buffer and return a byte slice of it,
+    /// prefixed by its size, or an error if the data does not fit within the
+    /// size limits of the builder.
+    ///
+    /// This works like [`finish_size_prefixed`], except that it returns an
+    /// error instead of panicking, in the same way as [`try_finish`].
+    ///
+    /// [`finish_size_prefixed`]: Self::finish_size_prefixed
+    /// [`try_finish`]: Self::try_finish
+    pub fn try_finish_size_prefixed<T>(
+        &mut self,
+        root: impl WriteAsOffset<T>,
+        file_identifier: Option<[u8; 4]>,
+    ) -> Result<&[u8], BuilderError> {
+        self.try_write(|builder| {
+            let root = root.prepare(builder);
+            builder.write_header(root, file_identifier, true);
+        })?;
+        Ok(self.as_slice())
+    }
+
+    /// Finish writing the internal buffer and return it as a `Vec<u8>`.
+    ///
+    /// This works like [`finish`], except that the buffer is moved out of the
+    /// builder instead of being borrowed from it. The data is moved to the
+    /// front of the existing allocation, so no new allocation or copy into
+    /// a new buffer is needed. The builder is cleared and starts over with a
+    /// new, small allocation. To avoid that allocation growing again, pass
+    /// a buffer that is no longer needed to [`reuse_vec`].
+    ///
+    /// # Examples
+    /// ```
+    /// use planus::{Builder, ReadAsRoot};
+    /// use planus_example::monster_generated::my_game::sample::{Weapon, WeaponRef};
+    /// let mut builder = Builder::new();
+    /// let weapon = Weapon::create(&mut builder, "Axe", 24);
+    /// let data: Vec<u8> = builder.finish_into_vec(weapon, None);
+    /// assert!(builder.is_empty());
+    ///
+    /// let weapon = WeaponRef::read_as_root(&data).unwrap();
+    /// assert_eq!(weapon.damage().unwrap(), 24);
+    ///
+    /// // Let the builder use the allocation for the next buffer
+    /// builder.reuse_vec(data);
+    /// ```
+    ///
+    /// [`finish`]: Self::finish
+    /// [`reuse_vec`]: Self::reuse_vec
+    #[cfg(feature = "alloc")]
+    pub fn finish_into_vec<T>(
+        &mut self,
+        root: impl WriteAsOffset<T>,
+        file_identifier: Option<[u8; 4]>,
+    ) -> alloc::vec::Vec<u8> {
+        let root = root.prepare(self);
+        self.write_header(root, file_identifier, false);
+        if let Err(error) = self.check_overflow() {
+            panic!("{error}");
+        }
+        if self.inner.is_fixed() {
+            let vec = self.as_slice().to_vec();
+            self.clear();
+            return vec;
+        }
+        let inner = core::mem::replace(&mut self.inner, BackVec::with_capacity(0));
+        self.inner.set_max_len(inner.max_len());
+        self.clear();
+        inner.into_vec()
+    }
+
+    /// Clears the builder and gives it the allocation of `vec` to write into,
+    /// if it is larger than the current one.
+    ///
+    /// This is meant for buffers returned by [`finish_into_vec`] that are no
+    /// longer needed, so a builder can be reused without allocating. The
+    /// contents of `vec` are discarded. Builders created using
+    /// [`from_buffer`] keep writing into their buffer.
+    ///
+    /// [`finish_into_vec`]: Self::finish_into_vec
+    /// [`from_buffer`]: Self::from_buffer
+    #[cfg(feature = "alloc")]
+    pub fn reuse_vec(&mut self, vec: alloc::vec::Vec<u8>) {
+        if !self.inner.is_fixed() && vec.capacity() > self.inner.capacity() {
+            let max_len = self.inner.max_len();
+            self.inner = BackVec::from_vec(vec);
+            self.inner.set_max_len(max_len);
+        }
+        self.clear();
+    }
+
+    /// Runs `f` with the size of the data limited to `u32::MAX` bytes, and
+    /// rolls back its writes if the data did not fit.
+    fn try_write<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> Result<R, BuilderError> {
+        let checkpoint = self.checkpoint();
+        let max_len = self.inner.max_len();
+        self.inner.set_max_len(max_len.min(u32::MAX as usize));
+        let result = f(self);
+        let overflow = self.check_overflow();
+        self.inner.set_max_len(max_len);
+        if let Err(error) = overflow {
+            self.rollback_to(checkpoint);
+            return Err(error);
+        }
+        Ok(result)
+    }
+
+    fn check_overflow(&self) -> Result<(), BuilderError> {
+        if self.inner.overflow() == 0 {
+            match self.invalid_object {
+                Some(error) => Err(error),
+                None => Ok(()),
+            }
+        } else if self.inner.is_fixed() && self.inner.max_len() == self.inner.capacity() {
+            // Vectors written into fixed buffers may have needed more room
+            // than the length of the data, see `with_prepared_values`
+            let needed = match self.invalid_object {
+                Some(BuilderError::BufferTooSmall { needed, .. }) => needed.max(self.inner.len()),
+                _ => self.inner.len(),
+            };
+            Err(BuilderError::BufferTooSmall {
+                capacity: self.inner.capacity(),
+                needed,
+            })
+        } else {
+            Err(BuilderError::MaxSizeExceeded {
+                max_size: self.inner.max_len(),
+                needed: self.inner.len(),
+            })
+        }
+    }
+
+    fn write_header<T>(
+        &mut self,
+        root: Offset<T>,
+        file_identifier: Option<[u8; 4]>,
+        size_prefixed: bool,
+    ) {
+        // The header is the optional size prefix, followed by the root offset
+        // and the optional file identifier. It is written as a single unit
+        // aligned to the largest alignment in the buffer, so every object
+        // keeps its alignment relative to the start of the buffer.
+        let prefix_size = if size_prefixed { 4 } else { 0 };
+        let identifier_size = if file_identifier.is_some() { 4 } else { 0 };
+        let header_size = prefix_size + 4 + identifier_size;
+        let offset = self.prepare_write(
+            header_size,
+            <Offset<T> as Primitive>::ALIGNMENT_MASK.max(self.alignment_mask),
+        ) as u64;
+
+        if !self.inner.reserve(header_size) {
+            // The header does not fit within the size limits, so it is only
+            // counted like the other data that did not fit
+            self.write(&[0; 12][..header_size]);
+            return;
+        }
+
+        // The buffer is written back to front, so the header fields are
+        // written in reverse order.
         if let Some(file_identifier) = file_identifier {
-            // TODO: how does alignment interact with file identifiers? Is the alignment with out without the header?
-            let offset = self.prepare_write(
-                8,
-                <Offset<T> as Primitive>::ALIGNMENT_MASK.max(self.alignment_mask),
-            ) as u32;
-            self.write(&(offset - 4 - root.offset).to_le_bytes());
             self.write(&file_identifier);
-        } else {
-            let offset = self.prepare_write(
-                4,
-                <Offset<T> as Primitive>::ALIGNMENT_MASK.max(self.alignment_mask),
-            ) as u32;
-            self.write(&(offset - root.offset).to_le_bytes());
+        }
+        let root_offset = ((offset - prefix_size as u64) as u32).wrapping_sub(root.offset);
+        self.write(&root_offset.to_le_bytes());
+        if size_prefixed {
+            let size = u32::try_from(offset - 4).unwrap_or_else(|_| {
+                self.mark_invalid(BuilderError::SizePrefixOutOfRange);
+                0
+            });
+            self.write(&size.to_le_bytes());
         }
         debug_assert_eq!(self.delayed_bytes, 0);
-        self.as_slice()
     }
 
     /// Returns a reference to the current data buffer.
     ///
     /// It will return the same slice as the one return by [`finish`], unless additional data has been appened afterwards.
+    /// If the builder was created using [`from_buffer`] and the data did not
+    /// fit, only the part that was written is returned.
     ///
+    /// [`from_buffer`]: Self::from_buffer
     /// [`finish`]: Self::finish
     pub fn as_slice(&self) -> &[u8] {
         self.inner.as_slice()
@@ -291,6 +1417,7 @@ mod tests {
     use rand::RngExt;
 
     use super::*;
+    use crate::UnionOffset;
 
     #[test]
     fn test_buffer_random() {
@@ -372,4 +1499,293 @@ mod tests {
             b"\x08\x00\x00\x00\x00\x00\x00\x00ABCDEFGH\x00\x00YXIJKL"
         );
     }
+
+    #[test]
+    fn test_rollback() {
+        let mut expected = Builder::new();
+        let first = expected.create_string("first");
+        expected.create_string("first");
+        expected.create_vector([1u16, 2, 3]);
+        expected.create_string("second");
+
+        let mut builder = Builder::new();
+        builder.create_string("first");
+        let checkpoint = builder.checkpoint();
+        builder.create_string("second");
+        builder.create_vector([1u64, 2]);
+        builder.rollback_to(checkpoint);
+        let first_again = builder.create_string("first");
+        // Strings written before the checkpoint are still reused
+        #[cfg(feature = "string-cache")]
+        assert_eq!(first_again.offset, first.offset);
+        #[cfg(not(feature = "string-cache"))]
+        assert_ne!(first_again.offset, first.offset);
+        builder.create_vector([1u16, 2, 3]);
+        // Must not reuse the string written before the rollback
+        builder.create_string("second");
+        assert_eq!(builder.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn test_finish_into_vec() {
+        let mut builder = Builder::new();
+        builder.create_string("hello");
+        let expected = builder
+            .finish(builder.current_offset::<()>(), None)
+            .to_vec();
+
+        builder.clear();
+        builder.create_string("hello");
+        let data = builder.finish_into_vec(builder.current_offset::<()>(), None);
+        assert_eq!(data, expected);
+        assert!(builder.is_empty());
+
+        // The builder must not use the cache from the previous buffer
+        builder.create_string("hello");
+        assert_eq!(
+            builder.finish(builder.current_offset::<()>(), None),
+            expected
+        );
+
+        let capacity = data.capacity();
+        builder.reuse_vec(data);
+        assert!(builder.is_empty());
+        assert_eq!(builder.inner.capacity(), capacity);
+        builder.create_string("hello");
+        assert_eq!(
+            builder.finish(builder.current_offset::<()>(), None),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_max_size() {
+        let mut builder = Builder::with_max_size(40);
+        let hello = builder.create_string("hello");
+        builder.create_string("world");
+        assert_eq!(builder.len(), 22);
+
+        // A write that does not fit is rolled back by the try_* methods
+        assert_eq!(
+            builder.try_create_vector(&[1u64, 2, 3][..]).map(|_| ()),
+            Err(BuilderError::MaxSizeExceeded {
+                max_size: 40,
+                needed: 50,
+            })
+        );
+        assert_eq!(builder.len(), 22);
+        assert!(builder.inner.capacity() <= 40);
+
+        // The cache is not used while the data does not fit, and entries
+        // from before still work after rolling back
+        let checkpoint = builder.checkpoint();
+        builder.create_string("a string that is too long");
+        builder.create_string("hello");
+        assert!(builder
+            .try_finish(builder.current_offset::<()>(), None)
+            .is_err());
+        builder.rollback_to(checkpoint);
+        if cfg!(feature = "string-cache") {
+            assert_eq!(builder.create_string("hello").offset, hello.offset);
+        }
+
+        let data = builder
+            .try_finish(builder.current_offset::<()>(), None)
+            .unwrap();
+        assert_eq!(data.len(), 26);
+    }
+
+    #[test]
+    fn test_finish_into_vec_max_size() {
+        let mut builder = Builder::with_max_size(40);
+        builder.create_string("hello");
+        let data = builder.finish_into_vec(builder.current_offset::<()>(), None);
+        assert!(builder
+            .try_create_vector(&[0u8; 40][..])
+            .is_err_and(|error| error
+                == BuilderError::MaxSizeExceeded {
+                    max_size: 40,
+                    needed: 44,
+                }));
+
+        builder.reuse_vec(alloc::vec::Vec::with_capacity(data.capacity().max(64)));
+        assert_eq!(builder.inner.max_len(), 40);
+        let name = builder.create_string("a string of thirty-one bytes...");
+        assert_eq!(
+            builder.try_finish_size_prefixed(name.downcast(), None),
+            Err(BuilderError::MaxSizeExceeded {
+                max_size: 40,
+                needed: 44,
+            })
+        );
+        let data = builder.try_finish(name.downcast(), None).unwrap();
+        assert_eq!(data.len(), 40);
+    }
+
+    #[test]
+    fn test_vector_from_iter() {
+        let mut from_slice = Builder::new();
+        let mut from_iter = Builder::new();
+        for builder in [&mut from_slice, &mut from_iter] {
+            builder.create_string("unaligned");
+        }
+        let name = from_slice.create_string("name");
+        from_iter.create_string("name");
+        from_slice.create_vector(&[1u16, 2, 3][..]);
+        from_slice.create_vector([name, name]);
+        from_iter.create_vector_from_iter(1..4u16);
+        from_iter.create_vector_from_iter(core::iter::repeat_n(name, 2));
+        assert_eq!(from_slice.as_slice(), from_iter.as_slice());
+    }
+
+    #[test]
+    fn test_fixed_buffer_vectors() {
+        fn write_vectors(builder: &mut BufferBuilder<'_>) {
+            builder.create_vector(&["axe", "sword", "bow"][..]);
+            let values = ["shield", "helm", "boots"]
+                .map(|name| UnionOffset::new(1, builder.create_string(name).downcast()));
+            builder.create_union_vector_from_iter::<_, (), _>(values);
+        }
+        let mut expected = Builder::new();
+        write_vectors(&mut expected);
+        let expected = expected.finish(expected.current_offset::<()>(), None);
+
+        let mut buffer = [0; 256];
+        let mut builder = BufferBuilder::from_buffer(&mut buffer);
+        write_vectors(&mut builder);
+        let data = builder
+            .try_finish(builder.current_offset::<()>(), None)
+            .unwrap();
+        assert_eq!(data, expected);
+
+        // The values are prepared in the unused part of the buffer, so this
+        // fails even though the finished data would fit, and the error
+        // includes the room needed for the values
+        let len = data.len();
+        let mut buffer = [0; 256];
+        let mut builder = BufferBuilder::from_buffer(&mut buffer[..len]);
+        write_vectors(&mut builder);
+        match builder.try_finish(builder.current_offset::<()>(), None) {
+            Err(BuilderError::BufferTooSmall { capacity, needed }) => {
+                assert_eq!(capacity, len);
+                assert!(needed > len);
+            }
+            result => panic!("unexpected result {result:?}"),
+        }
+    }
+
+    #[test]
+    #[should_panic = "different number of elements"]
+    fn test_vector_from_iter_wrong_len() {
+        struct WrongLen(core::ops::Range<u32>);
+        impl Iterator for WrongLen {
+            type Item = u32;
+            fn next(&mut self) -> Option<u32> {
+                self.0.next()
+            }
+        }
+        impl ExactSizeIterator for WrongLen {
+            fn len(&self) -> usize {
+                self.0.len() + 1
+            }
+        }
+        Builder::new().create_vector_from_iter(WrongLen(0..3));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_offset_out_of_range() {
+        // Only 64-bit offsets take up 8 bytes
+        assert_eq!(core::mem::size_of::<Offset<str>>(), 4);
+        assert_eq!(core::mem::size_of::<UnionOffset<()>>(), 8);
+        assert_eq!(core::mem::size_of::<Offset64<str>>(), 8);
+
+        // Objects behind 64-bit offsets do not count towards the 4 GiB limit
+        let mut builder = Builder::new();
+        let far = builder.prepare_offset64(|builder| {
+            builder.inner.add_sparse_bytes(1 << 32);
+            builder.create_string("far")
+        });
+        assert_eq!(far.offset, (1 << 32) + 8);
+        let near = builder.create_string("near");
+        let mut table_writer = crate::table_writer::TableWriter::<8>::default();
+        table_writer.write_entry::<Offset64<str>>(0);
+        table_writer.write_entry::<Offset<str>>(1);
+        unsafe {
+            table_writer.finish(&mut builder, |object_writer| {
+                object_writer.write::<_, _, 8>(&far);
+                object_writer.write::<_, _, 4>(&near);
+            });
+        }
+        builder.create_vector([near]);
+        assert_eq!(builder.check_overflow(), Ok(()));
+
+        // Buffers this large can be finished, but not with a size prefix
+        let checkpoint = builder.checkpoint();
+        builder.finish(near.downcast(), None);
+        builder.rollback_to(checkpoint);
+        builder.write_header(near.downcast(), None, true);
+        assert_eq!(
+            builder.check_overflow(),
+            Err(BuilderError::SizePrefixOutOfRange)
+        );
+
+        // Objects reachable through 32-bit offsets must fit in 4 GiB
+        let mut builder = Builder::new();
+        let near = builder.create_string("near");
+        builder.inner.add_sparse_bytes(1 << 32);
+        builder.create_vector([near]);
+        assert_eq!(
+            builder.check_overflow(),
+            Err(BuilderError::OffsetOutOfRange)
+        );
+
+        // Including objects behind 64-bit offsets created after them
+        let mut builder = Builder::new();
+        builder.create_string("near");
+        builder.prepare_offset64(|builder| {
+            builder.inner.add_sparse_bytes(1 << 32);
+            builder.create_string("far")
+        });
+        builder.create_string("near");
+        assert_eq!(
+            builder.check_overflow(),
+            Err(BuilderError::OffsetOutOfRange)
+        );
+
+        let mut builder = Builder::new();
+        assert_eq!(builder.checked_len(1 << 32), 0);
+        assert_eq!(
+            builder.check_overflow(),
+            Err(BuilderError::LengthOutOfRange)
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "vtable-cache", target_pointer_width = "64"))]
+    fn test_vtable_reuse_distance() {
+        fn write_table(builder: &mut BufferBuilder<'_>) -> usize {
+            let len = builder.len();
+            let mut table_writer = crate::table_writer::TableWriter::<6>::default();
+            table_writer.write_entry::<u32>(0);
+            unsafe {
+                table_writer.finish(builder, |object_writer| {
+                    object_writer.write::<_, _, 4>(&1u32);
+                });
+            }
+            builder.len() - len
+        }
+
+        let mut builder = Builder::new();
+        let table_with_vtable = write_table(&mut builder);
+        let table_without_vtable = write_table(&mut builder);
+        assert!(table_without_vtable < table_with_vtable);
+
+        // Vtables can be reused up to 2 GiB away from the table
+        builder.inner.add_sparse_bytes(3 << 29);
+        assert_eq!(write_table(&mut builder), table_without_vtable);
+        builder.inner.add_sparse_bytes(1 << 30);
+        assert!(write_table(&mut builder) > table_without_vtable);
+        assert_eq!(builder.check_overflow(), Ok(()));
+    }
 }
@@ -2,10 +2,16 @@ use core::{hash::BuildHasher, marker::PhantomData};
 
 use crate::Offset;
 
+/// The largest distance from the current end of the buffer at which cached
+/// strings and byte vectors are reused, since 32-bit offsets can not point
+/// further. If the buffer grows past this before the offset is written, the
+/// builder reports an error, as it would for any other object.
+pub(crate) const MAX_OFFSET_DISTANCE: usize = u32::MAX as usize;
+
 #[derive(Copy, Clone, Debug)]
 #[repr(transparent)]
 /// Backwards offset from the end of the serialized buffer
-pub(crate) struct CacheOffset(u32);
+pub(crate) struct CacheOffset(u64);
 
 pub(crate) trait GetCacheKey {
     /// Gets the cachable byte-slice at the start of the buffer
@@ -21,23 +27,15 @@ pub(crate) trait GetCacheKey {
 impl<T: ?Sized> From<CacheOffset> for Offset<T> {
     fn from(offset: CacheOffset) -> Self {
         Self {
-            offset: offset.0,
+            offset: offset.0 as u32,
             phantom: PhantomData,
         }
     }
 }
 
-impl<T: ?Sized> From<Offset<T>> for CacheOffset {
-    fn from(offset: Offset<T>) -> Self {
-        Self(offset.offset)
-    }
-}
-
-impl TryFrom<usize> for CacheOffset {
-    type Error = <u32 as TryFrom<usize>>::Error;
-
-    fn try_from(value: usize) -> Result<Self, Self::Error> {
-        u32::try_from(value).map(Self)
+impl From<usize> for CacheOffset {
+    fn from(value: usize) -> Self {
+        Self(value as u64)
     }
 }
 
@@ -92,16 +90,21 @@ impl<C: GetCacheKey> Cache<C> {
         self.hash_builder.hash_one(serialized_data)
     }
 
+    /// Looks up a cached object, ignoring objects written before the buffer
+    /// reached `min_offset` bytes, since the offsets pointing to them would
+    /// not fit.
     pub(crate) fn get(
         &mut self,
         serialized_data: &[u8],
         key_hash: u64,
         key: &[u8],
+        min_offset: usize,
     ) -> Option<CacheOffset> {
         self.cache
             .find(key_hash, |back_offset| {
-                C::get_cache_key(serialized_data, *back_offset)
-                    .is_some_and(|old_key| old_key == key)
+                usize::from(*back_offset) >= min_offset
+                    && C::get_cache_key(serialized_data, *back_offset)
+                        .is_some_and(|old_key| old_key == key)
             })
             .copied()
     }
@@ -125,4 +128,12 @@ impl<C: GetCacheKey> Cache<C> {
     pub(crate) fn clear(&mut self) {
         self.cache.clear();
     }
+
+    /// Removes all entries for objects that end more than `len` bytes from the
+    /// end of the buffer, i.e. objects that are removed when truncating the
+    /// buffer to `len` bytes.
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.cache
+            .retain(|back_offset| usize::from(*back_offset) <= len);
+    }
 }
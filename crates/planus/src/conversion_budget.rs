@@ -0,0 +1,160 @@
+use crate::{errors::ErrorKind, Error, Result, TryFromBudgeted};
+
+/// A limit on the amount of memory allocated when converting reference types
+/// into owned types using [`TryFromBudgeted`].
+///
+/// Since several parts of a buffer can refer to the same data, the owned
+/// version of a buffer can be much larger than the buffer itself, and a
+/// small hostile buffer can expand into gigabytes when converted using
+/// [`TryFrom`]. The budget counts the bytes needed for the contents of the
+/// strings, vectors and boxes of the owned value, and the conversion fails
+/// with [`ErrorKind::AllocationLimitExceeded`] once the limit is exceeded.
+///
+/// The budget does not limit the nesting depth of the data. Verify the buffer
+/// using [`VerifierOptions`] before converting it to limit that as well.
+///
+/// # Examples
+/// ```
+/// use planus::{Builder, ConversionBudget, ReadAsRoot, TryFromBudgeted};
+/// use planus_example::monster_generated::my_game::sample::{Weapon, WeaponRef};
+/// let mut builder = Builder::new();
+/// let weapon = Weapon::create(&mut builder, "Axe", 24);
+/// let data = builder.finish(weapon, None);
+/// let weapon_ref = WeaponRef::read_as_root(data).unwrap();
+///
+/// let mut budget = ConversionBudget::new(1024);
+/// let weapon = Weapon::try_from_budgeted(weapon_ref, &mut budget).unwrap();
+/// assert_eq!(budget.used(), 3);
+///
+/// let mut budget = ConversionBudget::new(2);
+/// assert!(Weapon::try_from_budgeted(weapon_ref, &mut budget).is_err());
+/// ```
+///
+/// [`VerifierOptions`]: crate::VerifierOptions
+#[derive(Clone, Debug)]
+pub struct ConversionBudget {
+    limit: usize,
+    used: usize,
+    // The type name and offset of the table currently being converted, used
+    // as the location of errors for values that do not know their offset
+    table: Option<(&'static str, usize)>,
+}
+
+impl ConversionBudget {
+    /// Creates a new budget allowing conversions to allocate at most
+    /// `max_bytes` bytes in total.
+    pub fn new(max_bytes: usize) -> Self {
+        Self {
+            limit: max_bytes,
+            used: 0,
+            table: None,
+        }
+    }
+
+    /// Returns the number of bytes used so far.
+    pub fn used(&self) -> usize {
+        self.used
+    }
+
+    /// Returns the number of bytes still available.
+    pub fn remaining(&self) -> usize {
+        self.limit.saturating_sub(self.used)
+    }
+
+    #[doc(hidden)]
+    pub fn allocate(&mut self, amount: usize) -> core::result::Result<(), ErrorKind> {
+        self.used = self.used.saturating_add(amount);
+        if self.used > self.limit {
+            Err(ErrorKind::AllocationLimitExceeded { limit: self.limit })
+        } else {
+            Ok(())
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn allocate_array<T>(&mut self, len: usize) -> core::result::Result<(), ErrorKind> {
+        self.allocate(len.saturating_mul(core::mem::size_of::<T>()))
+    }
+
+    /// Like [`allocate`](Self::allocate), but returns the error located at
+    /// the table currently being converted. Outside of tables, the error is
+    /// located at `type_` without an offset.
+    #[doc(hidden)]
+    pub fn allocate_in_table(&mut self, amount: usize, type_: &'static str) -> Result<()> {
+        self.allocate(amount).map_err(|error_kind| {
+            let (type_, byte_offset) = self.table.unwrap_or((type_, usize::MAX));
+            error_kind.with_error_location(type_, "try_from_budgeted", byte_offset)
+        })
+    }
+
+    /// Runs `f` to convert the fields of the table `type_` at `byte_offset`.
+    #[doc(hidden)]
+    pub fn in_table<T>(
+        &mut self,
+        type_: &'static str,
+        byte_offset: usize,
+        f: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> Result<T> {
+        let table = self.table.replace((type_, byte_offset));
+        let result = f(self);
+        self.table = table;
+        result
+    }
+
+    #[doc(hidden)]
+    pub fn boxed<T, O: TryFromBudgeted<T>>(&mut self, value: T) -> Result<alloc::boxed::Box<O>> {
+        self.allocate_in_table(core::mem::size_of::<O>(), "Box")?;
+        Ok(alloc::boxed::Box::new(O::try_from_budgeted(value, self)?))
+    }
+}
+
+macro_rules! gen_primitive_impls {
+    ($($ty:ty),*) => {
+        $(
+            impl TryFromBudgeted<$ty> for $ty {
+                #[inline]
+                fn try_from_budgeted(value: $ty, _budget: &mut ConversionBudget) -> Result<Self> {
+                    Ok(value)
+                }
+            }
+        )*
+    };
+}
+
+gen_primitive_impls!(bool, u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+
+impl<'a> TryFromBudgeted<&'a str> for alloc::string::String {
+    fn try_from_budgeted(value: &'a str, budget: &mut ConversionBudget) -> Result<Self> {
+        budget.allocate_in_table(value.len(), "str")?;
+        Ok(value.into())
+    }
+}
+
+impl<'a, T: Copy> TryFromBudgeted<&'a [T]> for alloc::vec::Vec<T> {
+    fn try_from_budgeted(value: &'a [T], budget: &mut ConversionBudget) -> Result<Self> {
+        budget.allocate_in_table(value.len().saturating_mul(core::mem::size_of::<T>()), "[T]")?;
+        Ok(value.to_vec())
+    }
+}
+
+impl<T, O: TryFromBudgeted<T>> TryFromBudgeted<Option<T>> for Option<O> {
+    #[inline]
+    fn try_from_budgeted(value: Option<T>, budget: &mut ConversionBudget) -> Result<Self> {
+        value
+            .map(|value| O::try_from_budgeted(value, budget))
+            .transpose()
+    }
+}
+
+impl<T, E, O: TryFromBudgeted<T>> TryFromBudgeted<core::result::Result<T, E>> for O
+where
+    Error: From<E>,
+{
+    #[inline]
+    fn try_from_budgeted(
+        value: core::result::Result<T, E>,
+        budget: &mut ConversionBudget,
+    ) -> Result<Self> {
+        O::try_from_budgeted(value?, budget)
+    }
+}
@@ -1,15 +1,148 @@
 /// The main error type for Planus
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone)]
 pub struct Error {
     /// The location of the error
     pub source_location: ErrorLocation,
     /// The kind of error
     pub error_kind: ErrorKind,
+    #[cfg(feature = "error-path")]
+    path: ErrorPath,
+}
+
+#[cfg(not(feature = "error-path"))]
+static EMPTY_PATH: ErrorPath = ErrorPath {};
+
+impl Error {
+    /// The path from the value being converted to the value where the
+    /// error was encountered, such as `weapons[3].name`.
+    ///
+    /// The path is collected by the generated conversions into owned types
+    /// and by [`Vector::to_vec`](crate::Vector::to_vec). It is always empty
+    /// unless the `error-path` feature is enabled.
+    pub fn path(&self) -> &ErrorPath {
+        #[cfg(feature = "error-path")]
+        {
+            &self.path
+        }
+        #[cfg(not(feature = "error-path"))]
+        {
+            &EMPTY_PATH
+        }
+    }
+
+    /// Adds a field access to the start of the path of this error.
+    #[inline]
+    pub fn with_field(self, field: &'static str) -> Self {
+        self.with_path_segment(PathSegment::Field(field))
+    }
+
+    /// Adds a vector index to the start of the path of this error.
+    #[inline]
+    pub fn with_index(self, index: usize) -> Self {
+        self.with_path_segment(PathSegment::Index(index))
+    }
+
+    #[cfg_attr(not(feature = "error-path"), allow(unused_mut, unused_variables))]
+    #[inline]
+    fn with_path_segment(mut self, segment: PathSegment) -> Self {
+        #[cfg(feature = "error-path")]
+        self.path.segments.push(segment);
+        self
+    }
+}
+
+impl core::fmt::Debug for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut f = f.debug_struct("Error");
+        f.field("source_location", &self.source_location);
+        f.field("error_kind", &self.error_kind);
+        let path = self.path();
+        if !path.is_empty() {
+            f.field("path", path);
+        }
+        f.finish()
+    }
 }
 
 impl core::fmt::Display for Error {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "In {}: {}", self.source_location, self.error_kind)
+        let path = self.path();
+        if path.is_empty() {
+            write!(f, "In {}: {}", self.source_location, self.error_kind)
+        } else {
+            write!(
+                f,
+                "In {} at {}: {}",
+                self.source_location, path, self.error_kind
+            )
+        }
+    }
+}
+
+#[doc(hidden)]
+/// Used in the conversions into owned types in generated code
+#[inline]
+pub fn in_field<T>(field: &'static str, f: impl FnOnce() -> crate::Result<T>) -> crate::Result<T> {
+    f().map_err(|error| error.with_field(field))
+}
+
+/// A single step in an [`ErrorPath`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PathSegment {
+    /// Access of a table or struct field.
+    Field(&'static str),
+    /// Access of a vector element.
+    Index(usize),
+}
+
+/// The path to the value where an [`Error`] was encountered.
+#[derive(Clone, Default)]
+pub struct ErrorPath {
+    // Stored innermost first, since that is the order the segments are
+    // added in
+    #[cfg(feature = "error-path")]
+    segments: alloc::vec::Vec<PathSegment>,
+}
+
+impl ErrorPath {
+    /// Whether paths are recorded, i.e. whether planus was built with the
+    /// `error-path` feature.
+    pub const ENABLED: bool = cfg!(feature = "error-path");
+
+    /// Returns true if the path has no segments.
+    pub fn is_empty(&self) -> bool {
+        self.segments().next().is_none()
+    }
+
+    /// Returns the segments of the path, starting from the outermost one.
+    pub fn segments(&self) -> impl Iterator<Item = PathSegment> + '_ {
+        #[cfg(feature = "error-path")]
+        {
+            self.segments.iter().rev().copied()
+        }
+        #[cfg(not(feature = "error-path"))]
+        {
+            core::iter::empty()
+        }
+    }
+}
+
+impl core::fmt::Debug for ErrorPath {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.segments()).finish()
+    }
+}
+
+impl core::fmt::Display for ErrorPath {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, segment) in self.segments().enumerate() {
+            match segment {
+                PathSegment::Field(field) if i == 0 => write!(f, "{field}")?,
+                PathSegment::Field(field) => write!(f, ".{field}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
     }
 }
 
@@ -61,6 +194,53 @@ pub enum ErrorKind {
     MissingRequired,
     /// A string null terminator was missing.
     MissingNullTerminator,
+    /// The verifier exceeded the maximum nesting depth of tables.
+    DepthLimitExceeded {
+        /// The configured maximum depth.
+        limit: usize,
+    },
+    /// The verifier exceeded the maximum number of tables.
+    TableLimitExceeded {
+        /// The configured maximum number of tables.
+        limit: usize,
+    },
+    /// The verifier exceeded the maximum number of bytes visited.
+    ByteLimitExceeded {
+        /// The configured maximum number of bytes.
+        limit: usize,
+    },
+    /// A conversion into an owned type exceeded its [`ConversionBudget`].
+    ///
+    /// [`ConversionBudget`]: crate::ConversionBudget
+    AllocationLimitExceeded {
+        /// The configured maximum number of bytes.
+        limit: usize,
+    },
+    /// The buffer did not have the expected file identifier.
+    InvalidFileIdentifier {
+        /// The file identifier that was expected.
+        expected: [u8; 4],
+        /// The file identifier found in the buffer.
+        found: [u8; 4],
+    },
+    /// The buffer had a file identifier that did not belong to any known root type.
+    UnknownFileIdentifier {
+        /// The file identifier found in the buffer.
+        found: [u8; 4],
+    },
+    /// A FlexBuffer contained a packed type that does not describe a valid value.
+    InvalidFlexBufferType {
+        /// The packed type byte.
+        packed_type: u8,
+    },
+    /// A FlexBuffer value was read as a different type than the one stored.
+    UnexpectedFlexBufferType {
+        /// The type of the stored value.
+        found: crate::flexbuffers::FlexBufferType,
+    },
+    /// A field could not be changed in place, because it is not present in
+    /// the buffer.
+    FieldNotPresent,
 }
 
 impl core::fmt::Display for ErrorKind {
@@ -82,6 +262,46 @@ impl core::fmt::Display for ErrorKind {
             ErrorKind::InvalidUtf8 { source } => write!(f, "Invalid utf-8: {source}"),
             ErrorKind::MissingRequired => write!(f, "Missing required field"),
             ErrorKind::MissingNullTerminator => write!(f, "Missing null terminator"),
+            ErrorKind::DepthLimitExceeded { limit } => {
+                write!(f, "Exceeded the maximum table depth (limit = {limit})")
+            }
+            ErrorKind::TableLimitExceeded { limit } => {
+                write!(f, "Exceeded the maximum number of tables (limit = {limit})")
+            }
+            ErrorKind::ByteLimitExceeded { limit } => {
+                write!(f, "Exceeded the maximum number of bytes (limit = {limit})")
+            }
+            ErrorKind::AllocationLimitExceeded { limit } => {
+                write!(
+                    f,
+                    "Exceeded the maximum number of bytes allocated (limit = {limit})"
+                )
+            }
+            ErrorKind::InvalidFileIdentifier { expected, found } => {
+                write!(
+                    f,
+                    "Invalid file identifier (expected = \"{}\", found = \"{}\")",
+                    expected.escape_ascii(),
+                    found.escape_ascii()
+                )
+            }
+            ErrorKind::UnknownFileIdentifier { found } => {
+                write!(
+                    f,
+                    "Unknown file identifier (found = \"{}\")",
+                    found.escape_ascii()
+                )
+            }
+            ErrorKind::InvalidFlexBufferType { packed_type } => {
+                write!(
+                    f,
+                    "Invalid FlexBuffer type (packed type = {packed_type:#04x})"
+                )
+            }
+            ErrorKind::UnexpectedFlexBufferType { found } => {
+                write!(f, "Unexpected FlexBuffer type (found = {found:?})")
+            }
+            ErrorKind::FieldNotPresent => write!(f, "Field not present in the buffer"),
         }
     }
 }
@@ -99,6 +319,15 @@ impl std::error::Error for ErrorKind {
             ErrorKind::InvalidUtf8 { source } => Some(source),
             ErrorKind::MissingRequired => None,
             ErrorKind::MissingNullTerminator => None,
+            ErrorKind::DepthLimitExceeded { .. } => None,
+            ErrorKind::TableLimitExceeded { .. } => None,
+            ErrorKind::ByteLimitExceeded { .. } => None,
+            ErrorKind::AllocationLimitExceeded { .. } => None,
+            ErrorKind::InvalidFileIdentifier { .. } => None,
+            ErrorKind::UnknownFileIdentifier { .. } => None,
+            ErrorKind::InvalidFlexBufferType { .. } => None,
+            ErrorKind::UnexpectedFlexBufferType { .. } => None,
+            ErrorKind::FieldNotPresent => None,
         }
     }
 }
@@ -115,6 +344,245 @@ impl From<core::str::Utf8Error> for ErrorKind {
     }
 }
 
+/// The possible errors in planus when writing data using a [`Builder`].
+///
+/// [`Builder`]: crate::Builder
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BuilderError {
+    /// The data did not fit into the buffer given to
+    /// [`BufferBuilder::from_buffer`](crate::BufferBuilder::from_buffer).
+    BufferTooSmall {
+        /// The size of the buffer
+        capacity: usize,
+        /// The size needed for the data written so far
+        needed: usize,
+    },
+    /// The data grew beyond the maximum size of the builder, as given to
+    /// [`BufferBuilder::with_max_size`](crate::BufferBuilder::with_max_size).
+    MaxSizeExceeded {
+        /// The maximum size of the data
+        max_size: usize,
+        /// The size needed for the data written so far
+        needed: usize,
+    },
+    /// The objects reachable through 32-bit offsets took up more than 4 GiB,
+    /// so an offset between them might not fit. This can only happen in
+    /// buffers with objects behind 64-bit offsets, which must be created
+    /// before the rest of the buffer.
+    OffsetOutOfRange,
+    /// A size-prefixed buffer was larger than 4 GiB, so its size did not fit
+    /// in the 32-bit size prefix.
+    SizePrefixOutOfRange,
+    /// A string or vector had more than `u32::MAX` elements, so its length
+    /// did not fit in 32 bits.
+    LengthOutOfRange,
+    /// A buffer given to
+    /// [`BufferBuilder::create_unknown_union_value`](crate::BufferBuilder::create_unknown_union_value)
+    /// did not start with an offset to a value inside it.
+    InvalidUnknownUnionValue,
+    /// A value copied from another buffer could not be read. Use
+    /// [`BufferBuilder::try_copy`](crate::BufferBuilder::try_copy) to get the error for it.
+    InvalidCopySource,
+}
+
+impl core::fmt::Display for BuilderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BuilderError::BufferTooSmall { capacity, needed } => {
+                write!(
+                    f,
+                    "The buffer is too small (capacity = {capacity}, needed = {needed})"
+                )
+            }
+            BuilderError::MaxSizeExceeded { max_size, needed } => {
+                write!(
+                    f,
+                    "Exceeded the maximum size of the builder (max size = {max_size}, needed = {needed})"
+                )
+            }
+            BuilderError::OffsetOutOfRange => {
+                write!(
+                    f,
+                    "An offset does not fit in 32 bits, objects behind 64-bit offsets must be created first"
+                )
+            }
+            BuilderError::SizePrefixOutOfRange => {
+                write!(
+                    f,
+                    "The size of a size-prefixed buffer does not fit in 32 bits"
+                )
+            }
+            BuilderError::LengthOutOfRange => {
+                write!(
+                    f,
+                    "The length of a string or vector does not fit in 32 bits"
+                )
+            }
+            BuilderError::InvalidUnknownUnionValue => {
+                write!(f, "The root offset of an unknown union value is invalid")
+            }
+            BuilderError::InvalidCopySource => {
+                write!(f, "A value copied from another buffer could not be read")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuilderError {}
+
+/// The possible errors when writing data using a
+/// [`FlexBufferBuilder`](crate::flexbuffers::FlexBufferBuilder).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FlexBufferBuilderError {
+    /// A key contained a null byte.
+    KeyContainsNull,
+    /// The entries of a map did not alternate between keys and values.
+    MissingKey,
+    /// The same key was used twice in a map.
+    DuplicateKey,
+    /// The elements of a typed vector did not all have the same type.
+    MixedTypedVector {
+        /// The type of the first element
+        expected: crate::flexbuffers::FlexBufferType,
+        /// The type of the first element with a different type
+        found: crate::flexbuffers::FlexBufferType,
+    },
+    /// A typed vector contained elements which are not integers, floats,
+    /// booleans or keys.
+    UnsupportedTypedVector {
+        /// The type of the elements
+        element_type: crate::flexbuffers::FlexBufferType,
+    },
+    /// The buffer was finished without exactly one value on the stack.
+    InvalidRoot {
+        /// The number of values on the stack
+        values: usize,
+    },
+}
+
+impl core::fmt::Display for FlexBufferBuilderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FlexBufferBuilderError::KeyContainsNull => {
+                write!(f, "FlexBuffer keys cannot contain null bytes")
+            }
+            FlexBufferBuilderError::MissingKey => write!(
+                f,
+                "FlexBuffer map entries must consist of a key followed by a value"
+            ),
+            FlexBufferBuilderError::DuplicateKey => {
+                write!(f, "FlexBuffer maps cannot contain duplicate keys")
+            }
+            FlexBufferBuilderError::MixedTypedVector { expected, found } => write!(
+                f,
+                "All elements of a typed FlexBuffer vector must have the same type (expected = {expected:?}, found = {found:?})"
+            ),
+            FlexBufferBuilderError::UnsupportedTypedVector { element_type } => write!(
+                f,
+                "Typed FlexBuffer vectors can only contain integers, floats, booleans or keys (found = {element_type:?})"
+            ),
+            FlexBufferBuilderError::InvalidRoot { values } => write!(
+                f,
+                "A FlexBuffer must contain exactly one root value (found = {values})"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FlexBufferBuilderError {}
+
+/// The possible errors when reading or writing framed messages using the
+/// [`stream`](crate::stream) module.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum StreamError {
+    /// The underlying reader or writer returned an error.
+    Io(std::io::Error),
+    /// A message was larger than the maximum message size.
+    MessageTooLarge {
+        /// The size of the message
+        size: usize,
+        /// The maximum size of a message
+        max_size: usize,
+    },
+    /// The stream ended in the middle of a frame.
+    TruncatedFrame {
+        /// The number of bytes in the frame, including the length prefix. If
+        /// the length prefix itself was cut short, this is only its size.
+        expected: u64,
+        /// The number of bytes of the frame that were read
+        received: u64,
+    },
+    /// A message did not have the expected file identifier.
+    InvalidFileIdentifier {
+        /// The file identifier that was expected
+        expected: [u8; 4],
+        /// The file identifier found in the message, or `None` if the
+        /// message was too short to contain one
+        found: Option<[u8; 4]>,
+    },
+    /// The builder could not finish a message.
+    Builder(BuilderError),
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for StreamError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            StreamError::Io(error) => write!(f, "I/O error: {error}"),
+            StreamError::MessageTooLarge { size, max_size } => {
+                write!(
+                    f,
+                    "The message is too large (size = {size}, max size = {max_size})"
+                )
+            }
+            StreamError::TruncatedFrame { expected, received } => {
+                write!(
+                    f,
+                    "The stream ended in the middle of a frame (expected = {expected}, received = {received})"
+                )
+            }
+            StreamError::InvalidFileIdentifier { expected, found } => {
+                write!(
+                    f,
+                    "Invalid file identifier (expected = {expected:?}, found = {found:?})"
+                )
+            }
+            StreamError::Builder(error) => write!(f, "Unable to finish the message: {error}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StreamError::Io(error) => Some(error),
+            StreamError::Builder(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for StreamError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<BuilderError> for StreamError {
+    fn from(error: BuilderError) -> Self {
+        Self::Builder(error)
+    }
+}
+
 #[derive(Clone, Debug)]
 /// Information about an unrecognized enum tag.
 ///
@@ -184,10 +652,11 @@ impl core::fmt::Display for ErrorLocation {
 
 impl From<UnknownEnumTag> for Error {
     fn from(error: UnknownEnumTag) -> Self {
-        Self {
-            source_location: error.source_location,
-            error_kind: error.error_kind.into(),
-        }
+        ErrorKind::from(error.error_kind).with_error_location(
+            error.source_location.type_,
+            error.source_location.method,
+            error.source_location.byte_offset,
+        )
     }
 }
 
@@ -231,6 +700,8 @@ impl ErrorKind {
                 byte_offset,
             },
             error_kind: self,
+            #[cfg(feature = "error-path")]
+            path: ErrorPath::default(),
         }
     }
 }
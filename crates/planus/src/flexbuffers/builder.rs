@@ -0,0 +1,426 @@
+use alloc::vec::Vec;
+
+use super::FlexBufferType;
+use crate::errors::FlexBufferBuilderError;
+
+/// The number of bits needed to store a value, as the log2 of the number of
+/// bytes. This is the representation used in packed type bytes.
+type BitWidth = u8;
+
+fn width_u(value: u64) -> BitWidth {
+    if value <= u8::MAX as u64 {
+        0
+    } else if value <= u16::MAX as u64 {
+        1
+    } else if value <= u32::MAX as u64 {
+        2
+    } else {
+        3
+    }
+}
+
+fn width_i(value: i64) -> BitWidth {
+    let shifted = (value as u64) << 1;
+    width_u(if value >= 0 { shifted } else { !shifted })
+}
+
+fn width_f(value: f64) -> BitWidth {
+    if value as f32 as f64 == value {
+        2
+    } else {
+        3
+    }
+}
+
+/// A value on the stack of the builder. Scalars are stored directly, while
+/// all other values are stored as the absolute position of their data.
+#[derive(Copy, Clone, Debug)]
+struct Value {
+    data: u64,
+    type_: FlexBufferType,
+    min_width: BitWidth,
+}
+
+impl Value {
+    /// Returns the width needed to store this value as element `index` of a
+    /// vector starting at `buffer_len`.
+    fn element_width(&self, buffer_len: usize, index: usize) -> BitWidth {
+        if self.type_.is_inline() {
+            return self.min_width;
+        }
+        // Whether the relative offset fits in a given width depends on the
+        // padding and size of the elements before it, so every width has to
+        // be tried in turn
+        for width in 0..3 {
+            let byte_width = 1 << width;
+            let offset_position = buffer_len.next_multiple_of(byte_width) + index * byte_width;
+            if width_u(offset_position as u64 - self.data) <= width {
+                return width;
+            }
+        }
+        3
+    }
+
+    fn packed_type(&self, parent_width: BitWidth) -> u8 {
+        let width = if self.type_.is_inline() {
+            self.min_width.max(parent_width)
+        } else {
+            self.min_width
+        };
+        ((self.type_ as u8) << 2) | width
+    }
+}
+
+/// A builder for FlexBuffers.
+///
+/// Values are pushed onto an internal stack. Vectors and maps are created by
+/// calling [`start_vector`] or [`start_map`], pushing their contents and then
+/// calling the matching `end_` method. Once exactly one value remains, the
+/// buffer can be completed with [`finish`].
+///
+/// [`start_vector`]: Self::start_vector
+/// [`start_map`]: Self::start_map
+/// [`finish`]: Self::finish
+#[derive(Clone, Debug, Default)]
+pub struct FlexBufferBuilder {
+    buffer: Vec<u8>,
+    stack: Vec<Value>,
+}
+
+impl FlexBufferBuilder {
+    /// Creates a new FlexBuffer builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resets the builder, so it can be used to create a new buffer.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+        self.stack.clear();
+    }
+
+    fn push_value(&mut self, data: u64, type_: FlexBufferType, min_width: BitWidth) {
+        self.stack.push(Value {
+            data,
+            type_,
+            min_width,
+        });
+    }
+
+    /// Pushes a null value.
+    pub fn push_null(&mut self) {
+        self.push_value(0, FlexBufferType::Null, 0);
+    }
+
+    /// Pushes a boolean.
+    pub fn push_bool(&mut self, value: bool) {
+        self.push_value(value as u64, FlexBufferType::Bool, 0);
+    }
+
+    /// Pushes a signed integer.
+    pub fn push_int(&mut self, value: i64) {
+        self.push_value(value as u64, FlexBufferType::Int, width_i(value));
+    }
+
+    /// Pushes an unsigned integer.
+    pub fn push_uint(&mut self, value: u64) {
+        self.push_value(value, FlexBufferType::UInt, width_u(value));
+    }
+
+    /// Pushes a floating point number. It is stored using 32 bits if that can
+    /// be done without losing precision.
+    pub fn push_float(&mut self, value: f64) {
+        self.push_value(value.to_bits(), FlexBufferType::Float, width_f(value));
+    }
+
+    /// Pushes a string.
+    pub fn push_str(&mut self, value: &str) {
+        self.push_sized(value.as_bytes(), 1, FlexBufferType::String);
+    }
+
+    /// Pushes a blob of bytes.
+    pub fn push_blob(&mut self, value: &[u8]) {
+        self.push_sized(value, 0, FlexBufferType::Blob);
+    }
+
+    /// Pushes a key. Inside a map, every value must be preceded by its key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key contains a null byte. See [`try_push_key`] for a
+    /// version which returns an error instead.
+    ///
+    /// [`try_push_key`]: Self::try_push_key
+    pub fn push_key(&mut self, key: &str) {
+        self.try_push_key(key)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Pushes a key. Inside a map, every value must be preceded by its key.
+    ///
+    /// Returns an error if the key contains a null byte.
+    pub fn try_push_key(&mut self, key: &str) -> Result<(), FlexBufferBuilderError> {
+        if key.as_bytes().contains(&0) {
+            return Err(FlexBufferBuilderError::KeyContainsNull);
+        }
+        let position = self.buffer.len();
+        self.buffer.extend_from_slice(key.as_bytes());
+        self.buffer.push(0);
+        self.push_value(position as u64, FlexBufferType::Key, 0);
+        Ok(())
+    }
+
+    fn push_sized(&mut self, bytes: &[u8], trailing: usize, type_: FlexBufferType) {
+        let width = width_u(bytes.len() as u64);
+        let byte_width = self.align(width);
+        self.write_uint(bytes.len() as u64, byte_width);
+        let position = self.buffer.len();
+        self.buffer.extend_from_slice(bytes);
+        self.buffer.resize(self.buffer.len() + trailing, 0);
+        self.push_value(position as u64, type_, width);
+    }
+
+    /// Starts a vector and returns a handle to pass to [`end_vector`] or
+    /// [`end_typed_vector`].
+    ///
+    /// [`end_vector`]: Self::end_vector
+    /// [`end_typed_vector`]: Self::end_typed_vector
+    pub fn start_vector(&mut self) -> usize {
+        self.stack.len()
+    }
+
+    /// Ends a vector, turning all values pushed since the matching
+    /// [`start_vector`] into its elements.
+    ///
+    /// [`start_vector`]: Self::start_vector
+    pub fn end_vector(&mut self, start: usize) {
+        let vector = self.create_vector(start, 1, FlexBufferType::Vector, None);
+        self.stack.truncate(start);
+        self.stack.push(vector);
+    }
+
+    /// Ends a vector where all elements have the same type. This avoids
+    /// storing the type of each element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the elements do not all have the same type, or if the type
+    /// is not an integer, float, boolean or key. See [`try_end_typed_vector`]
+    /// for a version which returns an error instead.
+    ///
+    /// [`try_end_typed_vector`]: Self::try_end_typed_vector
+    pub fn end_typed_vector(&mut self, start: usize) {
+        self.try_end_typed_vector(start)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Ends a vector where all elements have the same type. This avoids
+    /// storing the type of each element.
+    ///
+    /// Returns an error if the elements do not all have the same type, or if
+    /// the type is not an integer, float, boolean or key. The builder is left
+    /// unchanged in that case.
+    pub fn try_end_typed_vector(&mut self, start: usize) -> Result<(), FlexBufferBuilderError> {
+        let vector_type = self.typed_vector_type(start, 1)?;
+        let vector = self.create_vector(start, 1, vector_type, None);
+        self.stack.truncate(start);
+        self.stack.push(vector);
+        Ok(())
+    }
+
+    /// Starts a map and returns a handle to pass to [`end_map`].
+    ///
+    /// [`end_map`]: Self::end_map
+    pub fn start_map(&mut self) -> usize {
+        self.stack.len()
+    }
+
+    /// Ends a map. The values pushed since the matching [`start_map`] must
+    /// alternate between keys and values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the map contains a value without a key, or if the same key
+    /// is used twice. See [`try_end_map`] for a version which returns an
+    /// error instead.
+    ///
+    /// [`start_map`]: Self::start_map
+    /// [`try_end_map`]: Self::try_end_map
+    pub fn end_map(&mut self, start: usize) {
+        self.try_end_map(start)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Ends a map. The values pushed since the matching [`start_map`] must
+    /// alternate between keys and values.
+    ///
+    /// Returns an error if the map contains a value without a key, or if the
+    /// same key is used twice. The builder is left unchanged in that case.
+    ///
+    /// [`start_map`]: Self::start_map
+    pub fn try_end_map(&mut self, start: usize) -> Result<(), FlexBufferBuilderError> {
+        let entries = &mut self.stack[start..];
+        if !entries.len().is_multiple_of(2)
+            || entries
+                .iter()
+                .step_by(2)
+                .any(|key| key.type_ != FlexBufferType::Key)
+        {
+            return Err(FlexBufferBuilderError::MissingKey);
+        }
+
+        // Lookups use a binary search, so the entries are sorted by key
+        let buffer = &self.buffer;
+        let key = |value: &Value| {
+            let key = &buffer[value.data as usize..];
+            &key[..key.iter().position(|&b| b == 0).unwrap()]
+        };
+        let mut pairs: Vec<[Value; 2]> = entries
+            .chunks_exact(2)
+            .map(|pair| [pair[0], pair[1]])
+            .collect();
+        pairs.sort_by(|a, b| key(&a[0]).cmp(key(&b[0])));
+        if pairs.windows(2).any(|w| key(&w[0][0]) == key(&w[1][0])) {
+            return Err(FlexBufferBuilderError::DuplicateKey);
+        }
+        entries.copy_from_slice(pairs.as_flattened());
+
+        let keys = self.create_vector(start, 2, FlexBufferType::VectorKey, None);
+        let map = self.create_vector(start + 1, 2, FlexBufferType::Map, Some(keys));
+        self.stack.truncate(start);
+        self.stack.push(map);
+        Ok(())
+    }
+
+    /// Pads the buffer to the given width and returns the width in bytes.
+    fn align(&mut self, width: BitWidth) -> usize {
+        let byte_width = 1 << width;
+        self.buffer
+            .resize(self.buffer.len().next_multiple_of(byte_width), 0);
+        byte_width
+    }
+
+    fn write_uint(&mut self, value: u64, byte_width: usize) {
+        self.buffer
+            .extend_from_slice(&value.to_le_bytes()[..byte_width]);
+    }
+
+    fn write_value(&mut self, value: Value, byte_width: usize) {
+        match value.type_ {
+            FlexBufferType::Null
+            | FlexBufferType::Int
+            | FlexBufferType::UInt
+            | FlexBufferType::Bool => self.write_uint(value.data, byte_width),
+            FlexBufferType::Float => {
+                let value = f64::from_bits(value.data);
+                if byte_width == 4 {
+                    self.buffer.extend_from_slice(&(value as f32).to_le_bytes());
+                } else {
+                    debug_assert_eq!(byte_width, 8);
+                    self.buffer.extend_from_slice(&value.to_le_bytes());
+                }
+            }
+            _ => {
+                let relative = self.buffer.len() as u64 - value.data;
+                self.write_uint(relative, byte_width);
+            }
+        }
+    }
+
+    /// Returns the type of a typed vector of every `step`th value on the
+    /// stack from `start`.
+    fn typed_vector_type(
+        &self,
+        start: usize,
+        step: usize,
+    ) -> Result<FlexBufferType, FlexBufferBuilderError> {
+        let mut types = self.stack[start..].iter().step_by(step).map(|v| v.type_);
+        let element_type = types.next().unwrap_or(FlexBufferType::Key);
+        if let Some(found) = types.find(|&type_| type_ != element_type) {
+            return Err(FlexBufferBuilderError::MixedTypedVector {
+                expected: element_type,
+                found,
+            });
+        }
+        element_type
+            .to_typed_vector()
+            .ok_or(FlexBufferBuilderError::UnsupportedTypedVector { element_type })
+    }
+
+    /// Writes every `step`th value on the stack from `start` as a vector of
+    /// the given type.
+    fn create_vector(
+        &mut self,
+        start: usize,
+        step: usize,
+        vector_type: FlexBufferType,
+        keys: Option<Value>,
+    ) -> Value {
+        let typed = !matches!(vector_type, FlexBufferType::Vector | FlexBufferType::Map);
+        let len = self.stack[start..].len().div_ceil(step);
+        let mut width = width_u(len as u64);
+        let mut prefix_elements = 1;
+        if let Some(keys) = &keys {
+            // Maps are prefixed by an offset to their keys and the width of
+            // the keys
+            width = width.max(keys.element_width(self.buffer.len(), 0));
+            prefix_elements += 2;
+        }
+        for (i, value) in self.stack[start..].iter().step_by(step).enumerate() {
+            width = width.max(value.element_width(self.buffer.len(), i + prefix_elements));
+        }
+
+        let byte_width = self.align(width);
+        if let Some(keys) = keys {
+            self.write_value(keys, byte_width);
+            self.write_uint(1 << keys.min_width, byte_width);
+        }
+        self.write_uint(len as u64, byte_width);
+        let position = self.buffer.len();
+        for i in (start..self.stack.len()).step_by(step) {
+            self.write_value(self.stack[i], byte_width);
+        }
+        if !typed {
+            for i in (start..self.stack.len()).step_by(step) {
+                let packed_type = self.stack[i].packed_type(width);
+                self.buffer.push(packed_type);
+            }
+        }
+        Value {
+            data: position as u64,
+            type_: vector_type,
+            min_width: width,
+        }
+    }
+
+    /// Finishes the buffer and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not exactly one value on the stack, for instance
+    /// because a vector or map was not ended. See [`try_finish`] for a version
+    /// which returns an error instead.
+    ///
+    /// [`try_finish`]: Self::try_finish
+    pub fn finish(&mut self) -> &[u8] {
+        self.try_finish().unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Finishes the buffer and returns it.
+    ///
+    /// Returns an error if there is not exactly one value on the stack, for
+    /// instance because a vector or map was not ended.
+    pub fn try_finish(&mut self) -> Result<&[u8], FlexBufferBuilderError> {
+        let [root] = self.stack[..] else {
+            return Err(FlexBufferBuilderError::InvalidRoot {
+                values: self.stack.len(),
+            });
+        };
+        self.stack.clear();
+        let byte_width = self.align(root.element_width(self.buffer.len(), 0));
+        self.write_value(root, byte_width);
+        self.buffer.push(root.packed_type(0));
+        self.buffer.push(byte_width as u8);
+        Ok(&self.buffer)
+    }
+}
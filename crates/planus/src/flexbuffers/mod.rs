@@ -0,0 +1,385 @@
+//! FlexBuffers are a schemaless binary format from the flatbuffers project.
+//!
+//! Values are read using [`Reference::read_root`] and written using a
+//! [`FlexBufferBuilder`]. Fields of type `[ubyte]` with the `flexbuffer`
+//! attribute get an accessor in the generated code which returns a
+//! [`Reference`] to the contained value.
+//!
+//! # Examples
+//! ```
+//! use planus::flexbuffers::{FlexBufferBuilder, Reference};
+//!
+//! let mut builder = FlexBufferBuilder::new();
+//! let map = builder.start_map();
+//! builder.push_key("name");
+//! builder.push_str("Orc");
+//! builder.push_key("hp");
+//! builder.push_int(300);
+//! builder.end_map(map);
+//! let data = builder.finish();
+//!
+//! let map = Reference::read_root(data).unwrap().as_map().unwrap();
+//! assert_eq!(map.get("name").unwrap().unwrap().as_str().unwrap(), "Orc");
+//! assert_eq!(map.get("hp").unwrap().unwrap().as_i64().unwrap(), 300);
+//! ```
+
+#[cfg(feature = "alloc")]
+mod builder;
+mod reader;
+
+#[cfg(feature = "alloc")]
+pub use builder::FlexBufferBuilder;
+pub use reader::{MapIter, MapReader, Reference, VectorIter, VectorReader};
+
+/// The type of a value stored in a FlexBuffer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum FlexBufferType {
+    /// A null value.
+    Null = 0,
+    /// A signed integer.
+    Int = 1,
+    /// An unsigned integer.
+    UInt = 2,
+    /// A floating point number.
+    Float = 3,
+    /// A null-terminated string used as a map key.
+    Key = 4,
+    /// A length-prefixed and null-terminated string.
+    String = 5,
+    /// A signed integer stored out of line.
+    IndirectInt = 6,
+    /// An unsigned integer stored out of line.
+    IndirectUInt = 7,
+    /// A floating point number stored out of line.
+    IndirectFloat = 8,
+    /// A map from keys to values of any type.
+    Map = 9,
+    /// A vector of values of any type.
+    Vector = 10,
+    /// A vector of signed integers.
+    VectorInt = 11,
+    /// A vector of unsigned integers.
+    VectorUInt = 12,
+    /// A vector of floating point numbers.
+    VectorFloat = 13,
+    /// A vector of keys.
+    VectorKey = 14,
+    /// A vector of strings. This is deprecated in the FlexBuffers format.
+    VectorString = 15,
+    /// A vector of two signed integers.
+    VectorInt2 = 16,
+    /// A vector of two unsigned integers.
+    VectorUInt2 = 17,
+    /// A vector of two floating point numbers.
+    VectorFloat2 = 18,
+    /// A vector of three signed integers.
+    VectorInt3 = 19,
+    /// A vector of three unsigned integers.
+    VectorUInt3 = 20,
+    /// A vector of three floating point numbers.
+    VectorFloat3 = 21,
+    /// A vector of four signed integers.
+    VectorInt4 = 22,
+    /// A vector of four unsigned integers.
+    VectorUInt4 = 23,
+    /// A vector of four floating point numbers.
+    VectorFloat4 = 24,
+    /// A length-prefixed byte string.
+    Blob = 25,
+    /// A boolean.
+    Bool = 26,
+    /// A vector of booleans.
+    VectorBool = 36,
+}
+
+impl FlexBufferType {
+    fn from_u8(value: u8) -> Option<Self> {
+        use FlexBufferType::*;
+        Some(match value {
+            0 => Null,
+            1 => Int,
+            2 => UInt,
+            3 => Float,
+            4 => Key,
+            5 => String,
+            6 => IndirectInt,
+            7 => IndirectUInt,
+            8 => IndirectFloat,
+            9 => Map,
+            10 => Vector,
+            11 => VectorInt,
+            12 => VectorUInt,
+            13 => VectorFloat,
+            14 => VectorKey,
+            15 => VectorString,
+            16 => VectorInt2,
+            17 => VectorUInt2,
+            18 => VectorFloat2,
+            19 => VectorInt3,
+            20 => VectorUInt3,
+            21 => VectorFloat3,
+            22 => VectorInt4,
+            23 => VectorUInt4,
+            24 => VectorFloat4,
+            25 => Blob,
+            26 => Bool,
+            36 => VectorBool,
+            _ => return None,
+        })
+    }
+
+    /// Returns true if values of this type are stored directly in their
+    /// parent instead of behind an offset.
+    pub fn is_inline(self) -> bool {
+        matches!(
+            self,
+            FlexBufferType::Null
+                | FlexBufferType::Int
+                | FlexBufferType::UInt
+                | FlexBufferType::Float
+                | FlexBufferType::Bool
+        )
+    }
+
+    /// Returns true if this is any kind of vector, including maps.
+    pub fn is_vector(self) -> bool {
+        matches!(self, FlexBufferType::Map | FlexBufferType::Vector)
+            || self.typed_vector_element().is_some()
+    }
+
+    /// For typed vectors, returns the type of the elements along with the
+    /// length for fixed-length vectors.
+    fn typed_vector_element(self) -> Option<(FlexBufferType, Option<usize>)> {
+        use FlexBufferType::*;
+        Some(match self {
+            VectorInt => (Int, None),
+            VectorUInt => (UInt, None),
+            VectorFloat => (Float, None),
+            VectorKey => (Key, None),
+            VectorString => (String, None),
+            VectorBool => (Bool, None),
+            VectorInt2 => (Int, Some(2)),
+            VectorUInt2 => (UInt, Some(2)),
+            VectorFloat2 => (Float, Some(2)),
+            VectorInt3 => (Int, Some(3)),
+            VectorUInt3 => (UInt, Some(3)),
+            VectorFloat3 => (Float, Some(3)),
+            VectorInt4 => (Int, Some(4)),
+            VectorUInt4 => (UInt, Some(4)),
+            VectorFloat4 => (Float, Some(4)),
+            _ => return None,
+        })
+    }
+
+    /// Returns the type of a variable-length typed vector with elements of
+    /// this type.
+    #[cfg(feature = "alloc")]
+    fn to_typed_vector(self) -> Option<FlexBufferType> {
+        use FlexBufferType::*;
+        Some(match self {
+            Int => VectorInt,
+            UInt => VectorUInt,
+            Float => VectorFloat,
+            Key => VectorKey,
+            Bool => VectorBool,
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::ErrorKind;
+
+    #[test]
+    fn test_scalar_root() {
+        let mut builder = FlexBufferBuilder::new();
+        builder.push_int(13);
+        assert_eq!(builder.finish(), [13, 4, 1]);
+
+        for value in [0, -1, 127, -129, 1 << 20, i64::MIN, i64::MAX] {
+            builder.clear();
+            builder.push_int(value);
+            let root = Reference::read_root(builder.finish()).unwrap();
+            assert_eq!(root.flexbuffer_type(), FlexBufferType::Int);
+            assert_eq!(root.as_i64().unwrap(), value);
+            assert!(matches!(
+                root.as_u64().unwrap_err().error_kind,
+                ErrorKind::UnexpectedFlexBufferType {
+                    found: FlexBufferType::Int
+                }
+            ));
+        }
+
+        for value in [0.5, 1.0 / 3.0, f64::MAX] {
+            builder.clear();
+            builder.push_float(value);
+            let root = Reference::read_root(builder.finish()).unwrap();
+            assert_eq!(root.as_f64().unwrap(), value);
+        }
+
+        builder.clear();
+        builder.push_uint(u64::MAX);
+        let root = Reference::read_root(builder.finish()).unwrap();
+        assert_eq!(root.as_u64().unwrap(), u64::MAX);
+
+        builder.clear();
+        builder.push_null();
+        assert!(Reference::read_root(builder.finish()).unwrap().is_null());
+    }
+
+    #[test]
+    fn test_vectors() {
+        let mut builder = FlexBufferBuilder::new();
+        let vector = builder.start_vector();
+        builder.push_int(1);
+        builder.push_str("two");
+        builder.push_float(3.5);
+        builder.push_bool(true);
+        builder.push_blob(&[5; 300]);
+        let inner = builder.start_vector();
+        builder.push_uint(6);
+        builder.push_uint(70000);
+        builder.end_typed_vector(inner);
+        builder.push_null();
+        builder.end_vector(vector);
+        let data = builder.finish();
+
+        let vector = Reference::read_root(data).unwrap().as_vector().unwrap();
+        assert_eq!(vector.len(), 7);
+        let get = |index| vector.get(index).unwrap().unwrap();
+        assert_eq!(get(0).as_i64().unwrap(), 1);
+        assert_eq!(get(1).as_str().unwrap(), "two");
+        assert_eq!(get(1).as_blob().unwrap(), b"two");
+        assert_eq!(get(2).as_f64().unwrap(), 3.5);
+        assert!(get(3).as_bool().unwrap());
+        assert_eq!(get(4).as_blob().unwrap(), [5; 300]);
+        assert_eq!(get(5).flexbuffer_type(), FlexBufferType::VectorUInt);
+        let inner = get(5)
+            .as_vector()
+            .unwrap()
+            .iter()
+            .map(|value| value.unwrap().as_u64().unwrap())
+            .collect::<alloc::vec::Vec<_>>();
+        assert_eq!(inner, [6, 70000]);
+        assert!(get(6).is_null());
+        assert!(vector.get(7).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_maps() {
+        let mut builder = FlexBufferBuilder::new();
+        let map = builder.start_map();
+        for key in ["zeta", "alpha", "mu", "beta"] {
+            builder.push_key(key);
+            builder.push_str(&key.repeat(2));
+        }
+        builder.push_key("nested");
+        let nested = builder.start_map();
+        builder.push_key("value");
+        builder.push_int(-7);
+        builder.end_map(nested);
+        builder.end_map(map);
+        let data = builder.finish();
+
+        let map = Reference::read_root(data).unwrap().as_map().unwrap();
+        assert_eq!(map.len(), 5);
+        let keys = map
+            .iter()
+            .map(|entry| entry.unwrap().0)
+            .collect::<alloc::vec::Vec<_>>();
+        assert_eq!(keys, ["alpha", "beta", "mu", "nested", "zeta"]);
+        assert_eq!(map.get("mu").unwrap().unwrap().as_str().unwrap(), "mumu");
+        assert!(map.get("gamma").unwrap().is_none());
+        let nested = map.get("nested").unwrap().unwrap().as_map().unwrap();
+        assert_eq!(nested.get("value").unwrap().unwrap().as_i64().unwrap(), -7);
+        assert_eq!(map.values().len(), 5);
+    }
+
+    #[test]
+    fn test_invalid_buffers() {
+        assert!(matches!(
+            Reference::read_root(&[]).unwrap_err().error_kind,
+            ErrorKind::InvalidLength
+        ));
+        assert!(matches!(
+            Reference::read_root(&[0, 0xfc, 1]).unwrap_err().error_kind,
+            ErrorKind::InvalidFlexBufferType { packed_type: 0xfc }
+        ));
+        // A string whose offset points before the start of the buffer
+        let root = Reference::read_root(&[10, 5 << 2, 1]).unwrap();
+        assert!(matches!(
+            root.as_str().unwrap_err().error_kind,
+            ErrorKind::InvalidOffset
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_duplicate_keys() {
+        let mut builder = FlexBufferBuilder::new();
+        let map = builder.start_map();
+        builder.push_key("a");
+        builder.push_int(1);
+        builder.push_key("a");
+        builder.push_int(2);
+        builder.end_map(map);
+    }
+
+    #[test]
+    fn test_builder_errors() {
+        use crate::errors::FlexBufferBuilderError;
+
+        let mut builder = FlexBufferBuilder::new();
+        assert_eq!(
+            builder.try_push_key("a\0b"),
+            Err(FlexBufferBuilderError::KeyContainsNull)
+        );
+        assert_eq!(
+            builder.try_finish().unwrap_err(),
+            FlexBufferBuilderError::InvalidRoot { values: 0 }
+        );
+
+        let map = builder.start_map();
+        builder.push_key("a");
+        builder.push_int(1);
+        builder.push_int(2);
+        assert_eq!(
+            builder.try_end_map(map),
+            Err(FlexBufferBuilderError::MissingKey)
+        );
+
+        builder.clear();
+        let vector = builder.start_vector();
+        builder.push_int(1);
+        builder.push_uint(2);
+        assert_eq!(
+            builder.try_end_typed_vector(vector),
+            Err(FlexBufferBuilderError::MixedTypedVector {
+                expected: FlexBufferType::Int,
+                found: FlexBufferType::UInt
+            })
+        );
+
+        builder.clear();
+        let vector = builder.start_vector();
+        builder.push_str("a");
+        assert_eq!(
+            builder.try_end_typed_vector(vector),
+            Err(FlexBufferBuilderError::UnsupportedTypedVector {
+                element_type: FlexBufferType::String
+            })
+        );
+
+        // A failed call leaves the builder unchanged, so the values can still
+        // be used
+        builder.end_vector(vector);
+        let vector = Reference::read_root(builder.try_finish().unwrap())
+            .unwrap()
+            .as_vector()
+            .unwrap();
+        assert_eq!(vector.get(0).unwrap().unwrap().as_str().unwrap(), "a");
+    }
+}
@@ -0,0 +1,490 @@
+use core::cmp::Ordering;
+
+use super::FlexBufferType;
+use crate::errors::ErrorKind;
+
+fn read_uint(buffer: &[u8], offset: usize, width: usize) -> Result<u64, ErrorKind> {
+    let bytes = offset
+        .checked_add(width)
+        .and_then(|end| buffer.get(offset..end))
+        .ok_or(ErrorKind::InvalidOffset)?;
+    let mut value = [0; 8];
+    value[..width].copy_from_slice(bytes);
+    Ok(u64::from_le_bytes(value))
+}
+
+fn read_int(buffer: &[u8], offset: usize, width: usize) -> Result<i64, ErrorKind> {
+    let shift = 64 - 8 * width as u32;
+    Ok(((read_uint(buffer, offset, width)? << shift) as i64) >> shift)
+}
+
+fn read_float(buffer: &[u8], offset: usize, width: usize) -> Result<Option<f64>, ErrorKind> {
+    let value = read_uint(buffer, offset, width)?;
+    Ok(match width {
+        4 => Some(f32::from_bits(value as u32) as f64),
+        8 => Some(f64::from_bits(value)),
+        _ => None,
+    })
+}
+
+/// Follows the offset of the given width stored at `offset`.
+fn indirect(buffer: &[u8], offset: usize, width: usize) -> Result<usize, ErrorKind> {
+    let relative = read_uint(buffer, offset, width)?;
+    usize::try_from(relative)
+        .ok()
+        .and_then(|relative| offset.checked_sub(relative))
+        .ok_or(ErrorKind::InvalidOffset)
+}
+
+/// Reads the null-terminated string starting at `offset`, without the terminator.
+fn read_key(buffer: &[u8], offset: usize) -> Result<&[u8], ErrorKind> {
+    let bytes = buffer.get(offset..).ok_or(ErrorKind::InvalidOffset)?;
+    let len = bytes
+        .iter()
+        .position(|&b| b == 0)
+        .ok_or(ErrorKind::MissingNullTerminator)?;
+    Ok(&bytes[..len])
+}
+
+/// A reference to a single value inside a FlexBuffer.
+///
+/// The value is only decoded when one of the accessors is called. Accessors
+/// do not convert between types, so calling [`as_i64`] on an unsigned integer
+/// gives an [`ErrorKind::UnexpectedFlexBufferType`] error.
+///
+/// [`as_i64`]: Self::as_i64
+#[derive(Copy, Clone, Debug)]
+pub struct Reference<'buf> {
+    buffer: &'buf [u8],
+    offset: usize,
+    parent_width: usize,
+    byte_width: usize,
+    type_: FlexBufferType,
+}
+
+impl<'buf> Reference<'buf> {
+    /// Reads the root value of a finished FlexBuffer.
+    pub fn read_root(buffer: &'buf [u8]) -> crate::Result<Self> {
+        let make_error = |error_kind: ErrorKind| {
+            error_kind.with_error_location("flexbuffers::Reference", "read_root", 0)
+        };
+        let [.., packed_type, root_width] = *buffer else {
+            return Err(make_error(ErrorKind::InvalidLength));
+        };
+        let root_width = root_width as usize;
+        if !matches!(root_width, 1 | 2 | 4 | 8) {
+            return Err(make_error(ErrorKind::InvalidLength));
+        }
+        let offset = (buffer.len() - 2)
+            .checked_sub(root_width)
+            .ok_or(make_error(ErrorKind::InvalidLength))?;
+        Self::new(buffer, offset, root_width, packed_type).map_err(make_error)
+    }
+
+    fn new(
+        buffer: &'buf [u8],
+        offset: usize,
+        parent_width: usize,
+        packed_type: u8,
+    ) -> Result<Self, ErrorKind> {
+        let type_ = FlexBufferType::from_u8(packed_type >> 2)
+            .ok_or(ErrorKind::InvalidFlexBufferType { packed_type })?;
+        Ok(Self {
+            buffer,
+            offset,
+            parent_width,
+            byte_width: 1 << (packed_type & 3),
+            type_,
+        })
+    }
+
+    fn make_error(&self, method: &'static str) -> impl '_ + Fn(ErrorKind) -> crate::Error {
+        move |error_kind| {
+            error_kind.with_error_location("flexbuffers::Reference", method, self.offset)
+        }
+    }
+
+    fn unexpected_type(&self) -> ErrorKind {
+        ErrorKind::UnexpectedFlexBufferType { found: self.type_ }
+    }
+
+    fn invalid_type(&self) -> ErrorKind {
+        ErrorKind::InvalidFlexBufferType {
+            packed_type: ((self.type_ as u8) << 2) | self.byte_width.trailing_zeros() as u8,
+        }
+    }
+
+    fn target(&self) -> Result<usize, ErrorKind> {
+        indirect(self.buffer, self.offset, self.parent_width)
+    }
+
+    /// Returns the type of the referenced value.
+    pub fn flexbuffer_type(&self) -> FlexBufferType {
+        self.type_
+    }
+
+    /// Returns true if the referenced value is null.
+    pub fn is_null(&self) -> bool {
+        self.type_ == FlexBufferType::Null
+    }
+
+    /// Reads the value as a boolean.
+    pub fn as_bool(&self) -> crate::Result<bool> {
+        match self.type_ {
+            FlexBufferType::Bool => read_uint(self.buffer, self.offset, self.parent_width)
+                .map(|value| value != 0)
+                .map_err(self.make_error("as_bool")),
+            _ => Err(self.make_error("as_bool")(self.unexpected_type())),
+        }
+    }
+
+    /// Reads the value as a signed integer.
+    pub fn as_i64(&self) -> crate::Result<i64> {
+        let result = match self.type_ {
+            FlexBufferType::Int => read_int(self.buffer, self.offset, self.parent_width),
+            FlexBufferType::IndirectInt => self
+                .target()
+                .and_then(|target| read_int(self.buffer, target, self.byte_width)),
+            _ => Err(self.unexpected_type()),
+        };
+        result.map_err(self.make_error("as_i64"))
+    }
+
+    /// Reads the value as an unsigned integer.
+    pub fn as_u64(&self) -> crate::Result<u64> {
+        let result = match self.type_ {
+            FlexBufferType::UInt => read_uint(self.buffer, self.offset, self.parent_width),
+            FlexBufferType::IndirectUInt => self
+                .target()
+                .and_then(|target| read_uint(self.buffer, target, self.byte_width)),
+            _ => Err(self.unexpected_type()),
+        };
+        result.map_err(self.make_error("as_u64"))
+    }
+
+    /// Reads the value as a floating point number.
+    pub fn as_f64(&self) -> crate::Result<f64> {
+        let result = match self.type_ {
+            FlexBufferType::Float => read_float(self.buffer, self.offset, self.parent_width),
+            FlexBufferType::IndirectFloat => self
+                .target()
+                .and_then(|target| read_float(self.buffer, target, self.byte_width)),
+            _ => Err(self.unexpected_type()),
+        };
+        result
+            .and_then(|value| value.ok_or(self.invalid_type()))
+            .map_err(self.make_error("as_f64"))
+    }
+
+    /// Reads the value as a string. This works for both strings and keys.
+    pub fn as_str(&self) -> crate::Result<&'buf str> {
+        let result = match self.type_ {
+            FlexBufferType::String => self.read_sized(1),
+            FlexBufferType::Key => self
+                .target()
+                .and_then(|target| read_key(self.buffer, target)),
+            _ => Err(self.unexpected_type()),
+        };
+        result
+            .and_then(|bytes| core::str::from_utf8(bytes).map_err(ErrorKind::from))
+            .map_err(self.make_error("as_str"))
+    }
+
+    /// Reads the value as a byte slice. This works for both blobs and strings.
+    pub fn as_blob(&self) -> crate::Result<&'buf [u8]> {
+        let result = match self.type_ {
+            FlexBufferType::Blob => self.read_sized(0),
+            FlexBufferType::String => self.read_sized(1),
+            _ => Err(self.unexpected_type()),
+        };
+        result.map_err(self.make_error("as_blob"))
+    }
+
+    /// Reads a length-prefixed byte string followed by `trailing` null bytes.
+    fn read_sized(&self, trailing: usize) -> Result<&'buf [u8], ErrorKind> {
+        let target = self.target()?;
+        let len = self.read_length(target)?;
+        let bytes = target
+            .checked_add(len)
+            .and_then(|end| self.buffer.get(target..end.checked_add(trailing)?))
+            .ok_or(ErrorKind::InvalidLength)?;
+        let (bytes, terminator) = bytes.split_at(len);
+        if terminator.iter().any(|&b| b != 0) {
+            return Err(ErrorKind::MissingNullTerminator);
+        }
+        Ok(bytes)
+    }
+
+    /// Reads the length stored in front of the object at `target`.
+    fn read_length(&self, target: usize) -> Result<usize, ErrorKind> {
+        let offset = target
+            .checked_sub(self.byte_width)
+            .ok_or(ErrorKind::InvalidOffset)?;
+        usize::try_from(read_uint(self.buffer, offset, self.byte_width)?)
+            .map_err(|_| ErrorKind::InvalidLength)
+    }
+
+    /// Reads the value as a vector. This works for all kinds of vectors, and
+    /// for maps it gives a vector of the values.
+    pub fn as_vector(&self) -> crate::Result<VectorReader<'buf>> {
+        let result = match self.type_ {
+            FlexBufferType::Vector | FlexBufferType::Map => self.read_vector(None),
+            type_ => match type_.typed_vector_element() {
+                Some(element) => self.read_vector(Some(element)),
+                None => Err(self.unexpected_type()),
+            },
+        };
+        result.map_err(self.make_error("as_vector"))
+    }
+
+    fn read_vector(
+        &self,
+        element: Option<(FlexBufferType, Option<usize>)>,
+    ) -> Result<VectorReader<'buf>, ErrorKind> {
+        let target = self.target()?;
+        let len = match element {
+            Some((_, Some(len))) => len,
+            _ => self.read_length(target)?,
+        };
+        // Untyped vectors store a packed type byte per element after the values
+        let bytes_per_element = self.byte_width + usize::from(element.is_none());
+        len.checked_mul(bytes_per_element)
+            .and_then(|size| target.checked_add(size))
+            .filter(|&end| end <= self.buffer.len())
+            .ok_or(ErrorKind::InvalidLength)?;
+        Ok(VectorReader {
+            buffer: self.buffer,
+            offset: target,
+            byte_width: self.byte_width,
+            len,
+            element_type: element.map(|(type_, _)| type_),
+        })
+    }
+
+    /// Reads the value as a map.
+    pub fn as_map(&self) -> crate::Result<MapReader<'buf>> {
+        let result = match self.type_ {
+            FlexBufferType::Map => self.read_map(),
+            _ => Err(self.unexpected_type()),
+        };
+        result.map_err(self.make_error("as_map"))
+    }
+
+    fn read_map(&self) -> Result<MapReader<'buf>, ErrorKind> {
+        let values = self.read_vector(None)?;
+        let keys_offset = values
+            .offset
+            .checked_sub(3 * self.byte_width)
+            .ok_or(ErrorKind::InvalidOffset)?;
+        let keys = Reference {
+            buffer: self.buffer,
+            offset: keys_offset,
+            parent_width: self.byte_width,
+            byte_width: read_uint(self.buffer, keys_offset + self.byte_width, self.byte_width)?
+                as usize,
+            type_: FlexBufferType::VectorKey,
+        };
+        if !matches!(keys.byte_width, 1 | 2 | 4 | 8) {
+            return Err(ErrorKind::InvalidLength);
+        }
+        let keys = keys.read_vector(Some((FlexBufferType::Key, None)))?;
+        if keys.len != values.len {
+            return Err(ErrorKind::InvalidLength);
+        }
+        Ok(MapReader { keys, values })
+    }
+}
+
+/// A vector inside a FlexBuffer.
+#[derive(Copy, Clone, Debug)]
+pub struct VectorReader<'buf> {
+    buffer: &'buf [u8],
+    offset: usize,
+    byte_width: usize,
+    len: usize,
+    /// The type of the elements for typed vectors.
+    element_type: Option<FlexBufferType>,
+}
+
+impl<'buf> VectorReader<'buf> {
+    /// Returns the number of elements in the vector.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the vector is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the element at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> crate::Result<Option<Reference<'buf>>> {
+        if index >= self.len {
+            return Ok(None);
+        }
+        let offset = self.offset + index * self.byte_width;
+        let reference = match self.element_type {
+            Some(type_) => Ok(Reference {
+                buffer: self.buffer,
+                offset,
+                parent_width: self.byte_width,
+                byte_width: 1,
+                type_,
+            }),
+            None => {
+                let packed_type = self.buffer[self.offset + self.len * self.byte_width + index];
+                Reference::new(self.buffer, offset, self.byte_width, packed_type)
+            }
+        };
+        reference.map(Some).map_err(|error_kind| {
+            error_kind.with_error_location("flexbuffers::VectorReader", "get", offset)
+        })
+    }
+
+    /// Returns an iterator over the elements of the vector.
+    pub fn iter(&self) -> VectorIter<'buf> {
+        VectorIter {
+            vector: *self,
+            index: 0,
+        }
+    }
+}
+
+impl<'buf> IntoIterator for VectorReader<'buf> {
+    type Item = crate::Result<Reference<'buf>>;
+    type IntoIter = VectorIter<'buf>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a [`VectorReader`].
+#[derive(Clone, Debug)]
+pub struct VectorIter<'buf> {
+    vector: VectorReader<'buf>,
+    index: usize,
+}
+
+impl<'buf> Iterator for VectorIter<'buf> {
+    type Item = crate::Result<Reference<'buf>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.vector.get(self.index).transpose()?;
+        self.index += 1;
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.vector.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for VectorIter<'_> {}
+
+/// A map inside a FlexBuffer. The keys are sorted, so lookups use a binary
+/// search.
+#[derive(Copy, Clone, Debug)]
+pub struct MapReader<'buf> {
+    keys: VectorReader<'buf>,
+    values: VectorReader<'buf>,
+}
+
+impl<'buf> MapReader<'buf> {
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.values.len
+    }
+
+    /// Checks if the map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.values.len == 0
+    }
+
+    /// Returns the key at `index`, or `None` if it is out of bounds.
+    pub fn key(&self, index: usize) -> crate::Result<Option<&'buf str>> {
+        self.keys.get(index)?.map(|key| key.as_str()).transpose()
+    }
+
+    fn key_bytes(&self, index: usize) -> crate::Result<&'buf [u8]> {
+        let offset = self.keys.offset + index * self.keys.byte_width;
+        indirect(self.keys.buffer, offset, self.keys.byte_width)
+            .and_then(|target| read_key(self.keys.buffer, target))
+            .map_err(|error_kind| {
+                error_kind.with_error_location("flexbuffers::MapReader", "get", offset)
+            })
+    }
+
+    fn entry(&self, index: usize) -> crate::Result<(&'buf str, Reference<'buf>)> {
+        let key = self.key(index)?.unwrap();
+        let value = self.values.get(index)?.unwrap();
+        Ok((key, value))
+    }
+
+    /// Looks up the value for the given key.
+    pub fn get(&self, key: &str) -> crate::Result<Option<Reference<'buf>>> {
+        let key = key.as_bytes();
+        let mut low = 0;
+        let mut high = self.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match self.key_bytes(mid)?.cmp(key) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return self.values.get(mid),
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the values of the map, in the order of their keys.
+    pub fn values(&self) -> VectorReader<'buf> {
+        self.values
+    }
+
+    /// Returns an iterator over the entries of the map, in the order of their
+    /// keys.
+    pub fn iter(&self) -> MapIter<'buf> {
+        MapIter {
+            map: *self,
+            index: 0,
+        }
+    }
+}
+
+impl<'buf> IntoIterator for MapReader<'buf> {
+    type Item = crate::Result<(&'buf str, Reference<'buf>)>;
+    type IntoIter = MapIter<'buf>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of a [`MapReader`].
+#[derive(Clone, Debug)]
+pub struct MapIter<'buf> {
+    map: MapReader<'buf>,
+    index: usize,
+}
+
+impl<'buf> Iterator for MapIter<'buf> {
+    type Item = crate::Result<(&'buf str, Reference<'buf>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.map.len() {
+            return None;
+        }
+        let index = self.index;
+        self.index += 1;
+        Some(self.map.entry(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.map.len() - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for MapIter<'_> {}
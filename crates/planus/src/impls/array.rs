@@ -1,13 +1,13 @@
 use core::mem::MaybeUninit;
 
-use crate::{builder::Builder, traits::*, Cursor, Offset, UnionVectorOffset};
+use crate::{builder::BufferBuilder, traits::*, Cursor, Offset, UnionVectorOffset};
 
 impl<T, P, const N: usize> WriteAsOffset<[P]> for [T; N]
 where
     P: Primitive,
     T: VectorWrite<P>,
 {
-    fn prepare(&self, builder: &mut Builder) -> Offset<[P]> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset<[P]> {
         let mut tmp: [MaybeUninit<T::Value>; N] = unsafe { MaybeUninit::uninit().assume_init() };
         for (t, v) in tmp.iter_mut().zip(self.iter()) {
             t.write(v.prepare(builder));
@@ -16,6 +16,7 @@ where
         //       https://github.com/rust-lang/rust/issues/80908
         let tmp =
             unsafe { (&tmp as *const [MaybeUninit<T::Value>; N] as *const [T::Value; N]).read() };
+        let len = builder.checked_len(self.len());
         unsafe {
             builder.write_with(
                 4 + T::STRIDE.checked_mul(self.len()).unwrap(),
@@ -23,7 +24,7 @@ where
                 |buffer_position, bytes| {
                     let bytes = bytes.as_mut_ptr();
 
-                    (self.len() as u32).write(
+                    len.write(
                         Cursor::new(&mut *(bytes as *mut [MaybeUninit<u8>; 4])),
                         buffer_position,
                     );
@@ -43,7 +44,7 @@ where
 {
     type Prepared = Offset<[P]>;
 
-    fn prepare(&self, builder: &mut Builder) -> Offset<[P]> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset<[P]> {
         WriteAsOffset::prepare(self, builder)
     }
 }
@@ -56,7 +57,7 @@ where
     type Prepared = Offset<[P]>;
 
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Option<Offset<[P]>> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<Offset<[P]>> {
         Some(WriteAsOffset::prepare(self, builder))
     }
 }
@@ -65,7 +66,7 @@ impl<T, P, const N: usize> WriteAsUnionVector<P> for [T; N]
 where
     T: WriteAsUnion<P>,
 {
-    fn prepare(&self, builder: &mut Builder) -> UnionVectorOffset<P> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> UnionVectorOffset<P> {
         WriteAsUnionVector::prepare(self.as_slice(), builder)
     }
 }
@@ -74,7 +75,7 @@ impl<T, P, const N: usize> WriteAsDefaultUnionVector<P> for [T; N]
 where
     T: WriteAsUnion<P>,
 {
-    fn prepare(&self, builder: &mut Builder) -> Option<UnionVectorOffset<P>> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<UnionVectorOffset<P>> {
         if self.is_empty() {
             None
         } else {
@@ -88,7 +89,7 @@ where
     T: WriteAsUnion<P>,
 {
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Option<UnionVectorOffset<P>> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<UnionVectorOffset<P>> {
         Some(WriteAsUnionVector::prepare(self.as_slice(), builder))
     }
 }
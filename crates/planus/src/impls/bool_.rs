@@ -1,8 +1,7 @@
 use core::mem::MaybeUninit;
 
-use crate::{
-    builder::Builder, errors::ErrorKind, slice_helpers::SliceWithStartOffset, traits::*, Cursor,
-};
+use crate::builder::BufferBuilder;
+use crate::{errors::ErrorKind, slice_helpers::SliceWithStartOffset, traits::*, Cursor};
 
 /// # Safety
 /// `ALIGNMENT` and `SIZE` should are 1 for bool.
@@ -13,7 +12,7 @@ unsafe impl Primitive for bool {
 
 impl WriteAsPrimitive<bool> for bool {
     #[inline]
-    fn write<const N: usize>(&self, cursor: Cursor<'_, N>, _buffer_position: u32) {
+    fn write<const N: usize>(&self, cursor: Cursor<'_, N>, _buffer_position: u64) {
         cursor.assert_size().finish([u8::from(*self)]);
     }
 }
@@ -21,7 +20,7 @@ impl WriteAsPrimitive<bool> for bool {
 impl WriteAs<bool> for bool {
     type Prepared = Self;
     #[inline]
-    fn prepare(&self, _builder: &mut Builder) -> Self {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Self {
         *self
     }
 }
@@ -29,7 +28,7 @@ impl WriteAs<bool> for bool {
 impl WriteAsDefault<bool, bool> for bool {
     type Prepared = Self;
     #[inline]
-    fn prepare(&self, _builder: &mut Builder, default: &bool) -> Option<bool> {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>, default: &bool) -> Option<bool> {
         if self == default {
             None
         } else {
@@ -41,7 +40,7 @@ impl WriteAsDefault<bool, bool> for bool {
 impl WriteAsOptional<bool> for bool {
     type Prepared = Self;
     #[inline]
-    fn prepare(&self, _builder: &mut Builder) -> Option<Self> {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Option<Self> {
         Some(*self)
     }
 }
@@ -54,6 +53,11 @@ impl<'buf> TableRead<'buf> for bool {
     ) -> core::result::Result<bool, ErrorKind> {
         Ok(buffer.advance_as_array::<1>(offset)?.as_array()[0] != 0)
     }
+
+    #[inline]
+    unsafe fn from_buffer_unchecked(buffer: SliceWithStartOffset<'buf>, offset: usize) -> bool {
+        unsafe { *buffer.as_slice().get_unchecked(offset) != 0 }
+    }
 }
 
 impl<'buf> VectorRead<'buf> for bool {
@@ -69,11 +73,12 @@ impl<'buf> VectorRead<'buf> for bool {
 /// The implementation of `write_values` initializes all the bytes.
 unsafe impl VectorWrite<bool> for bool {
     const STRIDE: usize = 1;
+    const INLINE: bool = true;
 
     type Value = bool;
 
     #[inline]
-    fn prepare(&self, _builder: &mut Builder) -> Self::Value {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Self::Value {
         *self
     }
 
@@ -81,11 +86,25 @@ unsafe impl VectorWrite<bool> for bool {
     unsafe fn write_values(
         values: &[Self::Value],
         bytes: *mut MaybeUninit<u8>,
-        buffer_position: u32,
+        buffer_position: u64,
     ) {
         let bytes = bytes as *mut [MaybeUninit<u8>; 1];
         for (i, v) in values.iter().enumerate() {
-            v.write(Cursor::new(&mut *bytes.add(i)), buffer_position - i as u32);
+            v.write(Cursor::new(&mut *bytes.add(i)), buffer_position - i as u64);
         }
     }
 }
+
+impl Verify for bool {
+    #[inline]
+    fn verify(&self, _verifier: &mut crate::Verifier) -> crate::Result<()> {
+        Ok(())
+    }
+}
+
+impl KeyCompare for bool {
+    #[inline]
+    fn compare_key(&self, other: &Self) -> core::cmp::Ordering {
+        Ord::cmp(self, other)
+    }
+}
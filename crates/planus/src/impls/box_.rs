@@ -1,8 +1,8 @@
-use crate::{builder::Builder, traits::*, Offset, UnionOffset};
+use crate::{builder::BufferBuilder, traits::*, Offset, UnionOffset};
 
 impl<P, T: ?Sized + WriteAsOffset<P>> WriteAsOffset<P> for alloc::boxed::Box<T> {
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Offset<P> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset<P> {
         T::prepare(self, builder)
     }
 }
@@ -10,7 +10,7 @@ impl<P, T: ?Sized + WriteAsOffset<P>> WriteAsOffset<P> for alloc::boxed::Box<T>
 impl<P: Primitive, T: ?Sized + WriteAs<P>> WriteAs<P> for alloc::boxed::Box<T> {
     type Prepared = T::Prepared;
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> T::Prepared {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> T::Prepared {
         T::prepare(self, builder)
     }
 }
@@ -20,7 +20,7 @@ impl<P: Primitive, D: ?Sized, T: ?Sized + WriteAsDefault<P, D>> WriteAsDefault<P
 {
     type Prepared = T::Prepared;
     #[inline]
-    fn prepare(&self, builder: &mut Builder, default: &D) -> Option<T::Prepared> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>, default: &D) -> Option<T::Prepared> {
         T::prepare(self, builder, default)
     }
 }
@@ -28,14 +28,14 @@ impl<P: Primitive, D: ?Sized, T: ?Sized + WriteAsDefault<P, D>> WriteAsDefault<P
 impl<P: Primitive, T: ?Sized + WriteAsOptional<P>> WriteAsOptional<P> for alloc::boxed::Box<T> {
     type Prepared = T::Prepared;
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Option<T::Prepared> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<T::Prepared> {
         T::prepare(self, builder)
     }
 }
 
 impl<T1: ?Sized, T2: ?Sized + WriteAsUnion<T1>> WriteAsUnion<T1> for alloc::boxed::Box<T2> {
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> UnionOffset<T1> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> UnionOffset<T1> {
         T2::prepare(self, builder)
     }
 }
@@ -44,7 +44,7 @@ impl<T1: ?Sized, T2: ?Sized + WriteAsOptionalUnion<T1>> WriteAsOptionalUnion<T1>
     for alloc::boxed::Box<T2>
 {
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Option<UnionOffset<T1>> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<UnionOffset<T1>> {
         T2::prepare(self, builder)
     }
 }
@@ -1,8 +1,7 @@
 use core::mem::MaybeUninit;
 
-use crate::{
-    errors::ErrorKind, slice_helpers::SliceWithStartOffset, traits::*, Builder, Cursor, Offset,
-};
+use crate::{errors::ErrorKind, slice_helpers::SliceWithStartOffset, traits::*};
+use crate::{BufferBuilder, Cursor, Offset};
 
 impl<'buf> TableRead<'buf> for &'buf [u8] {
     fn from_buffer(
@@ -10,6 +9,23 @@ impl<'buf> TableRead<'buf> for &'buf [u8] {
         offset: usize,
     ) -> core::result::Result<Self, ErrorKind> {
         let (buffer, len) = super::array_from_buffer(buffer, offset)?;
+        Self::from_array(buffer, len)
+    }
+
+    #[inline]
+    unsafe fn from_buffer_unchecked(buffer: SliceWithStartOffset<'buf>, offset: usize) -> Self {
+        unsafe {
+            let (buffer, len) = super::array_from_buffer_unchecked(buffer, offset);
+            buffer.as_slice().get_unchecked(..len)
+        }
+    }
+}
+
+impl<'buf> TableReadArray<'buf> for &'buf [u8] {
+    fn from_array(
+        buffer: SliceWithStartOffset<'buf>,
+        len: usize,
+    ) -> core::result::Result<Self, ErrorKind> {
         buffer.as_slice().get(..len).ok_or(ErrorKind::InvalidLength)
     }
 }
@@ -20,6 +36,15 @@ impl<'buf> TableRead<'buf> for &'buf [i8] {
         offset: usize,
     ) -> core::result::Result<Self, ErrorKind> {
         let (buffer, len) = super::array_from_buffer(buffer, offset)?;
+        Self::from_array(buffer, len)
+    }
+}
+
+impl<'buf> TableReadArray<'buf> for &'buf [i8] {
+    fn from_array(
+        buffer: SliceWithStartOffset<'buf>,
+        len: usize,
+    ) -> core::result::Result<Self, ErrorKind> {
         let slice = buffer
             .as_slice()
             .get(..len)
@@ -30,19 +55,27 @@ impl<'buf> TableRead<'buf> for &'buf [i8] {
 
 impl WriteAsOffset<[u8]> for [u8] {
     #[allow(clippy::let_and_return)]
-    fn prepare(&self, builder: &mut Builder) -> Offset<[u8]> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset<[u8]> {
         #[cfg(feature = "bytes-cache")]
-        let hash = {
+        let hash = if builder.caches_enabled() {
             let hash = builder.bytes_cache.hash(self);
-            if let Some(offset) = builder
-                .bytes_cache
-                .get(builder.inner.as_slice(), hash, self)
-            {
+            if let Some(offset) = builder.bytes_cache.get(
+                builder.inner.as_slice(),
+                hash,
+                self,
+                builder
+                    .inner
+                    .len()
+                    .saturating_sub(crate::builder_cache::MAX_OFFSET_DISTANCE),
+            ) {
                 return offset.into();
             }
-            hash
+            Some(hash)
+        } else {
+            None
         };
 
+        let len = builder.checked_len(self.len());
         // SAFETY: We make sure to write the 4+len bytes inside the closure
         unsafe {
             builder.write_with(
@@ -51,7 +84,7 @@ impl WriteAsOffset<[u8]> for [u8] {
                 |buffer_position, bytes| {
                     let bytes = bytes.as_mut_ptr();
 
-                    (self.len() as u32).write(
+                    len.write(
                         Cursor::new(&mut *(bytes as *mut [MaybeUninit<u8>; 4])),
                         buffer_position,
                     );
@@ -67,9 +100,11 @@ impl WriteAsOffset<[u8]> for [u8] {
         let offset = builder.current_offset();
 
         #[cfg(feature = "bytes-cache")]
-        builder
-            .bytes_cache
-            .insert(hash, offset.into(), builder.inner.as_slice());
+        if let Some(hash) = hash.filter(|_| builder.caches_enabled()) {
+            builder
+                .bytes_cache
+                .insert(hash, builder.inner.len().into(), builder.inner.as_slice());
+        }
 
         offset
     }
@@ -77,18 +112,29 @@ impl WriteAsOffset<[u8]> for [u8] {
 
 impl WriteAsOffset<[i8]> for [i8] {
     #[allow(clippy::let_and_return)]
-    fn prepare(&self, builder: &mut Builder) -> Offset<[i8]> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset<[i8]> {
         #[cfg(feature = "bytes-cache")]
-        let hash = {
+        let hash = if builder.caches_enabled() {
             let v: &[u8] =
                 unsafe { core::slice::from_raw_parts(self.as_ptr() as *const u8, self.len()) };
             let hash = builder.bytes_cache.hash(v);
-            if let Some(offset) = builder.bytes_cache.get(builder.inner.as_slice(), hash, v) {
+            if let Some(offset) = builder.bytes_cache.get(
+                builder.inner.as_slice(),
+                hash,
+                v,
+                builder
+                    .inner
+                    .len()
+                    .saturating_sub(crate::builder_cache::MAX_OFFSET_DISTANCE),
+            ) {
                 return offset.into();
             }
-            hash
+            Some(hash)
+        } else {
+            None
         };
 
+        let len = builder.checked_len(self.len());
         // SAFETY: We make sure to write the 4+len bytes inside the closure
         unsafe {
             builder.write_with(
@@ -97,7 +143,7 @@ impl WriteAsOffset<[i8]> for [i8] {
                 |buffer_position, bytes| {
                     let bytes = bytes.as_mut_ptr();
 
-                    (self.len() as u32).write(
+                    len.write(
                         Cursor::new(&mut *(bytes as *mut [MaybeUninit<u8>; 4])),
                         buffer_position,
                     );
@@ -113,16 +159,18 @@ impl WriteAsOffset<[i8]> for [i8] {
         let offset = builder.current_offset();
 
         #[cfg(feature = "bytes-cache")]
-        builder
-            .bytes_cache
-            .insert(hash, offset.into(), builder.inner.as_slice());
+        if let Some(hash) = hash.filter(|_| builder.caches_enabled()) {
+            builder
+                .bytes_cache
+                .insert(hash, builder.inner.len().into(), builder.inner.as_slice());
+        }
 
         offset
     }
 }
 
 impl<const N: usize> WriteAsOffset<[u8]> for [u8; N] {
-    fn prepare(&self, builder: &mut Builder) -> Offset<[u8]> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset<[u8]> {
         WriteAsOffset::prepare(self.as_slice(), builder)
     }
 }
@@ -130,7 +178,7 @@ impl<const N: usize> WriteAsOffset<[u8]> for [u8; N] {
 impl<const N: usize> WriteAs<Offset<[u8]>> for [u8; N] {
     type Prepared = Offset<[u8]>;
 
-    fn prepare(&self, builder: &mut Builder) -> Offset<[u8]> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset<[u8]> {
         WriteAsOffset::prepare(self.as_slice(), builder)
     }
 }
@@ -139,13 +187,13 @@ impl<const N: usize> WriteAsOptional<Offset<[u8]>> for [u8; N] {
     type Prepared = Offset<[u8]>;
 
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Option<Offset<[u8]>> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<Offset<[u8]>> {
         Some(WriteAsOffset::prepare(self.as_slice(), builder))
     }
 }
 
 impl<const N: usize> WriteAsOffset<[i8]> for [i8; N] {
-    fn prepare(&self, builder: &mut Builder) -> Offset<[i8]> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset<[i8]> {
         WriteAsOffset::prepare(self.as_slice(), builder)
     }
 }
@@ -153,7 +201,7 @@ impl<const N: usize> WriteAsOffset<[i8]> for [i8; N] {
 impl<const N: usize> WriteAs<Offset<[i8]>> for [i8; N] {
     type Prepared = Offset<[i8]>;
 
-    fn prepare(&self, builder: &mut Builder) -> Offset<[i8]> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset<[i8]> {
         WriteAsOffset::prepare(self.as_slice(), builder)
     }
 }
@@ -162,7 +210,19 @@ impl<const N: usize> WriteAsOptional<Offset<[i8]>> for [i8; N] {
     type Prepared = Offset<[i8]>;
 
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Option<Offset<[i8]>> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<Offset<[i8]>> {
         Some(WriteAsOffset::prepare(self.as_slice(), builder))
     }
 }
+
+impl Verify for &[u8] {
+    fn verify(&self, verifier: &mut crate::Verifier) -> crate::Result<()> {
+        verifier.visit_bytes_at_table(self.len(), "[u8]")
+    }
+}
+
+impl Verify for &[i8] {
+    fn verify(&self, verifier: &mut crate::Verifier) -> crate::Result<()> {
+        verifier.visit_bytes_at_table(self.len(), "[i8]")
+    }
+}
@@ -1,19 +1,24 @@
 mod array;
 mod bool_;
+#[cfg(feature = "alloc")]
 mod box_;
 mod byte_slice;
 mod offset;
+mod offset64;
 mod option;
 mod primitives;
 mod ref_;
 mod result;
 mod slice;
 mod str;
+#[cfg(feature = "alloc")]
 mod string;
 mod union_offset;
 mod union_vector_offset;
 mod unit;
+#[cfg(feature = "alloc")]
 mod vec;
+mod vector;
 
 pub(crate) fn array_from_buffer(
     buffer: crate::slice_helpers::SliceWithStartOffset<'_>,
@@ -30,3 +35,47 @@ pub(crate) fn array_from_buffer(
     let len: u32 = crate::traits::TableRead::from_buffer(buffer, 0)?;
     Ok((buffer.advance(4)?, len as usize))
 }
+
+/// Like [`array_from_buffer`], but without checking that the offset and
+/// length are within the buffer.
+///
+/// # Safety
+///
+/// [`array_from_buffer`] must succeed for the same arguments.
+pub(crate) unsafe fn array_from_buffer_unchecked(
+    buffer: crate::slice_helpers::SliceWithStartOffset<'_>,
+    offset: usize,
+) -> (crate::slice_helpers::SliceWithStartOffset<'_>, usize) {
+    unsafe {
+        let value: u32 = crate::traits::TableRead::from_buffer_unchecked(buffer, offset);
+        let buffer = buffer.unchecked_advance(offset + value as usize);
+        let len: u32 = crate::traits::TableRead::from_buffer_unchecked(buffer, 0);
+        (buffer.unchecked_advance(4), len as usize)
+    }
+}
+
+/// Like [`array_from_buffer`], but for arrays behind a 64-bit offset. If
+/// `vector64` is set, the array has a 64-bit length as well.
+pub(crate) fn array64_from_buffer(
+    buffer: crate::slice_helpers::SliceWithStartOffset<'_>,
+    offset: usize,
+    vector64: bool,
+) -> core::result::Result<
+    (crate::slice_helpers::SliceWithStartOffset<'_>, usize),
+    crate::errors::ErrorKind,
+> {
+    let value: u64 = crate::traits::TableRead::from_buffer(buffer, offset)?;
+    let array_offset = usize::try_from(value)
+        .ok()
+        .and_then(|value| offset.checked_add(value))
+        .ok_or(crate::errors::ErrorKind::InvalidOffset)?;
+    let buffer = buffer.advance(array_offset)?;
+    if vector64 {
+        let len: u64 = crate::traits::TableRead::from_buffer(buffer, 0)?;
+        let len = usize::try_from(len).map_err(|_| crate::errors::ErrorKind::InvalidLength)?;
+        Ok((buffer.advance(8)?, len))
+    } else {
+        let len: u32 = crate::traits::TableRead::from_buffer(buffer, 0)?;
+        Ok((buffer.advance(4)?, len as usize))
+    }
+}
@@ -1,6 +1,6 @@
 use core::mem::MaybeUninit;
 
-use crate::{builder::Builder, traits::*, Cursor, Offset};
+use crate::{builder::BufferBuilder, traits::*, Cursor, Offset};
 
 /// # Safety
 /// An offset is a 32-bit unsigned integer + a zero-sized type.
@@ -11,15 +11,16 @@ unsafe impl<T: ?Sized> Primitive for Offset<T> {
 
 impl<T: ?Sized> WriteAsPrimitive<Offset<T>> for Offset<T> {
     #[inline]
-    fn write<const N: usize>(&self, cursor: Cursor<'_, N>, buffer_position: u32) {
-        cursor
-            .assert_size()
-            .finish(u32::to_le_bytes(buffer_position - self.offset));
+    fn write<const N: usize>(&self, cursor: Cursor<'_, N>, buffer_position: u64) {
+        // Both positions are truncated to 32 bits, which gives the right
+        // distance as the builder keeps it within 4 GiB
+        let offset = (buffer_position as u32).wrapping_sub(self.offset);
+        cursor.assert_size().finish(offset.to_le_bytes());
     }
 }
 
 impl<T: ?Sized> WriteAsOffset<T> for Offset<T> {
-    fn prepare(&self, _builder: &mut Builder) -> Offset<T> {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Offset<T> {
         *self
     }
 }
@@ -27,7 +28,7 @@ impl<T: ?Sized> WriteAsOffset<T> for Offset<T> {
 impl<T: ?Sized> WriteAs<Offset<T>> for Offset<T> {
     type Prepared = Self;
     #[inline]
-    fn prepare(&self, _builder: &mut Builder) -> Self {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Self {
         *self
     }
 }
@@ -35,7 +36,7 @@ impl<T: ?Sized> WriteAs<Offset<T>> for Offset<T> {
 impl<T: ?Sized> WriteAsOptional<Offset<T>> for Offset<T> {
     type Prepared = Self;
     #[inline]
-    fn prepare(&self, _builder: &mut Builder) -> Option<Self> {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Option<Self> {
         Some(*self)
     }
 }
@@ -43,7 +44,7 @@ impl<T: ?Sized> WriteAsOptional<Offset<T>> for Offset<T> {
 impl<T: ?Sized, D> WriteAsDefault<Offset<T>, D> for Offset<T> {
     type Prepared = Self;
     #[inline]
-    fn prepare(&self, _builder: &mut Builder, _default: &D) -> Option<Self> {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>, _default: &D) -> Option<Self> {
         Some(*self)
     }
 }
@@ -52,10 +53,11 @@ impl<T: ?Sized, D> WriteAsDefault<Offset<T>, D> for Offset<T> {
 // The implementation of `write_values` initializes all the bytes.
 unsafe impl<T: ?Sized> VectorWrite<Offset<T>> for Offset<T> {
     const STRIDE: usize = 4;
+    const INLINE: bool = true;
     type Value = Offset<T>;
 
     #[inline]
-    fn prepare(&self, _builder: &mut Builder) -> Self::Value {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Self::Value {
         *self
     }
 
@@ -63,13 +65,13 @@ unsafe impl<T: ?Sized> VectorWrite<Offset<T>> for Offset<T> {
     unsafe fn write_values(
         values: &[Offset<T>],
         bytes: *mut MaybeUninit<u8>,
-        buffer_position: u32,
+        buffer_position: u64,
     ) {
         let bytes = bytes as *mut [MaybeUninit<u8>; 4];
         for (i, v) in values.iter().enumerate() {
             v.write(
                 Cursor::new(&mut *bytes.add(i)),
-                buffer_position - (Self::STRIDE * i) as u32,
+                buffer_position - (Self::STRIDE * i) as u64,
             );
         }
     }
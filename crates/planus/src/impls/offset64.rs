@@ -0,0 +1,205 @@
+use crate::{builder::BufferBuilder, traits::*, Cursor, Offset64, Vector64Offset};
+
+macro_rules! gen_offset64_impls {
+    ($ty:ident) => {
+        /// # Safety
+        /// A 64-bit offset is a 64-bit unsigned integer + a zero-sized type.
+        unsafe impl<T: ?Sized> Primitive for $ty<T> {
+            const ALIGNMENT: usize = 8;
+            const SIZE: usize = 8;
+        }
+
+        impl<T: ?Sized> WriteAsPrimitive<$ty<T>> for $ty<T> {
+            #[inline]
+            fn write<const N: usize>(&self, cursor: Cursor<'_, N>, buffer_position: u64) {
+                cursor
+                    .assert_size()
+                    .finish(u64::to_le_bytes(buffer_position - self.offset));
+            }
+        }
+
+        impl<T: ?Sized> WriteAs<$ty<T>> for $ty<T> {
+            type Prepared = Self;
+            #[inline]
+            fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Self {
+                *self
+            }
+        }
+
+        impl<T: ?Sized> WriteAsOptional<$ty<T>> for $ty<T> {
+            type Prepared = Self;
+            #[inline]
+            fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Option<Self> {
+                Some(*self)
+            }
+        }
+
+        impl<T: ?Sized, D: ?Sized> WriteAsDefault<$ty<T>, D> for $ty<T> {
+            type Prepared = Self;
+            #[inline]
+            fn prepare(&self, _builder: &mut BufferBuilder<'_>, _default: &D) -> Option<Self> {
+                Some(*self)
+            }
+        }
+    };
+}
+
+gen_offset64_impls!(Offset64);
+gen_offset64_impls!(Vector64Offset);
+
+impl WriteAs<Offset64<str>> for str {
+    type Prepared = Offset64<str>;
+
+    #[inline]
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset64<str> {
+        builder.prepare_offset64(|builder| WriteAsOffset::prepare(self, builder))
+    }
+}
+
+impl WriteAsOptional<Offset64<str>> for str {
+    type Prepared = Offset64<str>;
+
+    #[inline]
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<Offset64<str>> {
+        Some(builder.prepare_offset64(|builder| WriteAsOffset::prepare(self, builder)))
+    }
+}
+
+impl WriteAsDefault<Offset64<str>, str> for str {
+    type Prepared = Offset64<str>;
+
+    #[inline]
+    fn prepare(&self, builder: &mut BufferBuilder<'_>, default: &str) -> Option<Offset64<str>> {
+        if self == default {
+            None
+        } else {
+            Some(builder.prepare_offset64(|builder| WriteAsOffset::prepare(self, builder)))
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl WriteAs<Offset64<str>> for alloc::string::String {
+    type Prepared = Offset64<str>;
+
+    #[inline]
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset64<str> {
+        WriteAs::<Offset64<str>>::prepare(self.as_str(), builder)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl WriteAsOptional<Offset64<str>> for alloc::string::String {
+    type Prepared = Offset64<str>;
+
+    #[inline]
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<Offset64<str>> {
+        WriteAsOptional::<Offset64<str>>::prepare(self.as_str(), builder)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl WriteAsDefault<Offset64<str>, str> for alloc::string::String {
+    type Prepared = Offset64<str>;
+
+    #[inline]
+    fn prepare(&self, builder: &mut BufferBuilder<'_>, default: &str) -> Option<Offset64<str>> {
+        WriteAsDefault::<Offset64<str>, str>::prepare(self.as_str(), builder, default)
+    }
+}
+
+/// Implements the traits for writing a vector behind a 64-bit offset for a
+/// type that can be viewed as a slice.
+macro_rules! gen_vector64_impls {
+    ([$($params:tt)*] $ty:ty, $as_slice:expr) => {
+        impl<T, P, $($params)*> WriteAs<Offset64<[P]>> for $ty
+        where
+            [T]: WriteAsOffset<[P]>,
+        {
+            type Prepared = Offset64<[P]>;
+
+            #[inline]
+            fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset64<[P]> {
+                builder.prepare_offset64(|builder| WriteAsOffset::prepare($as_slice(self), builder))
+            }
+        }
+
+        impl<T, P, $($params)*> WriteAsOptional<Offset64<[P]>> for $ty
+        where
+            [T]: WriteAsOffset<[P]>,
+        {
+            type Prepared = Offset64<[P]>;
+
+            #[inline]
+            fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<Offset64<[P]>> {
+                Some(builder.prepare_offset64(|builder| WriteAsOffset::prepare($as_slice(self), builder)))
+            }
+        }
+
+        impl<T, P, $($params)*> WriteAsDefault<Offset64<[P]>, ()> for $ty
+        where
+            [T]: WriteAsOffset<[P]>,
+        {
+            type Prepared = Offset64<[P]>;
+
+            #[inline]
+            fn prepare(&self, builder: &mut BufferBuilder<'_>, _default: &()) -> Option<Offset64<[P]>> {
+                let slice: &[T] = $as_slice(self);
+                if slice.is_empty() {
+                    None
+                } else {
+                    Some(builder.prepare_offset64(|builder| WriteAsOffset::prepare(slice, builder)))
+                }
+            }
+        }
+
+        impl<T, P, $($params)*> WriteAs<Vector64Offset<P>> for $ty
+        where
+            T: VectorWrite<P>,
+            P: Primitive,
+        {
+            type Prepared = Vector64Offset<P>;
+
+            #[inline]
+            fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Vector64Offset<P> {
+                builder.create_vector64($as_slice(self))
+            }
+        }
+
+        impl<T, P, $($params)*> WriteAsOptional<Vector64Offset<P>> for $ty
+        where
+            T: VectorWrite<P>,
+            P: Primitive,
+        {
+            type Prepared = Vector64Offset<P>;
+
+            #[inline]
+            fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<Vector64Offset<P>> {
+                Some(builder.create_vector64($as_slice(self)))
+            }
+        }
+
+        impl<T, P, $($params)*> WriteAsDefault<Vector64Offset<P>, ()> for $ty
+        where
+            T: VectorWrite<P>,
+            P: Primitive,
+        {
+            type Prepared = Vector64Offset<P>;
+
+            #[inline]
+            fn prepare(&self, builder: &mut BufferBuilder<'_>, _default: &()) -> Option<Vector64Offset<P>> {
+                let slice: &[T] = $as_slice(self);
+                if slice.is_empty() {
+                    None
+                } else {
+                    Some(builder.create_vector64(slice))
+                }
+            }
+        }
+    };
+}
+
+gen_vector64_impls!([][T], core::convert::identity);
+#[cfg(feature = "alloc")]
+gen_vector64_impls!([] alloc::vec::Vec<T>, alloc::vec::Vec::as_slice);
+gen_vector64_impls!([const N: usize] [T; N], <[T; N]>::as_slice);
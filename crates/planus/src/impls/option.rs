@@ -1,23 +1,34 @@
-use crate::{builder::Builder, traits::*, UnionOffset, UnionVectorOffset};
+use crate::traits::*;
+use crate::{builder::BufferBuilder, UnionOffset, UnionVectorOffset};
 
 impl<P: Primitive, T: WriteAsOptional<P>> WriteAsOptional<P> for Option<T> {
     type Prepared = T::Prepared;
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Option<T::Prepared> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<T::Prepared> {
         self.as_ref()?.prepare(builder)
     }
 }
 
 impl<T1, T2: WriteAsOptionalUnion<T1>> WriteAsOptionalUnion<T1> for Option<T2> {
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Option<UnionOffset<T1>> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<UnionOffset<T1>> {
         self.as_ref()?.prepare(builder)
     }
 }
 
 impl<T1, T2: WriteAsOptionalUnionVector<T1>> WriteAsOptionalUnionVector<T1> for Option<T2> {
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Option<UnionVectorOffset<T1>> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<UnionVectorOffset<T1>> {
         self.as_ref()?.prepare(builder)
     }
 }
+
+impl<T: Verify> Verify for Option<T> {
+    #[inline]
+    fn verify(&self, verifier: &mut crate::Verifier) -> crate::Result<()> {
+        if let Some(value) = self {
+            value.verify(verifier)?;
+        }
+        Ok(())
+    }
+}
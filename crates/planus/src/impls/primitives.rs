@@ -1,8 +1,7 @@
 use core::mem::MaybeUninit;
 
-use crate::{
-    builder::Builder, errors::ErrorKind, slice_helpers::SliceWithStartOffset, traits::*, Cursor,
-};
+use crate::builder::BufferBuilder;
+use crate::{errors::ErrorKind, slice_helpers::SliceWithStartOffset, traits::*, Cursor};
 
 macro_rules! unsafe_gen_primitive_types {
     ($ty:ty, $size:expr) => {
@@ -15,7 +14,7 @@ macro_rules! unsafe_gen_primitive_types {
 
         impl WriteAsPrimitive<$ty> for $ty {
             #[inline]
-            fn write<const N: usize>(&self, cursor: Cursor<'_, N>, _buffer_position: u32) {
+            fn write<const N: usize>(&self, cursor: Cursor<'_, N>, _buffer_position: u64) {
                 cursor.assert_size().finish(self.to_le_bytes());
             }
         }
@@ -23,7 +22,7 @@ macro_rules! unsafe_gen_primitive_types {
         impl WriteAs<$ty> for $ty {
             type Prepared = Self;
             #[inline]
-            fn prepare(&self, _builder: &mut Builder) -> Self {
+            fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Self {
                 *self
             }
         }
@@ -31,7 +30,7 @@ macro_rules! unsafe_gen_primitive_types {
         impl WriteAsDefault<$ty, $ty> for $ty {
             type Prepared = Self;
             #[inline]
-            fn prepare(&self, _builder: &mut Builder, default: &$ty) -> Option<Self> {
+            fn prepare(&self, _builder: &mut BufferBuilder<'_>, default: &$ty) -> Option<Self> {
                 #[allow(clippy::float_cmp)]
                 if self == default {
                     None
@@ -44,7 +43,7 @@ macro_rules! unsafe_gen_primitive_types {
         impl WriteAsOptional<$ty> for $ty {
             type Prepared = Self;
             #[inline]
-            fn prepare(&self, _builder: &mut Builder) -> Option<Self> {
+            fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Option<Self> {
                 Some(*self)
             }
         }
@@ -58,6 +57,22 @@ macro_rules! unsafe_gen_primitive_types {
                 let buffer = buffer.advance_as_array(offset)?.as_array();
                 Ok(<$ty>::from_le_bytes(*buffer))
             }
+
+            #[inline]
+            unsafe fn from_buffer_unchecked(
+                buffer: SliceWithStartOffset<'buf>,
+                offset: usize,
+            ) -> $ty {
+                let buffer = unsafe { buffer.unchecked_advance_as_array(offset) }.as_array();
+                <$ty>::from_le_bytes(*buffer)
+            }
+        }
+
+        impl Verify for $ty {
+            #[inline]
+            fn verify(&self, _verifier: &mut crate::Verifier) -> crate::Result<()> {
+                Ok(())
+            }
         }
     };
 }
@@ -74,13 +89,21 @@ macro_rules! unsafe_gen_primitive_types_with_vectors {
             }
         }
 
+        /// # Safety
+        /// The type is stored in little-endian order without padding, and
+        /// every bit pattern is a valid value.
+        unsafe impl<'buf> VectorReadSlice<'buf> for $ty {
+            type Value = $ty;
+        }
+
         /// # Safety
         /// write_values initalizes bytes.
         unsafe impl VectorWrite<$ty> for $ty {
             const STRIDE: usize = $size;
+            const INLINE: bool = true;
             type Value = $ty;
             #[inline]
-            fn prepare(&self, _builder: &mut Builder) -> Self::Value {
+            fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Self::Value {
                 *self
             }
 
@@ -88,13 +111,13 @@ macro_rules! unsafe_gen_primitive_types_with_vectors {
             unsafe fn write_values(
                 values: &[$ty],
                 bytes: *mut MaybeUninit<u8>,
-                buffer_position: u32,
+                buffer_position: u64,
             ) {
                 let bytes = bytes as *mut [MaybeUninit<u8>; $size];
                 for (i, v) in values.iter().enumerate() {
                     v.write(
                         Cursor::new(&mut *bytes.add(i)),
-                        buffer_position - ($size * i) as u32,
+                        buffer_position - ($size * i) as u64,
                     );
                 }
             }
@@ -112,3 +135,38 @@ unsafe_gen_primitive_types_with_vectors!(i64, core::mem::size_of::<i64>());
 unsafe_gen_primitive_types_with_vectors!(u64, core::mem::size_of::<u64>());
 unsafe_gen_primitive_types_with_vectors!(f32, core::mem::size_of::<f32>());
 unsafe_gen_primitive_types_with_vectors!(f64, core::mem::size_of::<f64>());
+
+macro_rules! gen_key_compare {
+    ($($ty:ty),*) => {
+        $(
+            impl KeyCompare for $ty {
+                #[inline]
+                fn compare_key(&self, other: &Self) -> core::cmp::Ordering {
+                    Ord::cmp(self, other)
+                }
+            }
+        )*
+    };
+}
+
+gen_key_compare!(i8, u8, i16, u16, i32, u32, i64, u64);
+
+impl KeyCompare for f32 {
+    #[inline]
+    fn compare_key(&self, other: &Self) -> core::cmp::Ordering {
+        // Matches the `<` comparison used by flatc, and puts NaNs last so
+        // the ordering stays consistent
+        self.partial_cmp(other)
+            .unwrap_or_else(|| self.is_nan().cmp(&other.is_nan()))
+    }
+}
+
+impl KeyCompare for f64 {
+    #[inline]
+    fn compare_key(&self, other: &Self) -> core::cmp::Ordering {
+        // Matches the `<` comparison used by flatc, and puts NaNs last so
+        // the ordering stays consistent
+        self.partial_cmp(other)
+            .unwrap_or_else(|| self.is_nan().cmp(&other.is_nan()))
+    }
+}
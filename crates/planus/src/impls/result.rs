@@ -1,4 +1,5 @@
-use crate::traits::{VectorRead, VectorReadInner};
+use crate::traits::{VectorRead, VectorReadInner, Verify};
+use crate::traits::{VectorWrite, WriteAsPrimitive, WriteAsUnion};
 
 impl<'buf, T: VectorReadInner<'buf>, E: 'buf> VectorRead<'buf> for Result<T, E>
 where
@@ -10,3 +11,95 @@ where
         Ok(T::from_buffer(buffer, offset)?)
     }
 }
+
+impl<T: Verify, E: Clone> Verify for Result<T, E>
+where
+    crate::Error: From<E>,
+{
+    #[inline]
+    fn verify(&self, verifier: &mut crate::Verifier) -> crate::Result<()> {
+        match self {
+            Ok(value) => value.verify(verifier),
+            Err(error) => Err(error.clone().into()),
+        }
+    }
+}
+
+/// A prepared vector element copied from another buffer, or `None` if it
+/// could not be read, in which case zeros are written in its place and the
+/// builder reports the error.
+#[doc(hidden)]
+pub struct CopiedElement<T>(Option<T>);
+
+impl<P, T: WriteAsPrimitive<P>> WriteAsPrimitive<P> for CopiedElement<T> {
+    #[inline]
+    fn write<const N: usize>(&self, cursor: crate::Cursor<'_, N>, buffer_position: u64) {
+        match &self.0 {
+            Some(value) => value.write(cursor, buffer_position),
+            None => cursor.finish([0; N]),
+        }
+    }
+}
+
+/// # Safety
+/// `T` must implement `VectorWrite` following the safety requirements from the trait.
+unsafe impl<P, T: VectorWrite<P>, E: Clone> VectorWrite<P> for Result<T, E>
+where
+    crate::Error: From<E>,
+{
+    const STRIDE: usize = T::STRIDE;
+    const INLINE: bool = T::INLINE;
+    type Value = CopiedElement<T::Value>;
+
+    /// Prepares the value when copying a vector read from another buffer.
+    ///
+    /// Elements that could not be read make finishing the buffer fail, see
+    /// [`BufferBuilder::try_copy`](crate::BufferBuilder::try_copy).
+    #[inline]
+    fn prepare(&self, builder: &mut crate::BufferBuilder<'_>) -> Self::Value {
+        match self {
+            Ok(value) => CopiedElement(Some(value.prepare(builder))),
+            Err(error) => {
+                builder.mark_copy_error(error.clone().into());
+                CopiedElement(None)
+            }
+        }
+    }
+
+    #[inline]
+    unsafe fn write_values(
+        values: &[Self::Value],
+        bytes: *mut core::mem::MaybeUninit<u8>,
+        buffer_position: u64,
+    ) {
+        for (i, value) in values.iter().enumerate() {
+            let bytes = bytes.add(Self::STRIDE * i);
+            match &value.0 {
+                Some(value) => T::write_values(
+                    core::slice::from_ref(value),
+                    bytes,
+                    buffer_position - (Self::STRIDE * i) as u64,
+                ),
+                None => core::ptr::write_bytes(bytes, 0, Self::STRIDE),
+            }
+        }
+    }
+}
+
+impl<P, T: WriteAsUnion<P>, E: Clone> WriteAsUnion<P> for Result<T, E>
+where
+    crate::Error: From<E>,
+{
+    /// Prepares the value when copying a union vector read from another
+    /// buffer.
+    ///
+    /// Values that could not be read make finishing the buffer fail, see
+    /// [`BufferBuilder::try_copy`](crate::BufferBuilder::try_copy).
+    #[inline]
+    fn prepare(&self, builder: &mut crate::BufferBuilder<'_>) -> crate::UnionOffset<P> {
+        match self {
+            Ok(value) => value.prepare(builder),
+            Err(error) => crate::UnionOffset::new(0, builder.copy_failed(error.clone().into())),
+        }
+    }
+}
@@ -1,28 +1,33 @@
 use core::mem::MaybeUninit;
 
-use crate::{
-    builder::Builder, errors::ErrorKind, slice_helpers::SliceWithStartOffset, traits::*, Cursor,
-    Offset,
-};
+use crate::{builder::BufferBuilder, Cursor, Offset};
+use crate::{errors::ErrorKind, slice_helpers::SliceWithStartOffset, traits::*};
 
 impl WriteAsOffset<str> for str {
     #[inline]
     #[allow(clippy::let_and_return)]
-    fn prepare(&self, builder: &mut Builder) -> Offset<str> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset<str> {
         #[cfg(feature = "string-cache")]
-        let hash = {
+        let hash = if builder.caches_enabled() {
             let hash = builder.string_cache.hash(self.as_bytes());
-            if let Some(offset) =
+            if let Some(offset) = builder.string_cache.get(
+                builder.inner.as_slice(),
+                hash,
+                self.as_bytes(),
                 builder
-                    .string_cache
-                    .get(builder.inner.as_slice(), hash, self.as_bytes())
-            {
+                    .inner
+                    .len()
+                    .saturating_sub(crate::builder_cache::MAX_OFFSET_DISTANCE),
+            ) {
                 return offset.into();
             }
-            hash
+            Some(hash)
+        } else {
+            None
         };
 
         let size_including_len_and_null = self.len().checked_add(5).unwrap();
+        let len = builder.checked_len(self.len());
         // SAFETY: We make sure to write the 4+len+1 bytes inside the closure
         unsafe {
             builder.write_with(
@@ -31,7 +36,7 @@ impl WriteAsOffset<str> for str {
                 |buffer_position, bytes| {
                     let bytes = bytes.as_mut_ptr();
 
-                    (self.len() as u32).write(
+                    len.write(
                         Cursor::new(&mut *(bytes as *mut [MaybeUninit<u8>; 4])),
                         buffer_position,
                     );
@@ -47,9 +52,11 @@ impl WriteAsOffset<str> for str {
         let offset = builder.current_offset();
 
         #[cfg(feature = "string-cache")]
-        builder
-            .string_cache
-            .insert(hash, offset.into(), builder.inner.as_slice());
+        if let Some(hash) = hash.filter(|_| builder.caches_enabled()) {
+            builder
+                .string_cache
+                .insert(hash, builder.inner.len().into(), builder.inner.as_slice());
+        }
 
         offset
     }
@@ -59,7 +66,7 @@ impl WriteAs<Offset<str>> for str {
     type Prepared = Offset<str>;
 
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Offset<str> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset<str> {
         WriteAsOffset::prepare(self, builder)
     }
 }
@@ -67,7 +74,7 @@ impl WriteAs<Offset<str>> for str {
 impl WriteAsOptional<Offset<str>> for str {
     type Prepared = Offset<str>;
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Option<Offset<str>> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<Offset<str>> {
         Some(WriteAsOffset::prepare(self, builder))
     }
 }
@@ -76,7 +83,7 @@ impl WriteAsDefault<Offset<str>, str> for str {
     type Prepared = Offset<str>;
 
     #[inline]
-    fn prepare(&self, builder: &mut Builder, default: &str) -> Option<Offset<str>> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>, default: &str) -> Option<Offset<str>> {
         if self == default {
             None
         } else {
@@ -91,6 +98,23 @@ impl<'buf> TableRead<'buf> for &'buf str {
         offset: usize,
     ) -> core::result::Result<Self, ErrorKind> {
         let (buffer, len) = super::array_from_buffer(buffer, offset)?;
+        Self::from_array(buffer, len)
+    }
+
+    #[inline]
+    unsafe fn from_buffer_unchecked(buffer: SliceWithStartOffset<'buf>, offset: usize) -> Self {
+        unsafe {
+            let (buffer, len) = super::array_from_buffer_unchecked(buffer, offset);
+            core::str::from_utf8_unchecked(buffer.as_slice().get_unchecked(..len))
+        }
+    }
+}
+
+impl<'buf> TableReadArray<'buf> for &'buf str {
+    fn from_array(
+        buffer: SliceWithStartOffset<'buf>,
+        len: usize,
+    ) -> core::result::Result<Self, ErrorKind> {
         #[cfg(feature = "extra-validation")]
         if buffer.as_slice().get(len) != Some(&0) {
             return Err(ErrorKind::MissingNullTerminator);
@@ -138,22 +162,35 @@ unsafe impl VectorWrite<Offset<str>> for str {
 
     const STRIDE: usize = 4;
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Self::Value {
-        WriteAs::prepare(self, builder)
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Self::Value {
+        WriteAsOffset::prepare(self, builder)
     }
 
     #[inline]
     unsafe fn write_values(
         values: &[Offset<str>],
         bytes: *mut MaybeUninit<u8>,
-        buffer_position: u32,
+        buffer_position: u64,
     ) {
         let bytes = bytes as *mut [MaybeUninit<u8>; 4];
         for (i, v) in values.iter().enumerate() {
             v.write(
                 Cursor::new(&mut *bytes.add(i)),
-                buffer_position - (4 * i) as u32,
+                buffer_position - (4 * i) as u64,
             );
         }
     }
 }
+
+impl Verify for &str {
+    fn verify(&self, verifier: &mut crate::Verifier) -> crate::Result<()> {
+        verifier.visit_bytes_at_table(self.len(), "str")
+    }
+}
+
+impl KeyCompare for str {
+    #[inline]
+    fn compare_key(&self, other: &Self) -> core::cmp::Ordering {
+        Ord::cmp(self.as_bytes(), other.as_bytes())
+    }
+}
@@ -1,10 +1,10 @@
 use core::mem::MaybeUninit;
 
-use crate::{builder::Builder, traits::*, Cursor, Offset};
+use crate::{builder::BufferBuilder, traits::*, Cursor, Offset};
 
 impl WriteAsOffset<str> for alloc::string::String {
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Offset<str> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset<str> {
         WriteAsOffset::prepare(self.as_str(), builder)
     }
 }
@@ -13,7 +13,7 @@ impl WriteAs<Offset<str>> for alloc::string::String {
     type Prepared = Offset<str>;
 
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Offset<str> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset<str> {
         WriteAsOffset::prepare(self.as_str(), builder)
     }
 }
@@ -22,7 +22,7 @@ impl WriteAsDefault<Offset<str>, str> for alloc::string::String {
     type Prepared = Offset<str>;
 
     #[inline]
-    fn prepare(&self, builder: &mut Builder, default: &str) -> Option<Offset<str>> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>, default: &str) -> Option<Offset<str>> {
         if self == default {
             None
         } else {
@@ -35,7 +35,7 @@ impl WriteAsOptional<Offset<str>> for alloc::string::String {
     type Prepared = Offset<str>;
 
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Option<Offset<str>> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<Offset<str>> {
         Some(WriteAsOffset::prepare(self.as_str(), builder))
     }
 }
@@ -47,21 +47,21 @@ unsafe impl VectorWrite<Offset<str>> for alloc::string::String {
 
     const STRIDE: usize = 4;
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Self::Value {
-        WriteAs::prepare(self, builder)
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Self::Value {
+        WriteAs::<Offset<str>>::prepare(self, builder)
     }
 
     #[inline]
     unsafe fn write_values(
         values: &[Offset<str>],
         bytes: *mut MaybeUninit<u8>,
-        buffer_position: u32,
+        buffer_position: u64,
     ) {
         let bytes = bytes as *mut [MaybeUninit<u8>; 4];
         for (i, v) in values.iter().enumerate() {
             v.write(
                 Cursor::new(&mut *bytes.add(i)),
-                buffer_position - (4 * i) as u32,
+                buffer_position - (4 * i) as u64,
             );
         }
     }
@@ -1,15 +1,15 @@
-use crate::{builder::Builder, traits::*, UnionOffset};
+use crate::{builder::BufferBuilder, traits::*, UnionOffset};
 
 impl<T: ?Sized> WriteAsUnion<T> for UnionOffset<T> {
     #[inline]
-    fn prepare(&self, _builder: &mut Builder) -> Self {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Self {
         *self
     }
 }
 
 impl<T: ?Sized> WriteAsOptionalUnion<T> for UnionOffset<T> {
     #[inline]
-    fn prepare(&self, _builder: &mut Builder) -> Option<Self> {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Option<Self> {
         Some(*self)
     }
 }
@@ -1,22 +1,22 @@
-use crate::{builder::Builder, traits::*, UnionVectorOffset};
+use crate::{builder::BufferBuilder, traits::*, UnionVectorOffset};
 
 impl<T: ?Sized> WriteAsUnionVector<T> for UnionVectorOffset<T> {
     #[inline]
-    fn prepare(&self, _builder: &mut Builder) -> Self {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Self {
         *self
     }
 }
 
 impl<T: ?Sized> WriteAsOptionalUnionVector<T> for UnionVectorOffset<T> {
     #[inline]
-    fn prepare(&self, _builder: &mut Builder) -> Option<Self> {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Option<Self> {
         Some(*self)
     }
 }
 
 impl<T: ?Sized> WriteAsDefaultUnionVector<T> for UnionVectorOffset<T> {
     #[inline]
-    fn prepare(&self, _builder: &mut Builder) -> Option<Self> {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Option<Self> {
         Some(*self)
     }
 }
@@ -1,12 +1,12 @@
 use crate::{
-    builder::Builder,
+    builder::BufferBuilder,
     traits::{Primitive, WriteAsOptional, WriteAsOptionalUnion, WriteAsPrimitive},
     Cursor, UnionOffset, UnionVectorOffset, Void, WriteAsOptionalUnionVector,
 };
 
 impl<P: Primitive> WriteAsPrimitive<P> for Void {
     #[inline]
-    fn write<const N: usize>(&self, _cursor: Cursor<'_, N>, _buffer_position: u32) {
+    fn write<const N: usize>(&self, _cursor: Cursor<'_, N>, _buffer_position: u64) {
         match *self {}
     }
 }
@@ -14,21 +14,21 @@ impl<P: Primitive> WriteAsPrimitive<P> for Void {
 impl<P: Primitive> WriteAsOptional<P> for () {
     type Prepared = Void;
     #[inline]
-    fn prepare(&self, _builder: &mut Builder) -> Option<Void> {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Option<Void> {
         None
     }
 }
 
 impl<T: ?Sized> WriteAsOptionalUnion<T> for () {
     #[inline]
-    fn prepare(&self, _builder: &mut Builder) -> Option<UnionOffset<T>> {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Option<UnionOffset<T>> {
         None
     }
 }
 
 impl<T: ?Sized> WriteAsOptionalUnionVector<T> for () {
     #[inline]
-    fn prepare(&self, _builder: &mut Builder) -> Option<UnionVectorOffset<T>> {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Option<UnionVectorOffset<T>> {
         None
     }
 }
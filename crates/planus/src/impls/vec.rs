@@ -1,11 +1,11 @@
-use crate::{builder::Builder, traits::*, Offset, UnionVectorOffset};
+use crate::{builder::BufferBuilder, traits::*, Offset, UnionVectorOffset};
 
 impl<T, P> WriteAsOffset<[P]> for alloc::vec::Vec<T>
 where
     [T]: WriteAsOffset<[P]>,
 {
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Offset<[P]> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset<[P]> {
         WriteAsOffset::prepare(self.as_slice(), builder)
     }
 }
@@ -17,7 +17,7 @@ where
     type Prepared = Offset<[P]>;
 
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Offset<[P]> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset<[P]> {
         WriteAsOffset::prepare(self.as_slice(), builder)
     }
 }
@@ -29,7 +29,7 @@ where
     type Prepared = Offset<[P]>;
 
     #[inline]
-    fn prepare(&self, builder: &mut Builder, _default: &()) -> Option<Offset<[P]>> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>, _default: &()) -> Option<Offset<[P]>> {
         if self.is_empty() {
             None
         } else {
@@ -45,7 +45,7 @@ where
     type Prepared = Offset<[P]>;
 
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Option<Offset<[P]>> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<Offset<[P]>> {
         Some(WriteAsOffset::prepare(self.as_slice(), builder))
     }
 }
@@ -54,7 +54,7 @@ impl<T, P> WriteAsUnionVector<P> for alloc::vec::Vec<T>
 where
     T: WriteAsUnion<P>,
 {
-    fn prepare(&self, builder: &mut Builder) -> UnionVectorOffset<P> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> UnionVectorOffset<P> {
         WriteAsUnionVector::prepare(self.as_slice(), builder)
     }
 }
@@ -63,7 +63,7 @@ impl<T, P> WriteAsDefaultUnionVector<P> for alloc::vec::Vec<T>
 where
     T: WriteAsUnion<P>,
 {
-    fn prepare(&self, builder: &mut Builder) -> Option<UnionVectorOffset<P>> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<UnionVectorOffset<P>> {
         if self.is_empty() {
             None
         } else {
@@ -77,7 +77,7 @@ where
     T: WriteAsUnion<P>,
 {
     #[inline]
-    fn prepare(&self, builder: &mut Builder) -> Option<UnionVectorOffset<P>> {
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<UnionVectorOffset<P>> {
         Some(WriteAsUnionVector::prepare(self.as_slice(), builder))
     }
 }
@@ -0,0 +1,174 @@
+// Implementations for writing vectors read from another buffer, which copy
+// every element into the builder.
+//
+// Elements that cannot be read from the source buffer make finishing the
+// buffer fail, since writing has no way of returning errors. Use
+// `BufferBuilder::try_copy` to get the error, or a verified buffer as the source to
+// avoid this.
+
+use crate::{
+    builder::BufferBuilder, traits::*, Offset, Offset64, UnionVector, UnionVectorOffset, Vector,
+    Vector64Offset,
+};
+
+impl<'buf, T, P: Primitive> WriteAsOffset<[P]> for Vector<'buf, T>
+where
+    T: VectorRead<'buf> + VectorWrite<P>,
+{
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset<[P]> {
+        builder.create_vector_from_iter(self.iter())
+    }
+}
+
+impl<'buf, T, P: Primitive> WriteAs<Offset<[P]>> for Vector<'buf, T>
+where
+    T: VectorRead<'buf> + VectorWrite<P>,
+{
+    type Prepared = Offset<[P]>;
+
+    #[inline]
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset<[P]> {
+        WriteAsOffset::prepare(self, builder)
+    }
+}
+
+impl<'buf, T, P: Primitive> WriteAsDefault<Offset<[P]>, ()> for Vector<'buf, T>
+where
+    T: VectorRead<'buf> + VectorWrite<P>,
+{
+    type Prepared = Offset<[P]>;
+
+    #[inline]
+    fn prepare(&self, builder: &mut BufferBuilder<'_>, _default: &()) -> Option<Offset<[P]>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(WriteAsOffset::prepare(self, builder))
+        }
+    }
+}
+
+impl<'buf, T, P: Primitive> WriteAsOptional<Offset<[P]>> for Vector<'buf, T>
+where
+    T: VectorRead<'buf> + VectorWrite<P>,
+{
+    type Prepared = Offset<[P]>;
+
+    #[inline]
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<Offset<[P]>> {
+        Some(WriteAsOffset::prepare(self, builder))
+    }
+}
+
+impl<'buf, T, P: Primitive> WriteAs<Offset64<[P]>> for Vector<'buf, T>
+where
+    T: VectorRead<'buf> + VectorWrite<P>,
+{
+    type Prepared = Offset64<[P]>;
+
+    #[inline]
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset64<[P]> {
+        builder.prepare_offset64(|builder| WriteAsOffset::prepare(self, builder))
+    }
+}
+
+impl<'buf, T, P: Primitive> WriteAsDefault<Offset64<[P]>, ()> for Vector<'buf, T>
+where
+    T: VectorRead<'buf> + VectorWrite<P>,
+{
+    type Prepared = Offset64<[P]>;
+
+    #[inline]
+    fn prepare(&self, builder: &mut BufferBuilder<'_>, _default: &()) -> Option<Offset64<[P]>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(builder.prepare_offset64(|builder| WriteAsOffset::prepare(self, builder)))
+        }
+    }
+}
+
+impl<'buf, T, P: Primitive> WriteAsOptional<Offset64<[P]>> for Vector<'buf, T>
+where
+    T: VectorRead<'buf> + VectorWrite<P>,
+{
+    type Prepared = Offset64<[P]>;
+
+    #[inline]
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<Offset64<[P]>> {
+        Some(builder.prepare_offset64(|builder| WriteAsOffset::prepare(self, builder)))
+    }
+}
+
+impl<'buf, T, P: Primitive> WriteAs<Vector64Offset<P>> for Vector<'buf, T>
+where
+    T: VectorRead<'buf> + VectorWrite<P>,
+{
+    type Prepared = Vector64Offset<P>;
+
+    #[inline]
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Vector64Offset<P> {
+        builder.create_vector64_from_iter(self.iter())
+    }
+}
+
+impl<'buf, T, P: Primitive> WriteAsDefault<Vector64Offset<P>, ()> for Vector<'buf, T>
+where
+    T: VectorRead<'buf> + VectorWrite<P>,
+{
+    type Prepared = Vector64Offset<P>;
+
+    #[inline]
+    fn prepare(&self, builder: &mut BufferBuilder<'_>, _default: &()) -> Option<Vector64Offset<P>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(builder.create_vector64_from_iter(self.iter()))
+        }
+    }
+}
+
+impl<'buf, T, P: Primitive> WriteAsOptional<Vector64Offset<P>> for Vector<'buf, T>
+where
+    T: VectorRead<'buf> + VectorWrite<P>,
+{
+    type Prepared = Vector64Offset<P>;
+
+    #[inline]
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<Vector64Offset<P>> {
+        Some(builder.create_vector64_from_iter(self.iter()))
+    }
+}
+
+impl<'buf, T, P> WriteAsUnionVector<P> for UnionVector<'buf, T>
+where
+    T: VectorReadUnion<'buf> + WriteAsUnion<P>,
+{
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> UnionVectorOffset<P> {
+        builder.create_union_vector_from_iter(self.iter())
+    }
+}
+
+impl<'buf, T, P> WriteAsDefaultUnionVector<P> for UnionVector<'buf, T>
+where
+    T: VectorReadUnion<'buf> + WriteAsUnion<P>,
+{
+    #[inline]
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<UnionVectorOffset<P>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(WriteAsUnionVector::prepare(self, builder))
+        }
+    }
+}
+
+impl<'buf, T, P> WriteAsOptionalUnionVector<P> for UnionVector<'buf, T>
+where
+    T: VectorReadUnion<'buf> + WriteAsUnion<P>,
+{
+    #[inline]
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<UnionVectorOffset<P>> {
+        Some(WriteAsUnionVector::prepare(self, builder))
+    }
+}
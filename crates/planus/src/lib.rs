@@ -1,14 +1,25 @@
 #![deny(missing_docs)]
 #![doc = include_str!("../README.md")]
 #![cfg_attr(not(feature = "std"), no_std)]
+// The error path makes `Error` larger, which is the cost of enabling it
+#![cfg_attr(feature = "error-path", allow(clippy::result_large_err))]
 mod backvec;
 mod builder;
+#[cfg(feature = "alloc")]
+mod conversion_budget;
 mod impls;
+#[cfg(feature = "alloc")]
+mod owned_root;
 mod slice_helpers;
 mod traits;
+mod unknown_union;
+mod verifier;
 
 /// Error types for serialization/deserialization
 pub mod errors;
+pub mod flexbuffers;
+#[cfg(feature = "std")]
+pub mod stream;
 /// Types for interacting with vectors of unions in serialized data
 pub mod union_vectors;
 /// Types for interacting with vectors in serialized data
@@ -21,20 +32,30 @@ pub mod vectors;
 ))]
 mod builder_cache;
 
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 pub extern crate alloc;
 #[doc(hidden)]
+pub mod table_mutator;
+#[doc(hidden)]
 pub mod table_reader;
 #[doc(hidden)]
 pub mod table_writer;
 
 pub use crate::{
-    builder::Builder,
+    builder::{BufferBuilder, Builder, Checkpoint},
     errors::Error,
     slice_helpers::{ArrayWithStartOffset, SliceWithStartOffset},
     traits::*,
     union_vectors::UnionVector,
+    unknown_union::UnknownUnionRef,
     vectors::Vector,
+    verifier::{Verifier, VerifierOptions},
+};
+#[cfg(feature = "alloc")]
+pub use crate::{
+    conversion_budget::ConversionBudget,
+    owned_root::{OwnedBuffer, OwnedRoot},
 };
 
 #[doc(hidden)]
@@ -47,6 +68,15 @@ pub const fn check_version_compatibility(s: &str) {
     }
 }
 
+/// Returns the file identifier of a buffer, or `None` if the buffer is too
+/// short to contain one.
+///
+/// The buffer is not otherwise checked, so a buffer that was finished without
+/// a file identifier will return whatever bytes follow the root offset.
+pub fn file_identifier(buffer: &[u8]) -> Option<[u8; 4]> {
+    buffer.get(4..8)?.try_into().ok()
+}
+
 /// A type alias for [`Result`] with a Planus error
 ///
 /// It is recommended to handle reading of serialized data in functions
@@ -75,13 +105,13 @@ pub struct DefaultValue;
 
 impl<P: Primitive, D: ?Sized> WriteAsDefault<P, D> for DefaultValue {
     type Prepared = Void;
-    fn prepare(&self, _builder: &mut Builder, _default: &D) -> Option<Self::Prepared> {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>, _default: &D) -> Option<Self::Prepared> {
         None
     }
 }
 
 impl<P> WriteAsDefaultUnionVector<P> for DefaultValue {
-    fn prepare(&self, _builder: &mut Builder) -> Option<UnionVectorOffset<P>> {
+    fn prepare(&self, _builder: &mut BufferBuilder<'_>) -> Option<UnionVectorOffset<P>> {
         None
     }
 }
@@ -94,6 +124,9 @@ impl From<Void> for crate::Error {
 
 /// An offset to a serialized value of type T inside a buffer currently being built.
 pub struct Offset<T: ?Sized> {
+    // The length of the buffer after writing the value, truncated to 32 bits.
+    // The builder keeps all objects reachable through 32-bit offsets within
+    // 4 GiB of each other, so the distances between them are still exact.
     offset: u32,
     phantom: core::marker::PhantomData<T>,
 }
@@ -115,6 +148,40 @@ impl<T: ?Sized> Offset<T> {
     }
 }
 
+/// A 64-bit offset to a serialized string or vector of type T inside a buffer
+/// currently being built.
+///
+/// These are used for fields with the `offset64` attribute, which allows the
+/// object to be placed more than 4 GiB away from the table referencing it.
+pub struct Offset64<T: ?Sized> {
+    offset: u64,
+    phantom: core::marker::PhantomData<T>,
+}
+impl<T: ?Sized> Copy for Offset64<T> {}
+impl<T: ?Sized> Clone for Offset64<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+/// A 64-bit offset to a serialized vector with elements of type T and a 64-bit
+/// length inside a buffer currently being built.
+///
+/// These are used for fields with the `vector64` attribute and are created
+/// using [`BufferBuilder::create_vector64`].
+pub struct Vector64Offset<T: ?Sized> {
+    offset: u64,
+    phantom: core::marker::PhantomData<T>,
+}
+impl<T: ?Sized> Copy for Vector64Offset<T> {}
+impl<T: ?Sized> Clone for Vector64Offset<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
 /// An offset to a serialized union value of type T inside a buffer currently being built.
 pub struct UnionOffset<T: ?Sized> {
     tag: u8,
@@ -0,0 +1,112 @@
+use core::marker::PhantomData;
+
+use crate::{HasRef, ReadAsRoot, Result, SliceWithStartOffset, TableRead, VerifierOptions};
+
+/// A verified buffer that owns its data and hands out references to its root
+/// table on demand.
+///
+/// `T` is the owned type of the root table and `B` is the type holding the
+/// data, such as a `Vec<u8>` or an `Arc<[u8]>`, see [`OwnedBuffer`]. Since the buffer is owned,
+/// an `OwnedRoot` can be stored in structs and sent between threads without
+/// borrowing from anything.
+///
+/// The buffer is verified once when the `OwnedRoot` is created, so getting
+/// the root reference afterwards is cheap and all accessors reachable from it
+/// will succeed.
+///
+/// # Examples
+/// ```
+/// use std::sync::Arc;
+/// use planus::{Builder, OwnedRoot};
+/// use planus_example::monster_generated::my_game::sample::Weapon;
+///
+/// let mut builder = Builder::new();
+/// let weapon = Weapon::create(&mut builder, "Axe", 24);
+/// let data: Arc<[u8]> = builder.finish(weapon, None).into();
+///
+/// let weapon = OwnedRoot::<Weapon, _>::new(data).unwrap();
+/// assert_eq!(weapon.get().name().unwrap(), Some("Axe"));
+/// ```
+pub struct OwnedRoot<T, B = alloc::vec::Vec<u8>> {
+    buffer: B,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: HasRef, B: OwnedBuffer> OwnedRoot<T, B> {
+    /// Verifies the buffer as a `T` using the default [`VerifierOptions`] and
+    /// takes ownership of it.
+    pub fn new(buffer: B) -> Result<Self> {
+        Self::new_with_options(buffer, VerifierOptions::default())
+    }
+
+    /// The same as [`OwnedRoot::new`], but with custom limits.
+    pub fn new_with_options(buffer: B, options: VerifierOptions) -> Result<Self> {
+        T::Ref::read_as_root_verified_with_options(&buffer, options)?;
+        Ok(Self {
+            buffer,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Returns a reference to the root table.
+    pub fn get(&self) -> T::Ref<'_> {
+        let buffer = SliceWithStartOffset {
+            buffer: &self.buffer,
+            offset_from_start: 0,
+        };
+        // SAFETY: The buffer was verified as a `T` when the `OwnedRoot` was
+        // created, which includes reading the root table. `OwnedBuffer` types
+        // give the same bytes every time they are dereferenced and can not be
+        // modified through a shared reference, so the read still succeeds.
+        unsafe { T::Ref::from_buffer_unchecked(buffer, 0) }
+    }
+
+    /// Returns the serialized data.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer
+    }
+}
+
+impl<T, B> OwnedRoot<T, B> {
+    /// Returns the buffer holding the serialized data.
+    pub fn into_inner(self) -> B {
+        self.buffer
+    }
+}
+
+impl<T, B: Clone> Clone for OwnedRoot<T, B> {
+    fn clone(&self) -> Self {
+        Self {
+            buffer: self.buffer.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: HasRef, B: OwnedBuffer> core::fmt::Debug for OwnedRoot<T, B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("OwnedRoot").field(&self.get()).finish()
+    }
+}
+
+/// Buffer types that can hold the data of an [`OwnedRoot`].
+///
+/// The trait is sealed, as [`OwnedRoot::get`] relies on the buffer giving the
+/// same bytes every time it is dereferenced.
+pub trait OwnedBuffer: core::ops::Deref<Target = [u8]> + private::Sealed {}
+
+impl OwnedBuffer for alloc::vec::Vec<u8> {}
+impl OwnedBuffer for alloc::boxed::Box<[u8]> {}
+impl OwnedBuffer for alloc::rc::Rc<[u8]> {}
+#[cfg(target_has_atomic = "ptr")]
+impl OwnedBuffer for alloc::sync::Arc<[u8]> {}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for alloc::vec::Vec<u8> {}
+    impl Sealed for alloc::boxed::Box<[u8]> {}
+    impl Sealed for alloc::rc::Rc<[u8]> {}
+    #[cfg(target_has_atomic = "ptr")]
+    impl Sealed for alloc::sync::Arc<[u8]> {}
+}
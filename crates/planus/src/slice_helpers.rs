@@ -32,6 +32,18 @@ impl<'buf> SliceWithStartOffset<'buf> {
         })
     }
 
+    /// The same as [`SliceWithStartOffset::advance`], but without bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// Calling this function is only safe if you guarantee that `amount <= self.len()`
+    pub unsafe fn unchecked_advance(&self, amount: usize) -> Self {
+        Self {
+            buffer: self.buffer.get_unchecked(amount..),
+            offset_from_start: self.offset_from_start + amount,
+        }
+    }
+
     /// The same as [`SliceWithStartOffset::advance`], but converted to an array reference.
     pub fn advance_as_array<const N: usize>(
         &self,
@@ -0,0 +1,487 @@
+//! Reading and writing streams of length-prefixed messages, for instance
+//! for logging messages to a file or sending them over a socket.
+//!
+//! Each message is written as a frame consisting of its length as a
+//! little-endian `u32`, followed by the message itself. This is the same
+//! format as the buffers created by [`BufferBuilder::finish_size_prefixed`], so a
+//! stream is a sequence of size-prefixed buffers.
+//!
+//! Messages are written using a [`StreamWriter`] and read back using a
+//! [`StreamReader`], which reuses a single buffer for all messages.
+//!
+//! # Examples
+//! ```
+//! use planus::{
+//!     stream::{StreamReader, StreamWriter},
+//!     Builder, ReadAsRoot,
+//! };
+//! use planus_example::monster_generated::my_game::sample::{Weapon, WeaponRef};
+//!
+//! let mut writer = StreamWriter::new(Vec::new());
+//! let mut builder = Builder::new();
+//! for (name, damage) in [("Sword", 3), ("Axe", 5)] {
+//!     let weapon = Weapon::create(&mut builder, name, damage);
+//!     writer.write_root(&mut builder, weapon, None).unwrap();
+//! }
+//! let data = writer.into_inner();
+//!
+//! let mut reader = StreamReader::new(data.as_slice());
+//! let mut names = Vec::new();
+//! while let Some(message) = reader.read_message().unwrap() {
+//!     let weapon = WeaponRef::read_as_root(message).unwrap();
+//!     names.push(weapon.name().unwrap().unwrap().to_string());
+//! }
+//! assert_eq!(names, ["Sword", "Axe"]);
+//! ```
+//!
+//! [`BufferBuilder::finish_size_prefixed`]: crate::BufferBuilder::finish_size_prefixed
+
+use std::{
+    io::{ErrorKind, Read, Write},
+    vec::Vec,
+};
+
+use crate::{errors::StreamError, BufferBuilder, WriteAsOffset};
+
+const LENGTH_PREFIX_SIZE: usize = 4;
+
+/// Writes length-prefixed messages to a [`Write`] implementation.
+///
+/// Each message is written using two calls to [`Write::write_all`], so
+/// unbuffered writers such as files and sockets should be wrapped in a
+/// [`BufWriter`](std::io::BufWriter).
+#[derive(Debug)]
+pub struct StreamWriter<W> {
+    writer: W,
+    max_message_size: usize,
+}
+
+impl<W: Write> StreamWriter<W> {
+    /// Creates a new writer.
+    ///
+    /// By default messages can be as large as the length prefix allows.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            max_message_size: u32::MAX as usize,
+        }
+    }
+
+    /// Sets the maximum size of a message in bytes, not including the
+    /// length prefix. Larger messages are rejected with
+    /// [`StreamError::MessageTooLarge`] without writing anything.
+    ///
+    /// The size is capped at [`u32::MAX`], the largest size the length
+    /// prefix can hold.
+    pub fn set_max_message_size(&mut self, max_message_size: usize) {
+        self.max_message_size = max_message_size.min(u32::MAX as usize);
+    }
+
+    /// Returns the maximum size of a message in bytes.
+    pub fn max_message_size(&self) -> usize {
+        self.max_message_size
+    }
+
+    /// Writes a finished message, such as the data returned by
+    /// [`BufferBuilder::finish`], as a frame.
+    pub fn write_message(&mut self, message: &[u8]) -> Result<(), StreamError> {
+        if message.len() > self.max_message_size {
+            return Err(StreamError::MessageTooLarge {
+                size: message.len(),
+                max_size: self.max_message_size,
+            });
+        }
+        self.writer
+            .write_all(&(message.len() as u32).to_le_bytes())?;
+        self.writer.write_all(message)?;
+        Ok(())
+    }
+
+    /// Finishes the data in the builder with the given root and writes it
+    /// as a frame.
+    ///
+    /// Data that can not be finished, such as data exceeding the maximum size
+    /// of the builder, is reported as [`StreamError::Builder`] without
+    /// writing anything. Afterwards the builder is cleared, so it can be used
+    /// for the next message.
+    pub fn write_root<T>(
+        &mut self,
+        builder: &mut BufferBuilder<'_>,
+        root: impl WriteAsOffset<T>,
+        file_identifier: Option<[u8; 4]>,
+    ) -> Result<(), StreamError> {
+        let result = match builder.try_finish(root, file_identifier) {
+            Ok(message) => self.write_message(message),
+            Err(error) => Err(error.into()),
+        };
+        builder.clear();
+        result
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> Result<(), StreamError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads length-prefixed messages from a [`Read`] implementation.
+///
+/// The messages are read into a buffer owned by the reader, which is reused
+/// for every message. The buffer only grows as data arrives, so a corrupted
+/// length prefix does not cause a large allocation by itself.
+///
+/// Unbuffered readers such as files and sockets should be wrapped in a
+/// [`BufReader`](std::io::BufReader), since the length prefix of every
+/// frame is read separately.
+///
+/// Messages rejected with [`StreamError::MessageTooLarge`] are left in the
+/// stream, and can be skipped using [`skip_message`](Self::skip_message).
+/// Messages rejected with [`StreamError::InvalidFileIdentifier`] have
+/// already been read, so the next message can be read right away. After
+/// other errors, the position in the stream is unspecified, and further
+/// messages should not be read.
+#[derive(Debug)]
+pub struct StreamReader<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    max_message_size: usize,
+    file_identifier: Option<[u8; 4]>,
+    // The size of a message rejected for being too large, which is still
+    // in the stream
+    skippable_size: Option<usize>,
+}
+
+impl<R: Read> StreamReader<R> {
+    /// Creates a new reader.
+    ///
+    /// By default messages can be as large as the length prefix allows, and
+    /// the file identifier is not checked.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: Vec::new(),
+            max_message_size: u32::MAX as usize,
+            file_identifier: None,
+            skippable_size: None,
+        }
+    }
+
+    /// Sets the maximum size of a message in bytes, not including the
+    /// length prefix. Frames with a larger message give
+    /// [`StreamError::MessageTooLarge`] before the message is read.
+    pub fn set_max_message_size(&mut self, max_message_size: usize) {
+        self.max_message_size = max_message_size;
+    }
+
+    /// Returns the maximum size of a message in bytes.
+    pub fn max_message_size(&self) -> usize {
+        self.max_message_size
+    }
+
+    /// Sets the file identifier every message must have. Messages with a
+    /// different identifier give [`StreamError::InvalidFileIdentifier`].
+    pub fn set_file_identifier(&mut self, file_identifier: Option<[u8; 4]>) {
+        self.file_identifier = file_identifier;
+    }
+
+    /// Returns the file identifier every message must have, if any.
+    pub fn file_identifier(&self) -> Option<[u8; 4]> {
+        self.file_identifier
+    }
+
+    /// Reads the next message, or returns `None` if the stream ended after
+    /// the previous frame.
+    ///
+    /// If the stream ends in the middle of a frame, for instance because
+    /// the writer was interrupted, [`StreamError::TruncatedFrame`] is
+    /// returned.
+    pub fn read_message(&mut self) -> Result<Option<&[u8]>, StreamError> {
+        self.skippable_size = None;
+        let mut length_prefix = [0; LENGTH_PREFIX_SIZE];
+        let received = read_until_end(&mut self.reader, &mut length_prefix)?;
+        if received == 0 {
+            return Ok(None);
+        } else if received < LENGTH_PREFIX_SIZE {
+            return Err(StreamError::TruncatedFrame {
+                expected: LENGTH_PREFIX_SIZE as u64,
+                received: received as u64,
+            });
+        }
+
+        let size = u32::from_le_bytes(length_prefix) as usize;
+        if size > self.max_message_size {
+            self.skippable_size = Some(size);
+            return Err(StreamError::MessageTooLarge {
+                size,
+                max_size: self.max_message_size,
+            });
+        }
+
+        self.buffer.clear();
+        let received = (&mut self.reader)
+            .take(size as u64)
+            .read_to_end(&mut self.buffer)?;
+        check_message_received(size, received as u64)?;
+
+        if let Some(expected) = self.file_identifier {
+            let found = crate::file_identifier(&self.buffer);
+            if found != Some(expected) {
+                return Err(StreamError::InvalidFileIdentifier { expected, found });
+            }
+        }
+
+        Ok(Some(&self.buffer))
+    }
+
+    /// Skips the message that the previous call to
+    /// [`read_message`](Self::read_message) rejected with
+    /// [`StreamError::MessageTooLarge`], so the next message can be read.
+    ///
+    /// The message is discarded as it is read, without storing it. Does
+    /// nothing if the previous call did not return that error.
+    pub fn skip_message(&mut self) -> Result<(), StreamError> {
+        let Some(size) = self.skippable_size.take() else {
+            return Ok(());
+        };
+        let received = std::io::copy(
+            &mut (&mut self.reader).take(size as u64),
+            &mut std::io::sink(),
+        )?;
+        check_message_received(size, received)
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+/// Returns an error if fewer than `size` bytes of a message were received.
+fn check_message_received(size: usize, received: u64) -> Result<(), StreamError> {
+    let prefix_size = LENGTH_PREFIX_SIZE as u64;
+    if received < size as u64 {
+        return Err(StreamError::TruncatedFrame {
+            expected: prefix_size + size as u64,
+            received: prefix_size + received,
+        });
+    }
+    Ok(())
+}
+
+/// Fills `buffer` from the reader, stopping early only at the end of the
+/// stream. Returns the number of bytes read.
+fn read_until_end(reader: &mut impl Read, buffer: &mut [u8]) -> std::io::Result<usize> {
+    let mut received = 0;
+    while received < buffer.len() {
+        match reader.read(&mut buffer[received..]) {
+            Ok(0) => break,
+            Ok(n) => received += n,
+            Err(error) if error.kind() == ErrorKind::Interrupted => (),
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(received)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{errors::BuilderError, Builder};
+
+    fn write_frames(messages: &[&[u8]]) -> Vec<u8> {
+        let mut writer = StreamWriter::new(Vec::new());
+        for message in messages {
+            writer.write_message(message).unwrap();
+        }
+        writer.into_inner()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let messages: [&[u8]; 4] = [b"first", b"", b"third message", &[0; 1000]];
+        let data = write_frames(&messages);
+        assert_eq!(&data[..9], b"\x05\x00\x00\x00first");
+
+        let mut reader = StreamReader::new(data.as_slice());
+        for message in messages {
+            assert_eq!(reader.read_message().unwrap(), Some(message));
+        }
+        assert_eq!(reader.read_message().unwrap(), None);
+        assert_eq!(reader.read_message().unwrap(), None);
+    }
+
+    #[test]
+    fn test_truncated_frames() {
+        let data = write_frames(&[b"complete", b"incomplete"]);
+
+        let mut reader = StreamReader::new(&data[..data.len() - 3]);
+        assert_eq!(reader.read_message().unwrap(), Some(&b"complete"[..]));
+        assert!(matches!(
+            reader.read_message(),
+            Err(StreamError::TruncatedFrame {
+                expected: 14,
+                received: 11
+            })
+        ));
+
+        let mut reader = StreamReader::new(&data[..14]);
+        reader.read_message().unwrap();
+        assert!(matches!(
+            reader.read_message(),
+            Err(StreamError::TruncatedFrame {
+                expected: 4,
+                received: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_max_message_size() {
+        let mut writer = StreamWriter::new(Vec::new());
+        writer.set_max_message_size(4);
+        writer.write_message(b"four").unwrap();
+        assert!(matches!(
+            writer.write_message(b"five!"),
+            Err(StreamError::MessageTooLarge {
+                size: 5,
+                max_size: 4
+            })
+        ));
+        assert_eq!(writer.get_ref().len(), 8);
+
+        let data = write_frames(&[b"four", b"five!"]);
+        let mut reader = StreamReader::new(data.as_slice());
+        reader.set_max_message_size(4);
+        assert_eq!(reader.read_message().unwrap(), Some(&b"four"[..]));
+        assert!(matches!(
+            reader.read_message(),
+            Err(StreamError::MessageTooLarge {
+                size: 5,
+                max_size: 4
+            })
+        ));
+
+        // The rejected message can be skipped to read the ones after it
+        let data = write_frames(&[b"five!", b"four", b"too long"]);
+        let mut reader = StreamReader::new(data.as_slice());
+        reader.set_max_message_size(4);
+        // Skipping does nothing until a message has been rejected
+        reader.skip_message().unwrap();
+        assert!(reader.read_message().is_err());
+        reader.skip_message().unwrap();
+        assert_eq!(reader.read_message().unwrap(), Some(&b"four"[..]));
+        assert!(reader.read_message().is_err());
+
+        // Skipping a message cut short by the end of the stream fails
+        let mut reader = StreamReader::new(&data[..data.len() - 1]);
+        reader.set_max_message_size(4);
+        assert!(reader.read_message().is_err());
+        reader.skip_message().unwrap();
+        reader.read_message().unwrap();
+        assert!(reader.read_message().is_err());
+        assert!(matches!(
+            reader.skip_message(),
+            Err(StreamError::TruncatedFrame {
+                expected: 12,
+                received: 11
+            })
+        ));
+
+        // A corrupted length prefix only reads the data that is present
+        let mut reader = StreamReader::new(&[0xff, 0xff, 0xff, 0x7f, 1, 2, 3][..]);
+        assert!(matches!(
+            reader.read_message(),
+            Err(StreamError::TruncatedFrame {
+                expected: 0x8000_0003,
+                received: 7
+            })
+        ));
+    }
+
+    #[test]
+    fn test_file_identifier() {
+        let mut writer = StreamWriter::new(Vec::new());
+        let mut builder = Builder::new();
+        builder.create_vector([1u32, 2, 3]);
+        let root = builder.current_offset::<()>();
+        writer
+            .write_root(&mut builder, root, Some(*b"TEST"))
+            .unwrap();
+        assert!(builder.is_empty());
+        builder.create_vector([4u32, 5]);
+        let root = builder.current_offset::<()>();
+        writer
+            .write_root(&mut builder, root, Some(*b"XXXX"))
+            .unwrap();
+        writer.write_message(b"tiny").unwrap();
+        let data = writer.into_inner();
+
+        let mut reader = StreamReader::new(data.as_slice());
+        reader.set_file_identifier(Some(*b"TEST"));
+        let message = reader.read_message().unwrap().unwrap();
+        assert_eq!(crate::file_identifier(message), Some(*b"TEST"));
+        assert_eq!(
+            &message[8..],
+            b"\x03\x00\x00\x00\x01\x00\x00\x00\x02\x00\x00\x00\x03\x00\x00\x00"
+        );
+        assert!(matches!(
+            reader.read_message(),
+            Err(StreamError::InvalidFileIdentifier {
+                expected: [b'T', b'E', b'S', b'T'],
+                found: Some([b'X', b'X', b'X', b'X'])
+            })
+        ));
+        assert!(matches!(
+            reader.read_message(),
+            Err(StreamError::InvalidFileIdentifier { found: None, .. })
+        ));
+    }
+
+    #[test]
+    fn test_write_root_error() {
+        let mut writer = StreamWriter::new(Vec::new());
+        let mut builder = Builder::with_max_size(16);
+        builder.create_vector([1u32, 2, 3, 4]);
+        let root = builder.current_offset::<()>();
+        assert!(matches!(
+            writer.write_root(&mut builder, root, None),
+            Err(StreamError::Builder(BuilderError::MaxSizeExceeded {
+                max_size: 16,
+                ..
+            }))
+        ));
+        assert!(writer.get_ref().is_empty());
+        assert!(builder.is_empty());
+
+        builder.create_vector([1u32]);
+        let root = builder.current_offset::<()>();
+        writer.write_root(&mut builder, root, None).unwrap();
+        assert_eq!(writer.get_ref().len(), 16);
+    }
+}
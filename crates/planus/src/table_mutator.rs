@@ -0,0 +1,78 @@
+use core::mem::MaybeUninit;
+
+use crate::{
+    errors::ErrorKind, slice_helpers::SliceWithStartOffset, table_reader::Table, Cursor,
+    WriteAsPrimitive,
+};
+
+/// A table in a finished buffer whose scalar and struct fields can be changed
+/// in place.
+#[derive(Debug)]
+pub struct TableMut<'buf> {
+    buffer: &'buf mut [u8],
+    // Offset of the offset pointing to the table, as given to
+    // `Table::from_buffer`
+    field_offset: usize,
+}
+
+impl<'buf> TableMut<'buf> {
+    pub fn from_buffer(buffer: &'buf mut [u8], field_offset: usize) -> Result<Self, ErrorKind> {
+        Table::from_buffer(
+            SliceWithStartOffset {
+                buffer,
+                offset_from_start: 0,
+            },
+            field_offset,
+        )?;
+        Ok(Self {
+            buffer,
+            field_offset,
+        })
+    }
+
+    /// Returns a read-only view of the table.
+    ///
+    /// Writing a field can overwrite the vtable of a malformed buffer, so the
+    /// table is looked up again instead of being stored.
+    pub fn as_table(&self) -> Result<Table<'_>, ErrorKind> {
+        Table::from_buffer(
+            SliceWithStartOffset {
+                buffer: self.buffer,
+                offset_from_start: 0,
+            },
+            self.field_offset,
+        )
+    }
+
+    /// Overwrites the value of a field of `N` bytes, which must already be
+    /// present in the vtable.
+    pub fn set<P, const N: usize>(
+        &mut self,
+        vtable_offset: usize,
+        value: &impl WriteAsPrimitive<P>,
+        type_: &'static str,
+        method: &'static str,
+    ) -> crate::Result<()> {
+        let table = self.as_table().map_err(|error_kind| {
+            error_kind.with_error_location(type_, method, self.field_offset)
+        })?;
+        let object_offset = table.offset();
+        let make_error =
+            |error_kind: ErrorKind| error_kind.with_error_location(type_, method, object_offset);
+        let start = object_offset
+            + table
+                .field_offset(vtable_offset)
+                .ok_or_else(|| make_error(ErrorKind::FieldNotPresent))?;
+        let buffer_position = self.buffer.len().saturating_sub(start) as u64;
+        let bytes: &mut [u8; N] = self
+            .buffer
+            .get_mut(start..)
+            .and_then(|bytes| bytes.first_chunk_mut())
+            .ok_or_else(|| make_error(ErrorKind::InvalidOffset))?;
+        // SAFETY: `MaybeUninit<u8>` has the same layout as `u8`, and the
+        // cursor only writes initialized values
+        let bytes = unsafe { &mut *(bytes as *mut [u8; N] as *mut [MaybeUninit<u8>; N]) };
+        value.write(Cursor::new(bytes), buffer_position);
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    errors::ErrorKind, slice_helpers::SliceWithStartOffset, TableRead, TableReadUnion,
-    TableReadUnionVector,
+    errors::ErrorKind, impls::array64_from_buffer, slice_helpers::SliceWithStartOffset, TableRead,
+    TableReadArray, TableReadUnion, TableReadUnionVector,
 };
 
 #[derive(Copy, Clone, Debug)]
@@ -43,27 +43,63 @@ impl<'buf> Table<'buf> {
         Ok(Self { object, vtable })
     }
 
+    /// Like [`from_buffer`](Self::from_buffer), but without checking that
+    /// the table and its vtable are within the buffer.
+    ///
+    /// # Safety
+    ///
+    /// [`from_buffer`](Self::from_buffer) must succeed for the same
+    /// arguments, which is the case for tables of verified buffers.
+    pub unsafe fn from_buffer_unchecked(
+        buffer: SliceWithStartOffset<'buf>,
+        field_offset: usize,
+    ) -> Self {
+        unsafe {
+            let field_value = u32::from_buffer_unchecked(buffer, field_offset);
+            let object_offset = field_offset + field_value as usize;
+            let object = buffer.unchecked_advance(object_offset);
+            let vtable_offset_relative = i32::from_buffer_unchecked(buffer, object_offset);
+            let vtable_offset = (object_offset as i64 - vtable_offset_relative as i64) as usize;
+            let vtable_size = u16::from_buffer_unchecked(buffer, vtable_offset) as usize;
+            let vtable = buffer
+                .as_slice()
+                .get_unchecked(vtable_offset + 4..vtable_offset + vtable_size);
+            Self { object, vtable }
+        }
+    }
+
+    /// The offset of the table object from the start of the buffer.
+    pub fn offset(&self) -> usize {
+        self.object.offset_from_start
+    }
+
+    /// The size of the vtable in bytes, including the two size fields.
+    pub fn vtable_size(&self) -> usize {
+        self.vtable.len() + 4
+    }
+
+    /// The offset of a field from the start of the table object, or `None` if
+    /// the field is not present.
+    pub fn field_offset(&self, vtable_offset: usize) -> Option<usize> {
+        let offset = self
+            .vtable
+            .get(2 * vtable_offset..2 * (vtable_offset + 1))
+            .unwrap_or(&[0, 0]);
+        let offset = u16::from_le_bytes(offset.try_into().unwrap()) as usize;
+        (offset != 0).then_some(offset)
+    }
+
     pub fn access<T: TableRead<'buf>>(
         &self,
         vtable_offset: usize,
         type_: &'static str,
         method: &'static str,
     ) -> crate::Result<Option<T>> {
-        let offset = self
-            .vtable
-            .get(2 * vtable_offset..2 * (vtable_offset + 1))
-            .unwrap_or(&[0, 0]);
-        let offset = u16::from_le_bytes(offset.try_into().unwrap()) as usize;
-        if offset != 0 {
+        if let Some(offset) = self.field_offset(vtable_offset) {
             T::from_buffer(self.object, offset)
                 .map(Some)
-                .map_err(|error_kind| crate::errors::Error {
-                    source_location: crate::errors::ErrorLocation {
-                        type_,
-                        method,
-                        byte_offset: self.object.offset_from_start,
-                    },
-                    error_kind,
+                .map_err(|error_kind| {
+                    error_kind.with_error_location(type_, method, self.object.offset_from_start)
                 })
         } else {
             Ok(None)
@@ -76,31 +112,125 @@ impl<'buf> Table<'buf> {
         type_: &'static str,
         method: &'static str,
     ) -> crate::Result<T> {
-        self.access(vtable_offset, type_, method)?
-            .ok_or(crate::errors::Error {
-                source_location: crate::errors::ErrorLocation {
+        self.access(vtable_offset, type_, method)?.ok_or_else(|| {
+            ErrorKind::MissingRequired.with_error_location(
+                type_,
+                method,
+                self.object.offset_from_start,
+            )
+        })
+    }
+
+    /// Like [`access`](Self::access), but without checking that the field
+    /// can be read.
+    ///
+    /// # Safety
+    ///
+    /// [`access`](Self::access) must succeed for the same arguments, which
+    /// is the case for tables of verified buffers.
+    #[inline]
+    pub unsafe fn access_unchecked<T: TableRead<'buf>>(&self, vtable_offset: usize) -> Option<T> {
+        self.field_offset(vtable_offset)
+            .map(|offset| unsafe { T::from_buffer_unchecked(self.object, offset) })
+    }
+
+    /// Like [`access_required`](Self::access_required), but without checking
+    /// that the field is present and can be read.
+    ///
+    /// # Safety
+    ///
+    /// [`access_required`](Self::access_required) must succeed for the same
+    /// arguments, which is the case for tables of verified buffers.
+    #[inline]
+    pub unsafe fn access_required_unchecked<T: TableRead<'buf>>(&self, vtable_offset: usize) -> T {
+        unsafe { self.access_unchecked(vtable_offset).unwrap_unchecked() }
+    }
+
+    /// Reads a string or vector stored behind a 64-bit offset, as used by
+    /// fields with the `offset64` attribute.
+    pub fn access_offset64<T: TableReadArray<'buf>>(
+        &self,
+        vtable_offset: usize,
+        type_: &'static str,
+        method: &'static str,
+    ) -> crate::Result<Option<T>> {
+        self.access_array64(vtable_offset, type_, method, false)
+    }
+
+    /// Like [`access_offset64`](Self::access_offset64), but returns an
+    /// error if the field is missing.
+    pub fn access_offset64_required<T: TableReadArray<'buf>>(
+        &self,
+        vtable_offset: usize,
+        type_: &'static str,
+        method: &'static str,
+    ) -> crate::Result<T> {
+        self.access_offset64(vtable_offset, type_, method)?
+            .ok_or_else(|| {
+                ErrorKind::MissingRequired.with_error_location(
                     type_,
                     method,
-                    byte_offset: self.object.offset_from_start,
-                },
-                error_kind: ErrorKind::MissingRequired,
+                    self.object.offset_from_start,
+                )
             })
     }
 
+    /// Reads a vector with a 64-bit length stored behind a 64-bit offset, as
+    /// used by fields with the `vector64` attribute.
+    pub fn access_vector64<T: TableReadArray<'buf>>(
+        &self,
+        vtable_offset: usize,
+        type_: &'static str,
+        method: &'static str,
+    ) -> crate::Result<Option<T>> {
+        self.access_array64(vtable_offset, type_, method, true)
+    }
+
+    /// Like [`access_vector64`](Self::access_vector64), but returns an
+    /// error if the field is missing.
+    pub fn access_vector64_required<T: TableReadArray<'buf>>(
+        &self,
+        vtable_offset: usize,
+        type_: &'static str,
+        method: &'static str,
+    ) -> crate::Result<T> {
+        self.access_vector64(vtable_offset, type_, method)?
+            .ok_or_else(|| {
+                ErrorKind::MissingRequired.with_error_location(
+                    type_,
+                    method,
+                    self.object.offset_from_start,
+                )
+            })
+    }
+
+    fn access_array64<T: TableReadArray<'buf>>(
+        &self,
+        vtable_offset: usize,
+        type_: &'static str,
+        method: &'static str,
+        vector64: bool,
+    ) -> crate::Result<Option<T>> {
+        if let Some(offset) = self.field_offset(vtable_offset) {
+            array64_from_buffer(self.object, offset, vector64)
+                .and_then(|(buffer, len)| T::from_array(buffer, len))
+                .map(Some)
+                .map_err(|error_kind| {
+                    error_kind.with_error_location(type_, method, self.object.offset_from_start)
+                })
+        } else {
+            Ok(None)
+        }
+    }
+
     pub fn access_union<T: TableReadUnion<'buf>>(
         &self,
         vtable_offset: usize,
         type_: &'static str,
         method: &'static str,
     ) -> crate::Result<Option<T>> {
-        let source_location = crate::errors::ErrorLocation {
-            type_,
-            method,
-            byte_offset: self.object.offset_from_start,
-        };
-        let make_error = |error_kind| crate::errors::Error {
-            source_location,
-            error_kind,
+        let make_error = |error_kind: ErrorKind| {
+            error_kind.with_error_location(type_, method, self.object.offset_from_start)
         };
 
         if let Some(offset) = self.vtable.get(2 * vtable_offset..2 * (vtable_offset + 2)) {
@@ -132,13 +262,12 @@ impl<'buf> Table<'buf> {
         method: &'static str,
     ) -> crate::Result<T> {
         self.access_union(vtable_offset, type_, method)?
-            .ok_or(crate::errors::Error {
-                source_location: crate::errors::ErrorLocation {
+            .ok_or_else(|| {
+                ErrorKind::MissingRequired.with_error_location(
                     type_,
                     method,
-                    byte_offset: self.object.offset_from_start,
-                },
-                error_kind: ErrorKind::MissingRequired,
+                    self.object.offset_from_start,
+                )
             })
     }
 
@@ -148,14 +277,8 @@ impl<'buf> Table<'buf> {
         type_: &'static str,
         method: &'static str,
     ) -> crate::Result<Option<T>> {
-        let source_location = crate::errors::ErrorLocation {
-            type_,
-            method,
-            byte_offset: self.object.offset_from_start,
-        };
-        let make_error = |error_kind| crate::errors::Error {
-            source_location,
-            error_kind,
+        let make_error = |error_kind: ErrorKind| {
+            error_kind.with_error_location(type_, method, self.object.offset_from_start)
         };
 
         if let Some(offset) = self.vtable.get(2 * vtable_offset..2 * (vtable_offset + 2)) {
@@ -180,13 +303,12 @@ impl<'buf> Table<'buf> {
         method: &'static str,
     ) -> crate::Result<T> {
         self.access_union_vector(vtable_offset, type_, method)?
-            .ok_or(crate::errors::Error {
-                source_location: crate::errors::ErrorLocation {
+            .ok_or_else(|| {
+                ErrorKind::MissingRequired.with_error_location(
                     type_,
                     method,
-                    byte_offset: self.object.offset_from_start,
-                },
-                error_kind: ErrorKind::MissingRequired,
+                    self.object.offset_from_start,
+                )
             })
     }
 }
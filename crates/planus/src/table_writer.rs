@@ -1,6 +1,6 @@
 use core::mem::{self, MaybeUninit};
 
-use crate::{Builder, Primitive, WriteAsPrimitive};
+use crate::{BufferBuilder, Primitive, WriteAsPrimitive};
 
 #[doc(hidden)]
 pub struct TableWriter<const VTABLE_MAX_BYTES: usize> {
@@ -47,7 +47,11 @@ impl<const VTABLE_MAX_BYTES: usize> TableWriter<VTABLE_MAX_BYTES> {
     }
 
     #[inline]
-    pub unsafe fn finish(mut self, builder: &mut Builder, f: impl FnOnce(&mut ObjectWriter<'_>)) {
+    pub unsafe fn finish(
+        mut self,
+        builder: &mut BufferBuilder<'_>,
+        f: impl FnOnce(&mut ObjectWriter<'_>),
+    ) {
         write_array(
             &mut self.vtable_buffer,
             0,
@@ -59,7 +63,10 @@ impl<const VTABLE_MAX_BYTES: usize> TableWriter<VTABLE_MAX_BYTES> {
             (self.object_size as u16).to_le_bytes(),
         );
 
-        let vtable_offset = builder.write_vtable(&self.vtable_buffer[..self.vtable_size]);
+        let vtable_offset = builder.write_vtable(
+            &self.vtable_buffer[..self.vtable_size],
+            self.object_size + self.object_alignment_mask,
+        );
 
         builder.write_with(
             self.object_size - 4,
@@ -68,18 +75,26 @@ impl<const VTABLE_MAX_BYTES: usize> TableWriter<VTABLE_MAX_BYTES> {
                 f(&mut ObjectWriter { offset, bytes });
             },
         );
+        let mut vtable_fits = true;
         builder.write_with(4, 0, |buffer_position, bytes| {
-            let len = (vtable_offset as i32 - buffer_position as i32)
-                .to_le_bytes()
-                .map(MaybeUninit::new);
-            bytes.copy_from_slice(&len);
+            // The vtable cache only reuses vtables close enough to the table
+            // for this to fit, so this is only a safeguard
+            let vtable_offset = i32::try_from(vtable_offset as i64 - buffer_position as i64)
+                .unwrap_or_else(|_| {
+                    vtable_fits = false;
+                    0
+                });
+            bytes.copy_from_slice(&vtable_offset.to_le_bytes().map(MaybeUninit::new));
         });
+        if !vtable_fits {
+            builder.mark_offset_out_of_range();
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct ObjectWriter<'a> {
-    offset: u32,
+    offset: u64,
     bytes: &'a mut [MaybeUninit<u8>],
 }
 
@@ -99,6 +114,6 @@ impl ObjectWriter<'_> {
             array_init_cursor::Cursor::<'_, u8, SIZE>::new(cur.try_into().unwrap()),
             self.offset,
         );
-        self.offset -= P::SIZE as u32;
+        self.offset -= P::SIZE as u64;
     }
 }
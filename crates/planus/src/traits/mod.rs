@@ -1,8 +1,14 @@
+use core::cmp::Ordering;
 use core::mem::MaybeUninit;
 
+#[cfg(feature = "alloc")]
+use crate::conversion_budget::ConversionBudget;
+use crate::{builder::BufferBuilder, Offset, UnionOffset, UnionVectorOffset};
 use crate::{
-    builder::Builder, errors::ErrorKind, slice_helpers::SliceWithStartOffset, Cursor, Offset,
-    Result, UnionOffset, UnionVectorOffset,
+    errors::ErrorKind,
+    slice_helpers::SliceWithStartOffset,
+    verifier::{Verifier, VerifierOptions},
+    Cursor, Result,
 };
 
 #[doc(hidden)]
@@ -36,11 +42,186 @@ pub unsafe trait Primitive {
 ///     Ok(())
 /// }
 pub trait ReadAsRoot<'a>: Sized {
+    /// The name of the type used in error locations, such as `"[MonsterRef]"`.
+    /// Generated code sets this, other implementations can keep the default.
+    #[doc(hidden)]
+    const TYPE_NAME: &'static str = "<root>";
+
     /// Takes a slice assumed to be of this type and returns a view into it.
     ///
     /// If the data is not valid for this type the field accessors will give
     /// errors or invalid values, but will still be memory safe.
     fn read_as_root(slice: &'a [u8]) -> Result<Self>;
+
+    /// Takes a size-prefixed slice assumed to be of this type and returns a
+    /// view into it.
+    ///
+    /// The size prefix must match the length of the rest of the slice
+    /// exactly, otherwise [`ErrorKind::InvalidLength`] is returned. Buffers of
+    /// this form can be created using [`BufferBuilder::finish_size_prefixed`].
+    fn read_as_root_size_prefixed(slice: &'a [u8]) -> Result<Self> {
+        let make_error = |error_kind: ErrorKind| {
+            error_kind.with_error_location(Self::TYPE_NAME, "read_as_root_size_prefixed", 0)
+        };
+        let (size, rest) = slice
+            .split_first_chunk::<4>()
+            .ok_or_else(|| make_error(ErrorKind::InvalidLength))?;
+        if u32::from_le_bytes(*size) as usize != rest.len() {
+            return Err(make_error(ErrorKind::InvalidLength));
+        }
+        Self::read_as_root(rest)
+    }
+
+    /// Takes a slice assumed to be of this type, checks that it has the
+    /// given file identifier and returns a view into it.
+    ///
+    /// If the identifier at bytes 4 to 8 does not match, then
+    /// [`ErrorKind::InvalidFileIdentifier`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use planus::{errors::ErrorKind, Builder, ReadAsRoot};
+    /// use planus_example::monster_generated::my_game::sample::{Weapon, WeaponRef};
+    ///
+    /// let mut builder = Builder::new();
+    /// let weapon = Weapon::create(&mut builder, "Axe", 24);
+    /// let data = builder.finish(weapon, Some(*b"WEAP"));
+    ///
+    /// let weapon = WeaponRef::read_as_root_with_identifier(data, *b"WEAP").unwrap();
+    /// assert_eq!(weapon.damage().unwrap(), 24);
+    ///
+    /// let error = WeaponRef::read_as_root_with_identifier(data, *b"SHLD").unwrap_err();
+    /// assert!(matches!(error.error_kind, ErrorKind::InvalidFileIdentifier { .. }));
+    /// ```
+    fn read_as_root_with_identifier(slice: &'a [u8], file_identifier: [u8; 4]) -> Result<Self> {
+        let make_error = |error_kind: ErrorKind| {
+            error_kind.with_error_location(Self::TYPE_NAME, "read_as_root_with_identifier", 0)
+        };
+        let found =
+            crate::file_identifier(slice).ok_or_else(|| make_error(ErrorKind::InvalidLength))?;
+        if found != file_identifier {
+            return Err(make_error(ErrorKind::InvalidFileIdentifier {
+                expected: file_identifier,
+                found,
+            }));
+        }
+        Self::read_as_root(slice)
+    }
+
+    /// Takes a slice assumed to be of this type, verifies the entire buffer
+    /// and returns a view into it.
+    ///
+    /// Unlike [`read_as_root`], this walks every table, vector and string
+    /// reachable from the root up front, using the default [`VerifierOptions`].
+    /// If it succeeds, all accessors reachable from the returned value will
+    /// succeed as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use planus::{Builder, ReadAsRoot};
+    /// use planus_example::monster_generated::my_game::sample::{Weapon, WeaponRef};
+    ///
+    /// let mut builder = Builder::new();
+    /// let weapon = Weapon::create(&mut builder, "Axe", 24);
+    /// let data = builder.finish(weapon, None);
+    ///
+    /// let weapon = WeaponRef::read_as_root_verified(data).unwrap();
+    /// assert_eq!(weapon.name().unwrap(), Some("Axe"));
+    /// assert!(WeaponRef::read_as_root_verified(&data[..data.len() - 8]).is_err());
+    /// ```
+    ///
+    /// [`read_as_root`]: ReadAsRoot::read_as_root
+    /// [`VerifierOptions`]: crate::VerifierOptions
+    fn read_as_root_verified(slice: &'a [u8]) -> Result<Self>
+    where
+        Self: Verify,
+    {
+        Self::read_as_root_verified_with_options(slice, VerifierOptions::default())
+    }
+
+    /// The same as [`read_as_root_verified`], but with custom limits.
+    ///
+    /// [`read_as_root_verified`]: ReadAsRoot::read_as_root_verified
+    fn read_as_root_verified_with_options(slice: &'a [u8], options: VerifierOptions) -> Result<Self>
+    where
+        Self: Verify,
+    {
+        let root = Self::read_as_root(slice)?;
+        root.verify(&mut Verifier::new(options))?;
+        Ok(root)
+    }
+}
+
+/// Trait used by generated code to eagerly verify serialized data.
+///
+/// See [`ReadAsRoot::read_as_root_verified`] for the typical way of using it.
+pub trait Verify {
+    /// Verifies this value and everything reachable from it, returning the
+    /// first error encountered.
+    fn verify(&self, verifier: &mut Verifier) -> Result<()>;
+}
+
+/// Trait implemented by generated owned table types, giving the type used to
+/// read them from a buffer.
+///
+/// See [`OwnedRoot`] for the typical way of using it.
+///
+/// [`OwnedRoot`]: crate::OwnedRoot
+pub trait HasRef {
+    /// The `Ref` type of the table.
+    type Ref<'a>: ReadAsRoot<'a> + TableRead<'a> + Verify + core::fmt::Debug;
+}
+
+/// Conversion from a reference type into an owned type, which fails if the
+/// owned value needs more memory than allowed by a [`ConversionBudget`].
+///
+/// This is implemented by generated code for all owned types, and can be
+/// used instead of [`TryFrom`] when converting untrusted data.
+#[cfg(feature = "alloc")]
+pub trait TryFromBudgeted<T>: Sized {
+    /// Performs the conversion, deducting the memory allocated for the owned
+    /// value from `budget`.
+    ///
+    /// The memory used by `Self` itself is not deducted, only the memory it
+    /// refers to, such as the contents of strings, vectors and boxes.
+    fn try_from_budgeted(value: T, budget: &mut ConversionBudget) -> Result<Self>;
+}
+
+/// Trait for types that can be used as the `key` field of a table or struct.
+///
+/// Floating point keys are compared like flatc compares them, using `<`, so
+/// `-0.0` and `0.0` are equal. NaNs are ordered after all other values.
+pub trait KeyCompare {
+    /// Compares two keys.
+    fn compare_key(&self, other: &Self) -> Ordering;
+}
+
+/// Trait implemented by generated owned types that have a field marked with
+/// the `key` attribute.
+///
+/// See [`BufferBuilder::create_vector_sorted_by_key`] for the typical way of using it.
+pub trait Keyed {
+    /// The type of the key field.
+    type Key: ?Sized + KeyCompare;
+
+    /// Returns the key of this value.
+    fn key(&self) -> &Self::Key;
+}
+
+/// Trait implemented by generated `Ref` types that have a field marked with
+/// the `key` attribute.
+///
+/// See [`Vector::lookup_by_key`] for the typical way of using it.
+///
+/// [`Vector::lookup_by_key`]: crate::Vector::lookup_by_key
+pub trait KeyedRead {
+    /// The type of the key field.
+    type Key: ?Sized + KeyCompare;
+
+    /// Compares the key of this value with the given key.
+    fn compare_key(&self, key: &Self::Key) -> Result<Ordering>;
 }
 
 /// Trait used by generated code to serialize primitive types.
@@ -48,7 +229,7 @@ pub trait WriteAs<P: Primitive> {
     #[doc(hidden)]
     type Prepared: WriteAsPrimitive<P>;
     #[doc(hidden)]
-    fn prepare(&self, builder: &mut Builder) -> Self::Prepared;
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Self::Prepared;
 }
 
 /// Trait used by generated code to serialize primitive types with default values.
@@ -56,7 +237,7 @@ pub trait WriteAsDefault<P: Primitive, D: ?Sized> {
     #[doc(hidden)]
     type Prepared: WriteAsPrimitive<P>;
     #[doc(hidden)]
-    fn prepare(&self, builder: &mut Builder, default: &D) -> Option<Self::Prepared>;
+    fn prepare(&self, builder: &mut BufferBuilder<'_>, default: &D) -> Option<Self::Prepared>;
 }
 
 /// Trait used by generated code to serialize optional primitive types.
@@ -64,48 +245,48 @@ pub trait WriteAsOptional<P: Primitive> {
     #[doc(hidden)]
     type Prepared: WriteAsPrimitive<P>;
     #[doc(hidden)]
-    fn prepare(&self, builder: &mut Builder) -> Option<Self::Prepared>;
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<Self::Prepared>;
 }
 
 /// Trait used by generated code to serialize offsets to already serialized data.
 pub trait WriteAsOffset<T: ?Sized> {
     #[doc(hidden)]
-    fn prepare(&self, builder: &mut Builder) -> Offset<T>;
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Offset<T>;
 }
 
 /// Trait used by generated code to serialize offsets to unions.
 pub trait WriteAsUnion<T: ?Sized> {
     #[doc(hidden)]
-    fn prepare(&self, builder: &mut Builder) -> UnionOffset<T>;
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> UnionOffset<T>;
 }
 
 /// Trait used by generated code to serialize offsets to optional unions.
 pub trait WriteAsOptionalUnion<T: ?Sized> {
     #[doc(hidden)]
-    fn prepare(&self, builder: &mut Builder) -> Option<UnionOffset<T>>;
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<UnionOffset<T>>;
 }
 
 /// Trait used by generated code to serialize offsets to unions.
 pub trait WriteAsUnionVector<T: ?Sized> {
     #[doc(hidden)]
-    fn prepare(&self, builder: &mut Builder) -> UnionVectorOffset<T>;
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> UnionVectorOffset<T>;
 }
 
 /// Trait used by generated code to serialize offsets to union.
 pub trait WriteAsDefaultUnionVector<T: ?Sized> {
     #[doc(hidden)]
-    fn prepare(&self, builder: &mut Builder) -> Option<UnionVectorOffset<T>>;
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<UnionVectorOffset<T>>;
 }
 
 /// Trait used by generated code to serialize offsets to optional unions.
 pub trait WriteAsOptionalUnionVector<T: ?Sized> {
     #[doc(hidden)]
-    fn prepare(&self, builder: &mut Builder) -> Option<UnionVectorOffset<T>>;
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Option<UnionVectorOffset<T>>;
 }
 
 #[doc(hidden)]
 pub trait WriteAsPrimitive<P> {
-    fn write<const N: usize>(&self, cursor: Cursor<'_, N>, buffer_position: u32);
+    fn write<const N: usize>(&self, cursor: Cursor<'_, N>, buffer_position: u64);
 }
 
 #[doc(hidden)]
@@ -114,6 +295,28 @@ pub trait TableRead<'buf>: Sized {
         buffer: SliceWithStartOffset<'buf>,
         offset: usize,
     ) -> core::result::Result<Self, ErrorKind>;
+
+    /// Like [`from_buffer`](Self::from_buffer), but without checking that
+    /// the value can be read.
+    ///
+    /// # Safety
+    ///
+    /// [`from_buffer`](Self::from_buffer) must succeed for the same
+    /// arguments, which is the case for fields of verified buffers.
+    #[inline]
+    unsafe fn from_buffer_unchecked(buffer: SliceWithStartOffset<'buf>, offset: usize) -> Self {
+        unsafe { Self::from_buffer(buffer, offset).unwrap_unchecked() }
+    }
+}
+
+/// Trait used by generated code to read strings and vectors, given the
+/// location of their contents and their length.
+#[doc(hidden)]
+pub trait TableReadArray<'buf>: Sized {
+    fn from_array(
+        buffer: SliceWithStartOffset<'buf>,
+        len: usize,
+    ) -> core::result::Result<Self, ErrorKind>;
 }
 
 #[doc(hidden)]
@@ -142,6 +345,23 @@ pub trait VectorRead<'buf>: 'buf {
     unsafe fn from_buffer(buffer: SliceWithStartOffset<'buf>, offset: usize) -> Self;
 }
 
+/// Trait for vector elements that can be viewed in place as a slice, using
+/// [`Vector::as_slice`](crate::Vector::as_slice).
+///
+/// It is implemented for the multi-byte scalar types and for generated
+/// structs that only contain scalars and other such structs. Bools and enums
+/// are left out, since not every byte pattern is a valid value for them.
+///
+/// # Safety
+/// On little-endian targets, the serialized representation of an element
+/// must be a valid in-memory representation of `Value`. In particular
+/// `Value` must have a size of `STRIDE` bytes, and every bit pattern must
+/// be valid for it.
+pub unsafe trait VectorReadSlice<'buf>: VectorRead<'buf> {
+    /// The type of the elements of the slice.
+    type Value: Copy + 'buf;
+}
+
 #[doc(hidden)]
 pub trait VectorReadUnion<'buf>: 'buf + Sized + TableReadUnion<'buf> {
     const VECTOR_NAME: &'static str;
@@ -180,14 +400,18 @@ pub trait VectorReadInner<'buf>: 'buf + Sized {
 pub unsafe trait VectorWrite<P> {
     #[doc(hidden)]
     const STRIDE: usize;
+    /// Whether `prepare` never writes to the builder, so the values can be
+    /// written directly into the buffer instead of being collected first.
+    #[doc(hidden)]
+    const INLINE: bool = false;
     #[doc(hidden)]
     type Value: WriteAsPrimitive<P> + Sized;
     #[doc(hidden)]
-    fn prepare(&self, builder: &mut Builder) -> Self::Value;
+    fn prepare(&self, builder: &mut BufferBuilder<'_>) -> Self::Value;
     #[doc(hidden)]
     unsafe fn write_values(
         values: &[Self::Value],
         bytes: *mut MaybeUninit<u8>,
-        buffer_position: u32,
+        buffer_position: u64,
     );
 }
@@ -1,11 +1,11 @@
 use core::{marker::PhantomData, num::NonZeroUsize};
 
 use crate::{
-    errors::{self, ErrorKind},
-    impls::array_from_buffer,
-    slice_helpers::SliceWithStartOffset,
-    TableReadUnionVector, VectorReadUnion,
+    errors::ErrorKind, impls::array_from_buffer, slice_helpers::SliceWithStartOffset,
+    TableReadUnionVector, VectorReadUnion, Verifier, Verify,
 };
+#[cfg(feature = "alloc")]
+use crate::{ConversionBudget, TryFromBudgeted};
 
 /// A [`slice`]-like view of a union vector into a serialized flatbuffer that deserializes on demand.
 pub struct UnionVector<'buf, T: ?Sized> {
@@ -299,6 +299,7 @@ impl<'buf, T: VectorReadUnion<'buf>> UnionVector<'buf, T> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'buf, T: VectorReadUnion<'buf>> UnionVector<'buf, T> {
     /// Copies self into a new `Vec`.
     pub fn to_vec<O>(self) -> crate::Result<alloc::vec::Vec<O>>
@@ -306,7 +307,39 @@ impl<'buf, T: VectorReadUnion<'buf>> UnionVector<'buf, T> {
         O: core::convert::TryFrom<T>,
         crate::errors::Error: From<O::Error>,
     {
-        self.iter().map(|v| Ok(O::try_from(v?)?)).collect()
+        self.iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let v = v.map_err(|e| e.with_index(i))?;
+                O::try_from(v).map_err(|e| crate::errors::Error::from(e).with_index(i))
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'buf, T: VectorReadUnion<'buf>, O: TryFromBudgeted<T>> TryFromBudgeted<UnionVector<'buf, T>>
+    for alloc::vec::Vec<O>
+{
+    fn try_from_budgeted(
+        value: UnionVector<'buf, T>,
+        budget: &mut ConversionBudget,
+    ) -> crate::Result<Self> {
+        budget.allocate_array::<O>(value.len).map_err(|e| {
+            e.with_error_location(
+                "UnionVector",
+                "try_from_budgeted",
+                value.values.offset_from_start,
+            )
+        })?;
+        value
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let v = v.map_err(|e| e.with_index(i))?;
+                O::try_from_budgeted(v, budget).map_err(|e| e.with_index(i))
+            })
+            .collect()
     }
 }
 
@@ -533,10 +566,11 @@ impl<'buf, T: VectorReadUnion<'buf>> UnionVectorIndex<'buf, T>
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'buf, T: VectorReadUnion<'buf>, O> TryFrom<UnionVector<'buf, T>> for alloc::vec::Vec<O>
 where
     O: core::convert::TryFrom<T>,
-    errors::Error: From<O::Error>,
+    crate::errors::Error: From<O::Error>,
 {
     type Error = crate::errors::Error;
 
@@ -574,6 +608,18 @@ impl<'buf, T: VectorReadUnion<'buf>> TableReadUnionVector<'buf> for UnionVector<
     }
 }
 
+impl<'buf, T: VectorReadUnion<'buf> + Verify> Verify for UnionVector<'buf, T> {
+    fn verify(&self, verifier: &mut Verifier) -> crate::Result<()> {
+        verifier.visit_bytes(8 + 5 * self.len).map_err(|e| {
+            e.with_error_location("UnionVector", "verify", self.values.offset_from_start)
+        })?;
+        for value in self.iter() {
+            value?.verify(verifier)?;
+        }
+        Ok(())
+    }
+}
+
 mod private {
     pub trait Sealed {}
 
@@ -0,0 +1,127 @@
+use crate::{errors::ErrorKind, slice_helpers::SliceWithStartOffset, TableRead};
+
+/// The largest alignment required by any object in a flatbuffer.
+///
+/// Copies of unknown union values keep the position of every byte modulo this
+/// alignment, so objects inside them stay correctly aligned.
+pub(crate) const UNKNOWN_UNION_ALIGNMENT: usize = 8;
+
+/// Reference to a serialized union value with a tag that is not known by
+/// the schema the code was generated from.
+///
+/// These are only created by code generated with open unions enabled. Since
+/// the type of the value is unknown, it can only be copied as a whole using
+/// [`UnknownUnionRef::to_bytes`].
+#[derive(Copy, Clone, Debug)]
+pub struct UnknownUnionRef<'buf> {
+    tag: u8,
+    buffer: SliceWithStartOffset<'buf>,
+    value_offset: usize,
+}
+
+impl<'buf> UnknownUnionRef<'buf> {
+    #[doc(hidden)]
+    pub fn from_buffer(
+        buffer: SliceWithStartOffset<'buf>,
+        tag: u8,
+        field_offset: usize,
+    ) -> core::result::Result<Self, ErrorKind> {
+        let value_offset: u32 = TableRead::from_buffer(buffer, field_offset)?;
+        let value_offset = field_offset
+            .checked_add(value_offset as usize)
+            .filter(|&value_offset| value_offset < buffer.len())
+            .ok_or(ErrorKind::InvalidOffset)?;
+        Ok(Self {
+            tag,
+            buffer,
+            value_offset,
+        })
+    }
+
+    /// Reads the value at the root of a buffer created by
+    /// [`UnknownUnionRef::to_bytes`], or returns `None` if the root offset
+    /// does not point inside the buffer.
+    pub(crate) fn from_bytes(bytes: &'buf [u8]) -> Option<Self> {
+        let value_offset = bytes
+            .get(..4)
+            .map(|root_offset| u32::from_le_bytes(root_offset.try_into().unwrap()) as usize)
+            .filter(|&value_offset| value_offset < bytes.len())?;
+        Some(Self {
+            tag: 0,
+            buffer: SliceWithStartOffset {
+                buffer: bytes,
+                offset_from_start: 0,
+            },
+            value_offset,
+        })
+    }
+
+    /// The tag of the union value.
+    pub fn tag(&self) -> u8 {
+        self.tag
+    }
+
+    /// Copies the value into a new buffer, which can be written back using
+    /// [`Builder::create_unknown_union_value`](crate::BufferBuilder::create_unknown_union_value).
+    ///
+    /// The copy is a flatbuffer without file identifier with the value as its
+    /// root, so it can be read using the generated code for a newer version of
+    /// the schema. Since the layout of the value is unknown, the copy contains
+    /// everything from the value to the end of the original buffer, as the
+    /// value can only refer to objects placed after it. If the value is a
+    /// table with its vtable placed before it, the vtable is copied as well.
+    #[cfg(feature = "alloc")]
+    pub fn to_bytes(&self) -> alloc::vec::Vec<u8> {
+        let (data, data_position, root_offset) = self.copied_data();
+        let start = Self::data_start(data_position);
+        // The value is at most `i32::MAX` bytes after the start of the data
+        let root_offset = (start + root_offset) as u32;
+        let mut bytes = alloc::vec::Vec::with_capacity(start + data.len());
+        bytes.extend_from_slice(&root_offset.to_le_bytes());
+        bytes.resize(start, 0);
+        bytes.extend_from_slice(data);
+        bytes
+    }
+
+    /// The length of the buffer returned by [`UnknownUnionRef::to_bytes`].
+    pub fn bytes_len(&self) -> usize {
+        let (data, data_position, _) = self.copied_data();
+        Self::data_start(data_position) + data.len()
+    }
+
+    /// Returns the bytes to copy, their position in the original buffer and
+    /// the offset of the value within them.
+    pub(crate) fn copied_data(&self) -> (&'buf [u8], usize, usize) {
+        // A table starts with a signed offset to its vtable. Values of other
+        // types can be mistaken for tables here, which only makes the copy
+        // larger than needed.
+        let vtable_offset: i32 =
+            TableRead::from_buffer(self.buffer, self.value_offset).unwrap_or(0);
+        let start = if vtable_offset > 0 {
+            self.value_offset
+                .checked_sub(vtable_offset as usize)
+                .unwrap_or(self.value_offset)
+        } else {
+            self.value_offset
+        };
+        (
+            &self.buffer.as_slice()[start..],
+            self.buffer.offset_from_start + start,
+            self.value_offset - start,
+        )
+    }
+
+    /// Returns the buffer the value was read from and its position in the
+    /// original buffer.
+    pub(crate) fn source(&self) -> (&'buf [u8], usize) {
+        (self.buffer.as_slice(), self.buffer.offset_from_start)
+    }
+
+    /// The position of the copied data in the buffer returned by
+    /// [`UnknownUnionRef::to_bytes`].
+    fn data_start(data_position: usize) -> usize {
+        // Place the copied data at the same position modulo the alignment as
+        // it had in the original buffer, leaving room for the root offset
+        4 + (data_position + UNKNOWN_UNION_ALIGNMENT - 4) % UNKNOWN_UNION_ALIGNMENT
+    }
+}
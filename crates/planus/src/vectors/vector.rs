@@ -1,12 +1,14 @@
-use core::{marker::PhantomData, num::NonZeroUsize};
+use core::{cmp::Ordering, marker::PhantomData, num::NonZeroUsize};
 
 use crate::{
-    errors::{self, ErrorKind},
-    impls::array_from_buffer,
+    errors::ErrorKind,
+    impls::{array_from_buffer, array_from_buffer_unchecked},
     slice_helpers::SliceWithStartOffset,
-    traits::VectorRead,
-    TableRead,
+    traits::{KeyedRead, TableReadArray, VectorRead, VectorReadInner, VectorReadSlice, Verify},
+    TableRead, Verifier,
 };
+#[cfg(feature = "alloc")]
+use crate::{ConversionBudget, TryFromBudgeted};
 
 /// A [`slice`]-like view into a serialized flatbuffer that deserializes on demand.
 pub struct Vector<'buf, T: ?Sized> {
@@ -292,6 +294,7 @@ impl<'buf, T: VectorRead<'buf>> Vector<'buf, T> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'buf, T: VectorRead<'buf>> Vector<'buf, T> {
     /// Copies self into a new `Vec`.
     pub fn to_vec<O>(self) -> crate::Result<alloc::vec::Vec<O>>
@@ -300,11 +303,120 @@ impl<'buf, T: VectorRead<'buf>> Vector<'buf, T> {
         crate::errors::Error: From<O::Error>,
     {
         self.iter()
-            .map(|v| O::try_from(v).map_err(crate::errors::Error::from))
+            .enumerate()
+            .map(|(i, v)| O::try_from(v).map_err(|e| crate::errors::Error::from(e).with_index(i)))
+            .collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'buf, T: VectorRead<'buf>, O: TryFromBudgeted<T>> TryFromBudgeted<Vector<'buf, T>>
+    for alloc::vec::Vec<O>
+{
+    fn try_from_budgeted(
+        value: Vector<'buf, T>,
+        budget: &mut ConversionBudget,
+    ) -> crate::Result<Self> {
+        budget.allocate_array::<O>(value.len).map_err(|e| {
+            e.with_error_location(
+                "Vector",
+                "try_from_budgeted",
+                value.buffer.offset_from_start,
+            )
+        })?;
+        value
+            .iter()
+            .enumerate()
+            .map(|(i, v)| O::try_from_budgeted(v, budget).map_err(|e| e.with_index(i)))
             .collect()
     }
 }
 
+impl<'buf, T: VectorReadSlice<'buf>> Vector<'buf, T> {
+    /// Returns the contents of the vector as a slice, without copying or
+    /// decoding the elements.
+    ///
+    /// Returns `None` on big-endian targets, or if the elements are not
+    /// aligned in memory. Flatbuffers aligns the elements relative to the
+    /// end of the buffer, so this succeeds when the buffer itself is stored
+    /// at a suitably aligned address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use planus::{Builder, ReadAsRoot};
+    /// use planus_example::monster_generated::my_game::sample::{Monster, MonsterRef, Vec3};
+    ///
+    /// let monster = Monster {
+    ///     path: Some(vec![Vec3 { x: 1.0, y: 2.0, z: 3.0 }]),
+    ///     ..Default::default()
+    /// };
+    /// let mut builder = Builder::new();
+    /// let data = builder.finish(&monster, None);
+    ///
+    /// // Copy the data into memory that is aligned for `f32`
+    /// let mut storage = vec![0f32; data.len().div_ceil(4)];
+    /// let aligned: &mut [u8] =
+    ///     unsafe { std::slice::from_raw_parts_mut(storage.as_mut_ptr().cast(), data.len()) };
+    /// aligned.copy_from_slice(data);
+    ///
+    /// let path = MonsterRef::read_as_root(aligned).unwrap().path().unwrap().unwrap();
+    /// if cfg!(target_endian = "little") {
+    ///     assert_eq!(path.as_slice(), Some(&[Vec3 { x: 1.0, y: 2.0, z: 3.0 }][..]));
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_slice(self) -> Option<&'buf [T::Value]> {
+        debug_assert_eq!(core::mem::size_of::<T::Value>(), T::STRIDE);
+        if cfg!(target_endian = "big") {
+            return None;
+        }
+        if self.len == 0 {
+            return Some(&[]);
+        }
+        let ptr = self.buffer.as_slice().as_ptr().cast::<T::Value>();
+        if !ptr.is_aligned() {
+            return None;
+        }
+        // SAFETY: `from_array` checked that the buffer holds `len` elements
+        // of `STRIDE` bytes, the pointer is aligned, and the `VectorReadSlice`
+        // implementation guarantees that the bytes are valid values
+        Some(unsafe { core::slice::from_raw_parts(ptr, self.len) })
+    }
+}
+
+impl<'buf, T: VectorReadInner<'buf>, E: From<T::Error> + 'buf>
+    Vector<'buf, core::result::Result<T, E>>
+{
+    /// Returns an iterator over the vector that yields the elements without
+    /// the [`Result`] wrapper.
+    ///
+    /// This is useful for iterating over vectors of tables in buffers that
+    /// have already been verified, where reading an element cannot fail.
+    ///
+    /// # Safety
+    ///
+    /// Every element must be readable without errors, which is the case if
+    /// the buffer has been verified, for instance by reading it with
+    /// [`ReadAsRoot::read_as_root_verified`]. Iterating over a vector with an
+    /// element that would return an error is [undefined behavior].
+    ///
+    /// [`ReadAsRoot::read_as_root_verified`]: crate::ReadAsRoot::read_as_root_verified
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    #[inline]
+    pub unsafe fn iter_unchecked(
+        self,
+    ) -> impl DoubleEndedIterator<Item = T> + ExactSizeIterator + core::iter::FusedIterator + Clone + 'buf
+    where
+        T: 'buf,
+    {
+        // SAFETY: the caller guarantees that no element is an error
+        self.iter().map(|value| unsafe { value.unwrap_unchecked() })
+    }
+}
+
+#[cfg(feature = "alloc")]
 impl<'buf, T, E> Vector<'buf, core::result::Result<T, E>> {
     /// Copies self into a new `Vec`.
     pub fn to_vec_result<O>(self) -> crate::Result<alloc::vec::Vec<O>>
@@ -315,11 +427,68 @@ impl<'buf, T, E> Vector<'buf, core::result::Result<T, E>> {
         crate::errors::Error: From<E> + From<O::Error>,
     {
         self.iter()
-            .map(|v| O::try_from(v?).map_err(|e| e.into()))
+            .enumerate()
+            .map(|(i, v)| {
+                let v = v.map_err(|e| crate::errors::Error::from(e).with_index(i))?;
+                O::try_from(v).map_err(|e| crate::errors::Error::from(e).with_index(i))
+            })
             .collect()
     }
 }
 
+impl<'buf, T: VectorRead<'buf> + KeyedRead> Vector<'buf, T> {
+    /// Looks up an element by its `key` field using binary search.
+    ///
+    /// The vector must be sorted by key, for instance by creating it using
+    /// [`BufferBuilder::create_vector_sorted_by_key`]. Otherwise the result is
+    /// unspecified, though still memory safe.
+    ///
+    /// [`BufferBuilder::create_vector_sorted_by_key`]: crate::BufferBuilder::create_vector_sorted_by_key
+    pub fn lookup_by_key(self, key: &T::Key) -> crate::Result<Option<T>> {
+        let (mut low, mut high) = (0, self.len);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            // SAFETY: `mid` is less than `high`, which is at most the length
+            let value = unsafe { self.get_unchecked(mid) };
+            match value.compare_key(key)? {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(Some(value)),
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<'buf, T, E> Vector<'buf, core::result::Result<T, E>>
+where
+    T: VectorReadInner<'buf> + KeyedRead,
+    E: 'buf + From<T::Error>,
+    crate::errors::Error: From<E>,
+{
+    /// Looks up an element by its `key` field using binary search.
+    ///
+    /// The vector must be sorted by key, for instance by creating it using
+    /// [`BufferBuilder::create_vector_sorted_by_key`]. Otherwise the result is
+    /// unspecified, though still memory safe.
+    ///
+    /// [`BufferBuilder::create_vector_sorted_by_key`]: crate::BufferBuilder::create_vector_sorted_by_key
+    pub fn lookup_by_key(self, key: &T::Key) -> crate::Result<Option<T>> {
+        let (mut low, mut high) = (0, self.len);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            // SAFETY: `mid` is less than `high`, which is at most the length
+            let value = unsafe { self.get_unchecked(mid) }?;
+            match value.compare_key(key)? {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(Some(value)),
+            }
+        }
+        Ok(None)
+    }
+}
+
 impl<'buf, T: VectorRead<'buf>> IntoIterator for Vector<'buf, T> {
     type Item = T;
     type IntoIter = super::Iter<'buf, T>;
@@ -533,17 +702,18 @@ impl<'buf, T: VectorRead<'buf>> VectorIndex<'buf, T> for core::ops::RangeToInclu
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'buf, T: VectorRead<'buf>, O> TryFrom<Vector<'buf, T>> for alloc::vec::Vec<O>
 where
     O: core::convert::TryFrom<T>,
-    errors::Error: From<O::Error>,
+    crate::errors::Error: From<O::Error>,
 {
     type Error = crate::errors::Error;
 
     fn try_from(value: Vector<'buf, T>) -> Result<Self, Self::Error> {
         value
             .iter()
-            .map(|v| O::try_from(v).map_err(errors::Error::from))
+            .map(|v| O::try_from(v).map_err(crate::errors::Error::from))
             .collect()
     }
 }
@@ -554,6 +724,25 @@ impl<'buf, T: ?Sized + VectorRead<'buf>> TableRead<'buf> for Vector<'buf, T> {
         offset: usize,
     ) -> core::result::Result<Self, ErrorKind> {
         let (buffer, len) = array_from_buffer(buffer, offset)?;
+        Self::from_array(buffer, len)
+    }
+
+    #[inline]
+    unsafe fn from_buffer_unchecked(buffer: SliceWithStartOffset<'buf>, offset: usize) -> Self {
+        let (buffer, len) = unsafe { array_from_buffer_unchecked(buffer, offset) };
+        Vector {
+            buffer,
+            len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'buf, T: ?Sized + VectorRead<'buf>> TableReadArray<'buf> for Vector<'buf, T> {
+    fn from_array(
+        buffer: SliceWithStartOffset<'buf>,
+        len: usize,
+    ) -> core::result::Result<Self, ErrorKind> {
         if len.checked_mul(T::STRIDE).ok_or(ErrorKind::InvalidLength)? <= buffer.len() {
             Ok(Vector {
                 buffer,
@@ -566,6 +755,20 @@ impl<'buf, T: ?Sized + VectorRead<'buf>> TableRead<'buf> for Vector<'buf, T> {
     }
 }
 
+impl<'buf, T: VectorRead<'buf> + Verify> Verify for Vector<'buf, T> {
+    fn verify(&self, verifier: &mut Verifier) -> crate::Result<()> {
+        verifier
+            .visit_bytes(4 + self.len * T::STRIDE)
+            .map_err(|e| {
+                e.with_error_location("Vector", "verify", self.buffer.offset_from_start)
+            })?;
+        for value in self.iter() {
+            value.verify(verifier)?;
+        }
+        Ok(())
+    }
+}
+
 mod private {
     pub trait Sealed {}
 
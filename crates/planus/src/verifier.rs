@@ -0,0 +1,144 @@
+use crate::{errors::ErrorKind, table_reader::Table};
+
+/// Limits enforced by a [`Verifier`] while walking a buffer.
+///
+/// The defaults match the ones used by the `flatc` verifier.
+#[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
+pub struct VerifierOptions {
+    /// The maximum nesting depth of tables.
+    pub max_depth: usize,
+    /// The maximum number of tables visited, counting shared tables once per visit.
+    pub max_tables: usize,
+    /// The maximum number of bytes visited, counting shared data once per visit.
+    pub max_bytes: usize,
+}
+
+impl Default for VerifierOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: 64,
+            max_tables: 1_000_000,
+            max_bytes: 0x7fff_ffff,
+        }
+    }
+}
+
+/// Eagerly checks an entire serialized buffer against its schema.
+///
+/// A verifier is normally used through [`ReadAsRoot::read_as_root_verified`],
+/// but it can also be passed to [`Verify::verify`] directly to verify a
+/// sub-tree or to inspect how much of the buffer was visited.
+///
+/// If verification succeeds, then every accessor reachable from the verified
+/// value will succeed as well.
+///
+/// [`ReadAsRoot::read_as_root_verified`]: crate::ReadAsRoot::read_as_root_verified
+/// [`Verify::verify`]: crate::Verify::verify
+#[derive(Clone, Debug)]
+pub struct Verifier {
+    options: VerifierOptions,
+    depth: usize,
+    tables: usize,
+    bytes: usize,
+    table_offset: usize,
+}
+
+impl Default for Verifier {
+    fn default() -> Self {
+        Self::new(VerifierOptions::default())
+    }
+}
+
+impl Verifier {
+    /// Creates a new verifier with the given limits.
+    pub fn new(options: VerifierOptions) -> Self {
+        Self {
+            options,
+            depth: 0,
+            tables: 0,
+            bytes: 0,
+            table_offset: 0,
+        }
+    }
+
+    /// Returns the limits used by this verifier.
+    pub fn options(&self) -> &VerifierOptions {
+        &self.options
+    }
+
+    /// Returns the number of tables visited so far.
+    pub fn tables_visited(&self) -> usize {
+        self.tables
+    }
+
+    /// Returns the number of bytes visited so far.
+    pub fn bytes_visited(&self) -> usize {
+        self.bytes
+    }
+
+    /// Verifies a table, using `verify_fields` to verify its fields.
+    ///
+    /// The depth and the offset of the current table are restored afterwards,
+    /// also when verification fails, so errors in the remaining fields of the
+    /// parent table are reported at the parent.
+    #[doc(hidden)]
+    pub fn verify_table(
+        &mut self,
+        table: &Table<'_>,
+        type_: &'static str,
+        verify_fields: impl FnOnce(&mut Self) -> crate::Result<()>,
+    ) -> crate::Result<()> {
+        let parent_table_offset = self.table_offset;
+        let parent_depth = self.depth;
+        let result = self
+            .enter_table(table, type_)
+            .and_then(|()| verify_fields(self));
+        self.table_offset = parent_table_offset;
+        self.depth = parent_depth;
+        result
+    }
+
+    fn enter_table(&mut self, table: &Table<'_>, type_: &'static str) -> crate::Result<()> {
+        let make_error =
+            |error_kind: ErrorKind| error_kind.with_error_location(type_, "verify", table.offset());
+
+        self.table_offset = table.offset();
+        self.depth += 1;
+        if self.depth > self.options.max_depth {
+            return Err(make_error(ErrorKind::DepthLimitExceeded {
+                limit: self.options.max_depth,
+            }));
+        }
+        self.tables += 1;
+        if self.tables > self.options.max_tables {
+            return Err(make_error(ErrorKind::TableLimitExceeded {
+                limit: self.options.max_tables,
+            }));
+        }
+        self.visit_bytes(table.vtable_size()).map_err(make_error)
+    }
+
+    /// Accounts for data without a location of its own, such as the contents
+    /// of strings. Errors are reported at the most recently entered table.
+    pub(crate) fn visit_bytes_at_table(
+        &mut self,
+        amount: usize,
+        type_: &'static str,
+    ) -> crate::Result<()> {
+        self.visit_bytes(amount)
+            .map_err(|e| e.with_error_location(type_, "verify", self.table_offset))
+    }
+
+    #[doc(hidden)]
+    pub fn visit_bytes(&mut self, amount: usize) -> core::result::Result<(), ErrorKind> {
+        self.bytes = self.bytes.saturating_add(amount);
+        if self.bytes > self.options.max_bytes {
+            Err(ErrorKind::ByteLimitExceeded {
+                limit: self.options.max_bytes,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -7,7 +7,7 @@ const _: () = ::planus::check_version_compatibility("planus-1.3.0");
 /// Generated from these locations:
 /// * File `examples/rust/monster.fbs`
 #[no_implicit_prelude]
-#[allow(clippy::needless_lifetimes)]
+#[allow(clippy::needless_lifetimes, clippy::result_large_err)]
 mod root {
     /// The namespace `MyGame`
     ///
@@ -72,6 +72,16 @@ mod root {
                 }
             }
 
+            impl ::planus::TryFromBudgeted<Color> for Color {
+                #[inline]
+                fn try_from_budgeted(
+                    value: Color,
+                    _budget: &mut ::planus::ConversionBudget,
+                ) -> ::planus::Result<Self> {
+                    ::core::result::Result::Ok(value)
+                }
+            }
+
             impl ::core::convert::From<Color> for i8 {
                 #[inline]
                 fn from(value: Color) -> Self {
@@ -79,6 +89,16 @@ mod root {
                 }
             }
 
+            impl ::planus::KeyCompare for Color {
+                #[inline]
+                fn compare_key(&self, other: &Self) -> ::core::cmp::Ordering {
+                    <i8 as ::planus::KeyCompare>::compare_key(
+                        &<i8 as ::core::convert::From<Self>>::from(*self),
+                        &<i8 as ::core::convert::From<Self>>::from(*other),
+                    )
+                }
+            }
+
             /// # Safety
             /// The Planus compiler correctly calculates `ALIGNMENT` and `SIZE`.
             unsafe impl ::planus::Primitive for Color {
@@ -91,9 +111,9 @@ mod root {
                 fn write<const N: usize>(
                     &self,
                     cursor: ::planus::Cursor<'_, N>,
-                    buffer_position: u32,
+                    buffer_position: u64,
                 ) {
-                    (*self as i8).write(cursor, buffer_position);
+                    <i8 as ::core::convert::From<Self>>::from(*self).write(cursor, buffer_position);
                 }
             }
 
@@ -101,7 +121,7 @@ mod root {
                 type Prepared = Self;
 
                 #[inline]
-                fn prepare(&self, _builder: &mut ::planus::Builder) -> Color {
+                fn prepare(&self, _builder: &mut ::planus::BufferBuilder<'_>) -> Color {
                     *self
                 }
             }
@@ -112,7 +132,7 @@ mod root {
                 #[inline]
                 fn prepare(
                     &self,
-                    _builder: &mut ::planus::Builder,
+                    _builder: &mut ::planus::BufferBuilder<'_>,
                     default: &Color,
                 ) -> ::core::option::Option<Color> {
                     if self == default {
@@ -129,7 +149,7 @@ mod root {
                 #[inline]
                 fn prepare(
                     &self,
-                    _builder: &mut ::planus::Builder,
+                    _builder: &mut ::planus::BufferBuilder<'_>,
                 ) -> ::core::option::Option<Color> {
                     ::core::option::Option::Some(*self)
                 }
@@ -146,6 +166,13 @@ mod root {
                 }
             }
 
+            impl ::planus::Verify for Color {
+                #[inline]
+                fn verify(&self, _verifier: &mut ::planus::Verifier) -> ::planus::Result<()> {
+                    ::core::result::Result::Ok(())
+                }
+            }
+
             impl<'buf> ::planus::VectorReadInner<'buf> for Color {
                 type Error = ::planus::errors::UnknownEnumTag;
                 const STRIDE: usize = 1;
@@ -173,11 +200,12 @@ mod root {
             /// the bytes in `write_values`.
             unsafe impl ::planus::VectorWrite<Color> for Color {
                 const STRIDE: usize = 1;
+                const INLINE: bool = true;
 
                 type Value = Self;
 
                 #[inline]
-                fn prepare(&self, _builder: &mut ::planus::Builder) -> Self {
+                fn prepare(&self, _builder: &mut ::planus::BufferBuilder<'_>) -> Self {
                     *self
                 }
 
@@ -185,14 +213,14 @@ mod root {
                 unsafe fn write_values(
                     values: &[Self],
                     bytes: *mut ::core::mem::MaybeUninit<u8>,
-                    buffer_position: u32,
+                    buffer_position: u64,
                 ) {
                     let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 1];
                     for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
                         ::planus::WriteAsPrimitive::write(
                             v,
                             ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
-                            buffer_position - i as u32,
+                            buffer_position - i as u64,
                         );
                     }
                 }
@@ -230,7 +258,7 @@ mod root {
 
                 #[inline]
                 pub fn create_weapon(
-                    builder: &mut ::planus::Builder,
+                    builder: &mut ::planus::BufferBuilder<'_>,
                     value: impl ::planus::WriteAsOffset<self::Weapon>,
                 ) -> ::planus::UnionOffset<Self> {
                     ::planus::UnionOffset::new(1, value.prepare(builder).downcast())
@@ -238,7 +266,7 @@ mod root {
 
                 #[inline]
                 pub fn create_shield(
-                    builder: &mut ::planus::Builder,
+                    builder: &mut ::planus::BufferBuilder<'_>,
                     value: impl ::planus::WriteAsOffset<self::Shield>,
                 ) -> ::planus::UnionOffset<Self> {
                     ::planus::UnionOffset::new(2, value.prepare(builder).downcast())
@@ -247,7 +275,10 @@ mod root {
 
             impl ::planus::WriteAsUnion<Equipment> for Equipment {
                 #[inline]
-                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::UnionOffset<Self> {
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::UnionOffset<Self> {
                     match self {
                         Self::Weapon(value) => Self::create_weapon(builder, value),
                         Self::Shield(value) => Self::create_shield(builder, value),
@@ -259,7 +290,7 @@ mod root {
                 #[inline]
                 fn prepare(
                     &self,
-                    builder: &mut ::planus::Builder,
+                    builder: &mut ::planus::BufferBuilder<'_>,
                 ) -> ::core::option::Option<::planus::UnionOffset<Self>> {
                     ::core::option::Option::Some(::planus::WriteAsUnion::prepare(self, builder))
                 }
@@ -297,7 +328,7 @@ mod root {
                 #[inline]
                 pub fn finish(
                     self,
-                    builder: &mut ::planus::Builder,
+                    builder: &mut ::planus::BufferBuilder<'_>,
                 ) -> ::planus::UnionOffset<Equipment>
                 where
                     Self: ::planus::WriteAsUnion<Equipment>,
@@ -313,7 +344,7 @@ mod root {
                 #[inline]
                 fn prepare(
                     &self,
-                    builder: &mut ::planus::Builder,
+                    builder: &mut ::planus::BufferBuilder<'_>,
                 ) -> ::planus::UnionOffset<Equipment> {
                     ::planus::UnionOffset::new(1, (self.0).0.prepare(builder).downcast())
                 }
@@ -326,7 +357,7 @@ mod root {
                 #[inline]
                 fn prepare(
                     &self,
-                    builder: &mut ::planus::Builder,
+                    builder: &mut ::planus::BufferBuilder<'_>,
                 ) -> ::core::option::Option<::planus::UnionOffset<Equipment>> {
                     ::core::option::Option::Some(::planus::WriteAsUnion::prepare(self, builder))
                 }
@@ -338,7 +369,7 @@ mod root {
                 #[inline]
                 fn prepare(
                     &self,
-                    builder: &mut ::planus::Builder,
+                    builder: &mut ::planus::BufferBuilder<'_>,
                 ) -> ::planus::UnionOffset<Equipment> {
                     ::planus::UnionOffset::new(2, (self.0).0.prepare(builder).downcast())
                 }
@@ -351,7 +382,7 @@ mod root {
                 #[inline]
                 fn prepare(
                     &self,
-                    builder: &mut ::planus::Builder,
+                    builder: &mut ::planus::BufferBuilder<'_>,
                 ) -> ::core::option::Option<::planus::UnionOffset<Equipment>> {
                     ::core::option::Option::Some(::planus::WriteAsUnion::prepare(self, builder))
                 }
@@ -384,6 +415,41 @@ mod root {
                 }
             }
 
+            impl<'a> ::planus::TryFromBudgeted<EquipmentRef<'a>> for Equipment {
+                fn try_from_budgeted(
+                    value: EquipmentRef<'a>,
+                    budget: &mut ::planus::ConversionBudget,
+                ) -> ::planus::Result<Self> {
+                    ::core::result::Result::Ok(match value {
+                        EquipmentRef::Weapon(value) => Self::Weapon(budget.boxed(value)?),
+
+                        EquipmentRef::Shield(value) => Self::Shield(budget.boxed(value)?),
+                    })
+                }
+            }
+
+            impl<'a> ::planus::WriteAsUnion<Equipment> for EquipmentRef<'a> {
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::UnionOffset<Equipment> {
+                    match self {
+                        Self::Weapon(value) => Equipment::create_weapon(builder, value),
+                        Self::Shield(value) => Equipment::create_shield(builder, value),
+                    }
+                }
+            }
+
+            impl<'a> ::planus::WriteAsOptionalUnion<Equipment> for EquipmentRef<'a> {
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::core::option::Option<::planus::UnionOffset<Equipment>> {
+                    ::core::option::Option::Some(::planus::WriteAsUnion::prepare(self, builder))
+                }
+            }
+
             impl<'a> ::planus::TableReadUnion<'a> for EquipmentRef<'a> {
                 fn from_buffer(
                     buffer: ::planus::SliceWithStartOffset<'a>,
@@ -404,6 +470,15 @@ mod root {
                 }
             }
 
+            impl<'a> ::planus::Verify for EquipmentRef<'a> {
+                fn verify(&self, verifier: &mut ::planus::Verifier) -> ::planus::Result<()> {
+                    match self {
+                        Self::Weapon(value) => ::planus::Verify::verify(value, verifier),
+                        Self::Shield(value) => ::planus::Verify::verify(value, verifier),
+                    }
+                }
+            }
+
             impl<'a> ::planus::VectorReadUnion<'a> for EquipmentRef<'a> {
                 const VECTOR_NAME: &'static str = "[EquipmentRef]";
             }
@@ -422,6 +497,7 @@ mod root {
                 ::serde::Serialize,
                 ::serde::Deserialize,
             )]
+            #[repr(C, align(4))]
             pub struct Vec3 {
                 ///  East-west direction
                 pub x: f32,
@@ -433,6 +509,14 @@ mod root {
                 pub z: f32,
             }
 
+            const _: () = {
+                ::core::assert!(::core::mem::size_of::<Vec3>() == 12);
+                ::core::assert!(::core::mem::align_of::<Vec3>() == 4);
+                ::core::assert!(::core::mem::offset_of!(Vec3, x) == 0);
+                ::core::assert!(::core::mem::offset_of!(Vec3, y) == 4);
+                ::core::assert!(::core::mem::offset_of!(Vec3, z) == 8);
+            };
+
             /// # Safety
             /// The Planus compiler correctly calculates `ALIGNMENT` and `SIZE`.
             unsafe impl ::planus::Primitive for Vec3 {
@@ -446,7 +530,7 @@ mod root {
                 fn write<const N: usize>(
                     &self,
                     cursor: ::planus::Cursor<'_, N>,
-                    buffer_position: u32,
+                    buffer_position: u64,
                 ) {
                     let (cur, cursor) = cursor.split::<4, 8>();
                     self.x.write(cur, buffer_position - 0);
@@ -460,7 +544,10 @@ mod root {
 
             impl ::planus::WriteAsOffset<Vec3> for Vec3 {
                 #[inline]
-                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Vec3> {
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Vec3> {
                     unsafe {
                         builder.write_with(12, 3, |buffer_position, bytes| {
                             let bytes = bytes.as_mut_ptr();
@@ -481,7 +568,7 @@ mod root {
             impl ::planus::WriteAs<Vec3> for Vec3 {
                 type Prepared = Self;
                 #[inline]
-                fn prepare(&self, _builder: &mut ::planus::Builder) -> Self {
+                fn prepare(&self, _builder: &mut ::planus::BufferBuilder<'_>) -> Self {
                     *self
                 }
             }
@@ -491,7 +578,7 @@ mod root {
                 #[inline]
                 fn prepare(
                     &self,
-                    _builder: &mut ::planus::Builder,
+                    _builder: &mut ::planus::BufferBuilder<'_>,
                 ) -> ::core::option::Option<Self> {
                     ::core::option::Option::Some(*self)
                 }
@@ -576,6 +663,16 @@ mod root {
                 }
             }
 
+            impl<'a> ::planus::TryFromBudgeted<Vec3Ref<'a>> for Vec3 {
+                #[inline]
+                fn try_from_budgeted(
+                    value: Vec3Ref<'a>,
+                    _budget: &mut ::planus::ConversionBudget,
+                ) -> ::planus::Result<Self> {
+                    ::core::result::Result::Ok(::core::convert::From::from(value))
+                }
+            }
+
             impl<'a> ::planus::TableRead<'a> for Vec3Ref<'a> {
                 #[inline]
                 fn from_buffer(
@@ -585,6 +682,14 @@ mod root {
                     let buffer = buffer.advance_as_array::<12>(offset)?;
                     ::core::result::Result::Ok(Self(buffer))
                 }
+
+                #[inline]
+                unsafe fn from_buffer_unchecked(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> Self {
+                    Self(unsafe { buffer.unchecked_advance_as_array(offset) })
+                }
             }
 
             impl<'a> ::planus::VectorRead<'a> for Vec3Ref<'a> {
@@ -599,16 +704,31 @@ mod root {
                 }
             }
 
+            /// # Safety
+            /// The struct is `#[repr(C)]` with the same layout as in the buffer, which
+            /// is checked at compile time, and it only contains integers and floats.
+            unsafe impl<'a> ::planus::VectorReadSlice<'a> for Vec3Ref<'a> {
+                type Value = Vec3;
+            }
+
+            impl<'a> ::planus::Verify for Vec3Ref<'a> {
+                #[allow(unused_variables)]
+                fn verify(&self, verifier: &mut ::planus::Verifier) -> ::planus::Result<()> {
+                    ::core::result::Result::Ok(())
+                }
+            }
+
             /// # Safety
             /// The planus compiler generates implementations that initialize
             /// the bytes in `write_values`.
             unsafe impl ::planus::VectorWrite<Vec3> for Vec3 {
                 const STRIDE: usize = 12;
+                const INLINE: bool = true;
 
                 type Value = Vec3;
 
                 #[inline]
-                fn prepare(&self, _builder: &mut ::planus::Builder) -> Self::Value {
+                fn prepare(&self, _builder: &mut ::planus::BufferBuilder<'_>) -> Self::Value {
                     *self
                 }
 
@@ -616,14 +736,98 @@ mod root {
                 unsafe fn write_values(
                     values: &[Vec3],
                     bytes: *mut ::core::mem::MaybeUninit<u8>,
-                    buffer_position: u32,
+                    buffer_position: u64,
                 ) {
                     let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 12];
                     for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
                         ::planus::WriteAsPrimitive::write(
                             v,
                             ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
-                            buffer_position - (12 * i) as u32,
+                            buffer_position - (12 * i) as u64,
+                        );
+                    }
+                }
+            }
+
+            impl<'a> ::planus::WriteAsPrimitive<Vec3> for Vec3Ref<'a> {
+                #[inline]
+                fn write<const N: usize>(
+                    &self,
+                    cursor: ::planus::Cursor<'_, N>,
+                    _buffer_position: u64,
+                ) {
+                    cursor.assert_size().finish(*self.0.as_array());
+                }
+            }
+
+            impl<'a> ::planus::WriteAsOffset<Vec3> for Vec3Ref<'a> {
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Vec3> {
+                    unsafe {
+                        builder.write_with(12, 3, |buffer_position, bytes| {
+                            let bytes = bytes.as_mut_ptr();
+
+                            ::planus::WriteAsPrimitive::write(
+                                self,
+                                ::planus::Cursor::new(
+                                    &mut *(bytes as *mut [::core::mem::MaybeUninit<u8>; 12]),
+                                ),
+                                buffer_position,
+                            );
+                        });
+                    }
+                    builder.current_offset()
+                }
+            }
+
+            impl<'a> ::planus::WriteAs<Vec3> for Vec3Ref<'a> {
+                type Prepared = Self;
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::BufferBuilder<'_>) -> Self {
+                    *self
+                }
+            }
+
+            impl<'a> ::planus::WriteAsOptional<Vec3> for Vec3Ref<'a> {
+                type Prepared = Self;
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::core::option::Option<Self> {
+                    ::core::option::Option::Some(*self)
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl<'a> ::planus::VectorWrite<Vec3> for Vec3Ref<'a> {
+                const STRIDE: usize = 12;
+                const INLINE: bool = true;
+
+                type Value = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::BufferBuilder<'_>) -> Self::Value {
+                    *self
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[Self],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u64,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 12];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - (12 * i) as u64,
                         );
                     }
                 }
@@ -678,6 +882,14 @@ mod root {
             }
 
             impl Monster {
+                /// Serializes a [Monster] as the root of the buffer.
+                pub fn finish_root<'b>(
+                    builder: &'b mut ::planus::BufferBuilder<'_>,
+                    root: impl ::planus::WriteAsOffset<Self>,
+                ) -> &'b [u8] {
+                    builder.finish(root, ::core::option::Option::None)
+                }
+
                 /// Creates a [MonsterBuilder] for serializing an instance of this table.
                 #[inline]
                 pub fn builder() -> MonsterBuilder<()> {
@@ -686,7 +898,7 @@ mod root {
 
                 #[allow(clippy::too_many_arguments)]
                 pub fn create(
-                    builder: &mut ::planus::Builder,
+                    builder: &mut ::planus::BufferBuilder<'_>,
                     field_pos: impl ::planus::WriteAsOptional<self::Vec3>,
                     field_mana: impl ::planus::WriteAsDefault<i16, i16>,
                     field_hp: impl ::planus::WriteAsDefault<i16, i16>,
@@ -809,7 +1021,10 @@ mod root {
                 type Prepared = ::planus::Offset<Self>;
 
                 #[inline]
-                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Monster> {
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Monster> {
                     ::planus::WriteAsOffset::prepare(self, builder)
                 }
             }
@@ -820,7 +1035,7 @@ mod root {
                 #[inline]
                 fn prepare(
                     &self,
-                    builder: &mut ::planus::Builder,
+                    builder: &mut ::planus::BufferBuilder<'_>,
                 ) -> ::core::option::Option<::planus::Offset<Monster>> {
                     ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
                 }
@@ -828,7 +1043,10 @@ mod root {
 
             impl ::planus::WriteAsOffset<Monster> for Monster {
                 #[inline]
-                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Monster> {
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Monster> {
                     Monster::create(
                         builder,
                         self.pos,
@@ -1071,7 +1289,10 @@ mod root {
             {
                 /// Finish writing the builder to get an [Offset](::planus::Offset) to a serialized [Monster].
                 #[inline]
-                pub fn finish(self, builder: &mut ::planus::Builder) -> ::planus::Offset<Monster>
+                pub fn finish(
+                    self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Monster>
                 where
                     Self: ::planus::WriteAsOffset<Monster>,
                 {
@@ -1096,7 +1317,10 @@ mod root {
                 type Prepared = ::planus::Offset<Monster>;
 
                 #[inline]
-                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Monster> {
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Monster> {
                     ::planus::WriteAsOffset::prepare(self, builder)
                 }
             }
@@ -1120,7 +1344,7 @@ mod root {
                 #[inline]
                 fn prepare(
                     &self,
-                    builder: &mut ::planus::Builder,
+                    builder: &mut ::planus::BufferBuilder<'_>,
                 ) -> ::core::option::Option<::planus::Offset<Monster>> {
                     ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
                 }
@@ -1141,7 +1365,10 @@ mod root {
                 for MonsterBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9)>
             {
                 #[inline]
-                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Monster> {
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Monster> {
                     let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9) = &self.0;
                     Monster::create(builder, v0, v1, v2, v3, v4, v5, v6, v7, v8, v9)
                 }
@@ -1152,24 +1379,68 @@ mod root {
             pub struct MonsterRef<'a>(#[allow(dead_code)] ::planus::table_reader::Table<'a>);
 
             impl<'a> MonsterRef<'a> {
+                /// Reads a buffer with a [Monster] as its root.
+                pub fn read_root(buffer: &'a [u8]) -> ::planus::Result<Self> {
+                    ::planus::ReadAsRoot::read_as_root(buffer)
+                }
+
                 /// Getter for the [`pos` field](Monster#structfield.pos).
                 #[inline]
                 pub fn pos(&self) -> ::planus::Result<::core::option::Option<self::Vec3Ref<'a>>> {
                     self.0.access(0, "Monster", "pos")
                 }
 
+                /// Getter for the `pos` field without error checking.
+                ///
+                /// # Safety
+                ///
+                /// The buffer must have been verified, for instance by reading it with
+                /// [`read_as_root_verified`](::planus::ReadAsRoot::read_as_root_verified).
+                /// Calling this on a buffer where [`pos`](Self::pos)
+                /// would return an error is undefined behavior.
+                #[inline]
+                pub unsafe fn pos_unchecked(&self) -> ::core::option::Option<self::Vec3Ref<'a>> {
+                    unsafe { self.0.access_unchecked(0) }
+                }
+
                 /// Getter for the [`mana` field](Monster#structfield.mana).
                 #[inline]
                 pub fn mana(&self) -> ::planus::Result<i16> {
                     ::core::result::Result::Ok(self.0.access(1, "Monster", "mana")?.unwrap_or(150))
                 }
 
+                /// Getter for the `mana` field without error checking.
+                ///
+                /// # Safety
+                ///
+                /// The buffer must have been verified, for instance by reading it with
+                /// [`read_as_root_verified`](::planus::ReadAsRoot::read_as_root_verified).
+                /// Calling this on a buffer where [`mana`](Self::mana)
+                /// would return an error is undefined behavior.
+                #[inline]
+                pub unsafe fn mana_unchecked(&self) -> i16 {
+                    unsafe { self.0.access_unchecked(1) }.unwrap_or(150)
+                }
+
                 /// Getter for the [`hp` field](Monster#structfield.hp).
                 #[inline]
                 pub fn hp(&self) -> ::planus::Result<i16> {
                     ::core::result::Result::Ok(self.0.access(2, "Monster", "hp")?.unwrap_or(100))
                 }
 
+                /// Getter for the `hp` field without error checking.
+                ///
+                /// # Safety
+                ///
+                /// The buffer must have been verified, for instance by reading it with
+                /// [`read_as_root_verified`](::planus::ReadAsRoot::read_as_root_verified).
+                /// Calling this on a buffer where [`hp`](Self::hp)
+                /// would return an error is undefined behavior.
+                #[inline]
+                pub unsafe fn hp_unchecked(&self) -> i16 {
+                    unsafe { self.0.access_unchecked(2) }.unwrap_or(100)
+                }
+
                 /// Getter for the [`name` field](Monster#structfield.name).
                 #[inline]
                 pub fn name(
@@ -1179,12 +1450,40 @@ mod root {
                     self.0.access(3, "Monster", "name")
                 }
 
+                /// Getter for the `name` field without error checking.
+                ///
+                /// # Safety
+                ///
+                /// The buffer must have been verified, for instance by reading it with
+                /// [`read_as_root_verified`](::planus::ReadAsRoot::read_as_root_verified).
+                /// Calling this on a buffer where [`name`](Self::name)
+                /// would return an error is undefined behavior.
+                #[inline]
+                pub unsafe fn name_unchecked(
+                    &self,
+                ) -> ::core::option::Option<&'a ::core::primitive::str> {
+                    unsafe { self.0.access_unchecked(3) }
+                }
+
                 /// Getter for the [`inventory` field](Monster#structfield.inventory).
                 #[inline]
                 pub fn inventory(&self) -> ::planus::Result<::core::option::Option<&'a [u8]>> {
                     self.0.access(5, "Monster", "inventory")
                 }
 
+                /// Getter for the `inventory` field without error checking.
+                ///
+                /// # Safety
+                ///
+                /// The buffer must have been verified, for instance by reading it with
+                /// [`read_as_root_verified`](::planus::ReadAsRoot::read_as_root_verified).
+                /// Calling this on a buffer where [`inventory`](Self::inventory)
+                /// would return an error is undefined behavior.
+                #[inline]
+                pub unsafe fn inventory_unchecked(&self) -> ::core::option::Option<&'a [u8]> {
+                    unsafe { self.0.access_unchecked(5) }
+                }
+
                 /// Getter for the [`color` field](Monster#structfield.color).
                 #[inline]
                 pub fn color(&self) -> ::planus::Result<self::Color> {
@@ -1195,6 +1494,19 @@ mod root {
                     )
                 }
 
+                /// Getter for the `color` field without error checking.
+                ///
+                /// # Safety
+                ///
+                /// The buffer must have been verified, for instance by reading it with
+                /// [`read_as_root_verified`](::planus::ReadAsRoot::read_as_root_verified).
+                /// Calling this on a buffer where [`color`](Self::color)
+                /// would return an error is undefined behavior.
+                #[inline]
+                pub unsafe fn color_unchecked(&self) -> self::Color {
+                    unsafe { self.0.access_unchecked(6) }.unwrap_or(self::Color::Blue)
+                }
+
                 /// Getter for the [`weapons` field](Monster#structfield.weapons).
                 #[inline]
                 pub fn weapons(
@@ -1207,6 +1519,23 @@ mod root {
                     self.0.access(7, "Monster", "weapons")
                 }
 
+                /// Getter for the `weapons` field without error checking.
+                ///
+                /// # Safety
+                ///
+                /// The buffer must have been verified, for instance by reading it with
+                /// [`read_as_root_verified`](::planus::ReadAsRoot::read_as_root_verified).
+                /// Calling this on a buffer where [`weapons`](Self::weapons)
+                /// would return an error is undefined behavior.
+                #[inline]
+                pub unsafe fn weapons_unchecked(
+                    &self,
+                ) -> ::core::option::Option<
+                    ::planus::Vector<'a, ::planus::Result<self::WeaponRef<'a>>>,
+                > {
+                    unsafe { self.0.access_unchecked(7) }
+                }
+
                 /// Getter for the [`equipped` field](Monster#structfield.equipped).
                 #[inline]
                 pub fn equipped(
@@ -1216,6 +1545,21 @@ mod root {
                     self.0.access_union(8, "Monster", "equipped")
                 }
 
+                /// Getter for the `equipped` field without error checking.
+                ///
+                /// # Safety
+                ///
+                /// The buffer must have been verified, for instance by reading it with
+                /// [`read_as_root_verified`](::planus::ReadAsRoot::read_as_root_verified).
+                /// Calling this on a buffer where [`equipped`](Self::equipped)
+                /// would return an error is undefined behavior.
+                #[inline]
+                pub unsafe fn equipped_unchecked(
+                    &self,
+                ) -> ::core::option::Option<self::EquipmentRef<'a>> {
+                    unsafe { self.equipped().unwrap_unchecked() }
+                }
+
                 /// Getter for the [`drops` field](Monster#structfield.drops).
                 #[inline]
                 pub fn drops(
@@ -1226,6 +1570,22 @@ mod root {
                     self.0.access_union_vector(10, "Monster", "drops")
                 }
 
+                /// Getter for the `drops` field without error checking.
+                ///
+                /// # Safety
+                ///
+                /// The buffer must have been verified, for instance by reading it with
+                /// [`read_as_root_verified`](::planus::ReadAsRoot::read_as_root_verified).
+                /// Calling this on a buffer where [`drops`](Self::drops)
+                /// would return an error is undefined behavior.
+                #[inline]
+                pub unsafe fn drops_unchecked(
+                    &self,
+                ) -> ::core::option::Option<::planus::UnionVector<'a, self::EquipmentRef<'a>>>
+                {
+                    unsafe { self.drops().unwrap_unchecked() }
+                }
+
                 /// Getter for the [`path` field](Monster#structfield.path).
                 #[inline]
                 pub fn path(
@@ -1234,6 +1594,22 @@ mod root {
                 {
                     self.0.access(12, "Monster", "path")
                 }
+
+                /// Getter for the `path` field without error checking.
+                ///
+                /// # Safety
+                ///
+                /// The buffer must have been verified, for instance by reading it with
+                /// [`read_as_root_verified`](::planus::ReadAsRoot::read_as_root_verified).
+                /// Calling this on a buffer where [`path`](Self::path)
+                /// would return an error is undefined behavior.
+                #[inline]
+                pub unsafe fn path_unchecked(
+                    &self,
+                ) -> ::core::option::Option<::planus::Vector<'a, self::Vec3Ref<'a>>>
+                {
+                    unsafe { self.0.access_unchecked(12) }
+                }
             }
 
             impl<'a> ::core::fmt::Debug for MonsterRef<'a> {
@@ -1275,43 +1651,176 @@ mod root {
             impl<'a> ::core::convert::TryFrom<MonsterRef<'a>> for Monster {
                 type Error = ::planus::Error;
 
-                #[allow(unreachable_code)]
+                #[allow(unreachable_code, clippy::needless_question_mark)]
                 fn try_from(value: MonsterRef<'a>) -> ::planus::Result<Self> {
                     ::core::result::Result::Ok(Self {
-                        pos: value.pos()?.map(::core::convert::Into::into),
-                        mana: ::core::convert::TryInto::try_into(value.mana()?)?,
-                        hp: ::core::convert::TryInto::try_into(value.hp()?)?,
-                        name: value.name()?.map(::core::convert::Into::into),
-                        inventory: value.inventory()?.map(|v| v.to_vec()),
-                        color: ::core::convert::TryInto::try_into(value.color()?)?,
-                        weapons: if let ::core::option::Option::Some(weapons) = value.weapons()? {
-                            ::core::option::Option::Some(weapons.to_vec_result()?)
-                        } else {
-                            ::core::option::Option::None
-                        },
-                        equipped: if let ::core::option::Option::Some(equipped) =
-                            value.equipped()?
-                        {
-                            ::core::option::Option::Some(::core::convert::TryInto::try_into(
-                                equipped,
+                        pos: ::planus::errors::in_field("pos", || {
+                            ::core::result::Result::Ok(
+                                value.pos()?.map(::core::convert::Into::into),
+                            )
+                        })?,
+                        mana: ::planus::errors::in_field("mana", || {
+                            ::core::result::Result::Ok(::core::convert::TryInto::try_into(
+                                value.mana()?,
                             )?)
-                        } else {
-                            ::core::option::Option::None
-                        },
-                        drops: if let ::core::option::Option::Some(drops) = value.drops()? {
-                            ::core::option::Option::Some(drops.to_vec()?)
-                        } else {
-                            ::core::option::Option::None
-                        },
-                        path: if let ::core::option::Option::Some(path) = value.path()? {
-                            ::core::option::Option::Some(path.to_vec()?)
-                        } else {
-                            ::core::option::Option::None
-                        },
+                        })?,
+                        hp: ::planus::errors::in_field("hp", || {
+                            ::core::result::Result::Ok(::core::convert::TryInto::try_into(
+                                value.hp()?,
+                            )?)
+                        })?,
+                        name: ::planus::errors::in_field("name", || {
+                            ::core::result::Result::Ok(
+                                value.name()?.map(::core::convert::Into::into),
+                            )
+                        })?,
+                        inventory: ::planus::errors::in_field("inventory", || {
+                            ::core::result::Result::Ok(value.inventory()?.map(|v| v.to_vec()))
+                        })?,
+                        color: ::planus::errors::in_field("color", || {
+                            ::core::result::Result::Ok(::core::convert::TryInto::try_into(
+                                value.color()?,
+                            )?)
+                        })?,
+                        weapons: ::planus::errors::in_field("weapons", || {
+                            ::core::result::Result::Ok(
+                                if let ::core::option::Option::Some(weapons) = value.weapons()? {
+                                    ::core::option::Option::Some(weapons.to_vec_result()?)
+                                } else {
+                                    ::core::option::Option::None
+                                },
+                            )
+                        })?,
+                        equipped: ::planus::errors::in_field("equipped", || {
+                            ::core::result::Result::Ok(
+                                if let ::core::option::Option::Some(equipped) = value.equipped()? {
+                                    ::core::option::Option::Some(
+                                        ::core::convert::TryInto::try_into(equipped)?,
+                                    )
+                                } else {
+                                    ::core::option::Option::None
+                                },
+                            )
+                        })?,
+                        drops: ::planus::errors::in_field("drops", || {
+                            ::core::result::Result::Ok(
+                                if let ::core::option::Option::Some(drops) = value.drops()? {
+                                    ::core::option::Option::Some(drops.to_vec()?)
+                                } else {
+                                    ::core::option::Option::None
+                                },
+                            )
+                        })?,
+                        path: ::planus::errors::in_field("path", || {
+                            ::core::result::Result::Ok(
+                                if let ::core::option::Option::Some(path) = value.path()? {
+                                    ::core::option::Option::Some(path.to_vec()?)
+                                } else {
+                                    ::core::option::Option::None
+                                },
+                            )
+                        })?,
                     })
                 }
             }
 
+            impl<'a> ::planus::TryFromBudgeted<MonsterRef<'a>> for Monster {
+                #[allow(unreachable_code, clippy::needless_question_mark)]
+                fn try_from_budgeted(
+                    value: MonsterRef<'a>,
+                    budget: &mut ::planus::ConversionBudget,
+                ) -> ::planus::Result<Self> {
+                    budget.in_table(
+                        <MonsterRef<'a> as ::planus::ReadAsRoot<'a>>::TYPE_NAME,
+                        value.0.offset(),
+                        |budget| {
+                            ::core::result::Result::Ok(Self {
+                                pos: ::planus::errors::in_field("pos", || {
+                                    ::core::result::Result::Ok(
+                                        ::planus::TryFromBudgeted::try_from_budgeted(
+                                            value.pos()?,
+                                            budget,
+                                        )?,
+                                    )
+                                })?,
+                                mana: ::planus::errors::in_field("mana", || {
+                                    ::core::result::Result::Ok(
+                                        ::planus::TryFromBudgeted::try_from_budgeted(
+                                            value.mana()?,
+                                            budget,
+                                        )?,
+                                    )
+                                })?,
+                                hp: ::planus::errors::in_field("hp", || {
+                                    ::core::result::Result::Ok(
+                                        ::planus::TryFromBudgeted::try_from_budgeted(
+                                            value.hp()?,
+                                            budget,
+                                        )?,
+                                    )
+                                })?,
+                                name: ::planus::errors::in_field("name", || {
+                                    ::core::result::Result::Ok(
+                                        ::planus::TryFromBudgeted::try_from_budgeted(
+                                            value.name()?,
+                                            budget,
+                                        )?,
+                                    )
+                                })?,
+                                inventory: ::planus::errors::in_field("inventory", || {
+                                    ::core::result::Result::Ok(
+                                        ::planus::TryFromBudgeted::try_from_budgeted(
+                                            value.inventory()?,
+                                            budget,
+                                        )?,
+                                    )
+                                })?,
+                                color: ::planus::errors::in_field("color", || {
+                                    ::core::result::Result::Ok(
+                                        ::planus::TryFromBudgeted::try_from_budgeted(
+                                            value.color()?,
+                                            budget,
+                                        )?,
+                                    )
+                                })?,
+                                weapons: ::planus::errors::in_field("weapons", || {
+                                    ::core::result::Result::Ok(
+                                        ::planus::TryFromBudgeted::try_from_budgeted(
+                                            value.weapons()?,
+                                            budget,
+                                        )?,
+                                    )
+                                })?,
+                                equipped: ::planus::errors::in_field("equipped", || {
+                                    ::core::result::Result::Ok(
+                                        ::planus::TryFromBudgeted::try_from_budgeted(
+                                            value.equipped()?,
+                                            budget,
+                                        )?,
+                                    )
+                                })?,
+                                drops: ::planus::errors::in_field("drops", || {
+                                    ::core::result::Result::Ok(
+                                        ::planus::TryFromBudgeted::try_from_budgeted(
+                                            value.drops()?,
+                                            budget,
+                                        )?,
+                                    )
+                                })?,
+                                path: ::planus::errors::in_field("path", || {
+                                    ::core::result::Result::Ok(
+                                        ::planus::TryFromBudgeted::try_from_budgeted(
+                                            value.path()?,
+                                            budget,
+                                        )?,
+                                    )
+                                })?,
+                            })
+                        },
+                    )
+                }
+            }
+
             impl<'a> ::planus::TableRead<'a> for MonsterRef<'a> {
                 #[inline]
                 fn from_buffer(
@@ -1322,6 +1831,35 @@ mod root {
                         buffer, offset,
                     )?))
                 }
+
+                #[inline]
+                unsafe fn from_buffer_unchecked(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> Self {
+                    Self(unsafe {
+                        ::planus::table_reader::Table::from_buffer_unchecked(buffer, offset)
+                    })
+                }
+            }
+
+            impl<'a> ::planus::Verify for MonsterRef<'a> {
+                #[allow(unused_variables)]
+                fn verify(&self, verifier: &mut ::planus::Verifier) -> ::planus::Result<()> {
+                    verifier.verify_table(&self.0, "Monster", |verifier| {
+                        ::planus::Verify::verify(&self.pos()?, verifier)?;
+                        ::planus::Verify::verify(&self.mana()?, verifier)?;
+                        ::planus::Verify::verify(&self.hp()?, verifier)?;
+                        ::planus::Verify::verify(&self.name()?, verifier)?;
+                        ::planus::Verify::verify(&self.inventory()?, verifier)?;
+                        ::planus::Verify::verify(&self.color()?, verifier)?;
+                        ::planus::Verify::verify(&self.weapons()?, verifier)?;
+                        ::planus::Verify::verify(&self.equipped()?, verifier)?;
+                        ::planus::Verify::verify(&self.drops()?, verifier)?;
+                        ::planus::Verify::verify(&self.path()?, verifier)?;
+                        ::core::result::Result::Ok(())
+                    })
+                }
             }
 
             impl<'a> ::planus::VectorReadInner<'a> for MonsterRef<'a> {
@@ -1349,7 +1887,7 @@ mod root {
                 type Value = ::planus::Offset<Monster>;
                 const STRIDE: usize = 4;
                 #[inline]
-                fn prepare(&self, builder: &mut ::planus::Builder) -> Self::Value {
+                fn prepare(&self, builder: &mut ::planus::BufferBuilder<'_>) -> Self::Value {
                     ::planus::WriteAs::prepare(self, builder)
                 }
 
@@ -1357,20 +1895,150 @@ mod root {
                 unsafe fn write_values(
                     values: &[::planus::Offset<Monster>],
                     bytes: *mut ::core::mem::MaybeUninit<u8>,
-                    buffer_position: u32,
+                    buffer_position: u64,
                 ) {
                     let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 4];
                     for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
                         ::planus::WriteAsPrimitive::write(
                             v,
                             ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
-                            buffer_position - (Self::STRIDE * i) as u32,
+                            buffer_position - (Self::STRIDE * i) as u64,
                         );
                     }
                 }
             }
 
+            impl<'a> ::planus::WriteAs<::planus::Offset<Monster>> for MonsterRef<'a> {
+                type Prepared = ::planus::Offset<Monster>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Monster> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl<'a> ::planus::WriteAsOptional<::planus::Offset<Monster>> for MonsterRef<'a> {
+                type Prepared = ::planus::Offset<Monster>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::core::option::Option<::planus::Offset<Monster>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl<'a> ::planus::WriteAsOffset<Monster> for MonsterRef<'a> {
+                #[allow(unreachable_code)]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Monster> {
+                    let fields = (
+                        match self.pos() {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(error) => {
+                                return builder.copy_failed(error)
+                            }
+                        },
+                        match self.mana() {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(error) => {
+                                return builder.copy_failed(error)
+                            }
+                        },
+                        match self.hp() {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(error) => {
+                                return builder.copy_failed(error)
+                            }
+                        },
+                        match self.name() {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(error) => {
+                                return builder.copy_failed(error)
+                            }
+                        },
+                        match self.inventory() {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(error) => {
+                                return builder.copy_failed(error)
+                            }
+                        },
+                        match self.color() {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(error) => {
+                                return builder.copy_failed(error)
+                            }
+                        },
+                        match self.weapons() {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(error) => {
+                                return builder.copy_failed(error)
+                            }
+                        },
+                        match self.equipped() {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(error) => {
+                                return builder.copy_failed(error)
+                            }
+                        },
+                        match self.drops() {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(error) => {
+                                return builder.copy_failed(error)
+                            }
+                        },
+                        match self.path() {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(error) => {
+                                return builder.copy_failed(error)
+                            }
+                        },
+                    );
+                    Monster::create(
+                        builder, fields.0, fields.1, fields.2, fields.3, fields.4, fields.5,
+                        fields.6, fields.7, fields.8, fields.9,
+                    )
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl<'a> ::planus::VectorWrite<::planus::Offset<Monster>> for MonsterRef<'a> {
+                type Value = ::planus::Offset<Monster>;
+                const STRIDE: usize = 4;
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::BufferBuilder<'_>) -> Self::Value {
+                    ::planus::WriteAs::prepare(self, builder)
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[::planus::Offset<Monster>],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u64,
+                ) {
+                    unsafe {
+                        <::planus::Offset<Monster> as ::planus::VectorWrite<
+                            ::planus::Offset<Monster>,
+                        >>::write_values(values, bytes, buffer_position);
+                    }
+                }
+            }
+
+            impl ::planus::HasRef for Monster {
+                type Ref<'a> = MonsterRef<'a>;
+            }
+
             impl<'a> ::planus::ReadAsRoot<'a> for MonsterRef<'a> {
+                const TYPE_NAME: &'static str = "[MonsterRef]";
+
                 fn read_as_root(slice: &'a [u8]) -> ::planus::Result<Self> {
                     ::planus::TableRead::from_buffer(
                         ::planus::SliceWithStartOffset {
@@ -1380,9 +2048,80 @@ mod root {
                         0,
                     )
                     .map_err(|error_kind| {
-                        error_kind.with_error_location("[MonsterRef]", "read_as_root", 0)
+                        error_kind.with_error_location(Self::TYPE_NAME, "read_as_root", 0)
+                    })
+                }
+            }
+
+            /// Mutable view of a serialized [Monster], for changing the values
+            /// of its scalar and struct fields in place.
+            ///
+            /// Only fields that are present in the buffer can be changed, since adding a
+            /// field would change the layout of the buffer.
+            pub struct MonsterMut<'a>(::planus::table_mutator::TableMut<'a>);
+
+            impl<'a> MonsterMut<'a> {
+                /// Takes a buffer with this table as its root and returns a mutable
+                /// view into it.
+                pub fn read_as_root(buffer: &'a mut [u8]) -> ::planus::Result<Self> {
+                    ::planus::table_mutator::TableMut::from_buffer(buffer, 0)
+                        .map(Self)
+                        .map_err(|error_kind| {
+                            error_kind.with_error_location("[MonsterMut]", "read_as_root", 0)
+                        })
+                }
+
+                /// Returns a read-only view of the table.
+                pub fn to_ref(&self) -> ::planus::Result<MonsterRef<'_>> {
+                    self.0.as_table().map(MonsterRef).map_err(|error_kind| {
+                        error_kind.with_error_location("[MonsterMut]", "to_ref", 0)
                     })
                 }
+
+                /// Sets the value of the [`pos` field](Monster#structfield.pos) in place.
+                ///
+                /// Returns an error if the field is not present in the buffer.
+                #[inline]
+                pub fn set_pos(&mut self, value: self::Vec3) -> ::planus::Result<()> {
+                    self.0
+                        .set::<self::Vec3, 12>(0, &value, "Monster", "set_pos")
+                }
+
+                /// Sets the value of the [`mana` field](Monster#structfield.mana) in place.
+                ///
+                /// Returns an error if the field is not present in the buffer.
+                #[inline]
+                pub fn set_mana(&mut self, value: i16) -> ::planus::Result<()> {
+                    self.0.set::<i16, 2>(1, &value, "Monster", "set_mana")
+                }
+
+                /// Sets the value of the [`hp` field](Monster#structfield.hp) in place.
+                ///
+                /// Returns an error if the field is not present in the buffer.
+                #[inline]
+                pub fn set_hp(&mut self, value: i16) -> ::planus::Result<()> {
+                    self.0.set::<i16, 2>(2, &value, "Monster", "set_hp")
+                }
+
+                /// Sets the value of the [`color` field](Monster#structfield.color) in place.
+                ///
+                /// Returns an error if the field is not present in the buffer.
+                #[inline]
+                pub fn set_color(&mut self, value: self::Color) -> ::planus::Result<()> {
+                    self.0
+                        .set::<self::Color, 1>(6, &value, "Monster", "set_color")
+                }
+            }
+
+            impl<'a> ::core::fmt::Debug for MonsterMut<'a> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self.to_ref() {
+                        ::core::result::Result::Ok(value) => value.fmt(f),
+                        ::core::result::Result::Err(error) => {
+                            f.debug_tuple("MonsterMut").field(&error).finish()
+                        }
+                    }
+                }
             }
 
             ///  A weapon is equipment that can be used for attacking
@@ -1426,7 +2165,7 @@ mod root {
 
                 #[allow(clippy::too_many_arguments)]
                 pub fn create(
-                    builder: &mut ::planus::Builder,
+                    builder: &mut ::planus::BufferBuilder<'_>,
                     field_name: impl ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
                     field_damage: impl ::planus::WriteAsDefault<i16, i16>,
                 ) -> ::planus::Offset<Self> {
@@ -1460,7 +2199,10 @@ mod root {
                 type Prepared = ::planus::Offset<Self>;
 
                 #[inline]
-                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Weapon> {
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Weapon> {
                     ::planus::WriteAsOffset::prepare(self, builder)
                 }
             }
@@ -1471,7 +2213,7 @@ mod root {
                 #[inline]
                 fn prepare(
                     &self,
-                    builder: &mut ::planus::Builder,
+                    builder: &mut ::planus::BufferBuilder<'_>,
                 ) -> ::core::option::Option<::planus::Offset<Weapon>> {
                     ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
                 }
@@ -1479,7 +2221,10 @@ mod root {
 
             impl ::planus::WriteAsOffset<Weapon> for Weapon {
                 #[inline]
-                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Weapon> {
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Weapon> {
                     Weapon::create(builder, &self.name, self.damage)
                 }
             }
@@ -1533,7 +2278,10 @@ mod root {
             impl<T0, T1> WeaponBuilder<(T0, T1)> {
                 /// Finish writing the builder to get an [Offset](::planus::Offset) to a serialized [Weapon].
                 #[inline]
-                pub fn finish(self, builder: &mut ::planus::Builder) -> ::planus::Offset<Weapon>
+                pub fn finish(
+                    self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Weapon>
                 where
                     Self: ::planus::WriteAsOffset<Weapon>,
                 {
@@ -1549,7 +2297,10 @@ mod root {
                 type Prepared = ::planus::Offset<Weapon>;
 
                 #[inline]
-                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Weapon> {
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Weapon> {
                     ::planus::WriteAsOffset::prepare(self, builder)
                 }
             }
@@ -1564,7 +2315,7 @@ mod root {
                 #[inline]
                 fn prepare(
                     &self,
-                    builder: &mut ::planus::Builder,
+                    builder: &mut ::planus::BufferBuilder<'_>,
                 ) -> ::core::option::Option<::planus::Offset<Weapon>> {
                     ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
                 }
@@ -1576,7 +2327,10 @@ mod root {
                 > ::planus::WriteAsOffset<Weapon> for WeaponBuilder<(T0, T1)>
             {
                 #[inline]
-                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Weapon> {
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Weapon> {
                     let (v0, v1) = &self.0;
                     Weapon::create(builder, v0, v1)
                 }
@@ -1596,11 +2350,39 @@ mod root {
                     self.0.access(0, "Weapon", "name")
                 }
 
+                /// Getter for the `name` field without error checking.
+                ///
+                /// # Safety
+                ///
+                /// The buffer must have been verified, for instance by reading it with
+                /// [`read_as_root_verified`](::planus::ReadAsRoot::read_as_root_verified).
+                /// Calling this on a buffer where [`name`](Self::name)
+                /// would return an error is undefined behavior.
+                #[inline]
+                pub unsafe fn name_unchecked(
+                    &self,
+                ) -> ::core::option::Option<&'a ::core::primitive::str> {
+                    unsafe { self.0.access_unchecked(0) }
+                }
+
                 /// Getter for the [`damage` field](Weapon#structfield.damage).
                 #[inline]
                 pub fn damage(&self) -> ::planus::Result<i16> {
                     ::core::result::Result::Ok(self.0.access(1, "Weapon", "damage")?.unwrap_or(0))
                 }
+
+                /// Getter for the `damage` field without error checking.
+                ///
+                /// # Safety
+                ///
+                /// The buffer must have been verified, for instance by reading it with
+                /// [`read_as_root_verified`](::planus::ReadAsRoot::read_as_root_verified).
+                /// Calling this on a buffer where [`damage`](Self::damage)
+                /// would return an error is undefined behavior.
+                #[inline]
+                pub unsafe fn damage_unchecked(&self) -> i16 {
+                    unsafe { self.0.access_unchecked(1) }.unwrap_or(0)
+                }
             }
 
             impl<'a> ::core::fmt::Debug for WeaponRef<'a> {
@@ -1617,15 +2399,56 @@ mod root {
             impl<'a> ::core::convert::TryFrom<WeaponRef<'a>> for Weapon {
                 type Error = ::planus::Error;
 
-                #[allow(unreachable_code)]
+                #[allow(unreachable_code, clippy::needless_question_mark)]
                 fn try_from(value: WeaponRef<'a>) -> ::planus::Result<Self> {
                     ::core::result::Result::Ok(Self {
-                        name: value.name()?.map(::core::convert::Into::into),
-                        damage: ::core::convert::TryInto::try_into(value.damage()?)?,
+                        name: ::planus::errors::in_field("name", || {
+                            ::core::result::Result::Ok(
+                                value.name()?.map(::core::convert::Into::into),
+                            )
+                        })?,
+                        damage: ::planus::errors::in_field("damage", || {
+                            ::core::result::Result::Ok(::core::convert::TryInto::try_into(
+                                value.damage()?,
+                            )?)
+                        })?,
                     })
                 }
             }
 
+            impl<'a> ::planus::TryFromBudgeted<WeaponRef<'a>> for Weapon {
+                #[allow(unreachable_code, clippy::needless_question_mark)]
+                fn try_from_budgeted(
+                    value: WeaponRef<'a>,
+                    budget: &mut ::planus::ConversionBudget,
+                ) -> ::planus::Result<Self> {
+                    budget.in_table(
+                        <WeaponRef<'a> as ::planus::ReadAsRoot<'a>>::TYPE_NAME,
+                        value.0.offset(),
+                        |budget| {
+                            ::core::result::Result::Ok(Self {
+                                name: ::planus::errors::in_field("name", || {
+                                    ::core::result::Result::Ok(
+                                        ::planus::TryFromBudgeted::try_from_budgeted(
+                                            value.name()?,
+                                            budget,
+                                        )?,
+                                    )
+                                })?,
+                                damage: ::planus::errors::in_field("damage", || {
+                                    ::core::result::Result::Ok(
+                                        ::planus::TryFromBudgeted::try_from_budgeted(
+                                            value.damage()?,
+                                            budget,
+                                        )?,
+                                    )
+                                })?,
+                            })
+                        },
+                    )
+                }
+            }
+
             impl<'a> ::planus::TableRead<'a> for WeaponRef<'a> {
                 #[inline]
                 fn from_buffer(
@@ -1636,6 +2459,27 @@ mod root {
                         buffer, offset,
                     )?))
                 }
+
+                #[inline]
+                unsafe fn from_buffer_unchecked(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> Self {
+                    Self(unsafe {
+                        ::planus::table_reader::Table::from_buffer_unchecked(buffer, offset)
+                    })
+                }
+            }
+
+            impl<'a> ::planus::Verify for WeaponRef<'a> {
+                #[allow(unused_variables)]
+                fn verify(&self, verifier: &mut ::planus::Verifier) -> ::planus::Result<()> {
+                    verifier.verify_table(&self.0, "Weapon", |verifier| {
+                        ::planus::Verify::verify(&self.name()?, verifier)?;
+                        ::planus::Verify::verify(&self.damage()?, verifier)?;
+                        ::core::result::Result::Ok(())
+                    })
+                }
             }
 
             impl<'a> ::planus::VectorReadInner<'a> for WeaponRef<'a> {
@@ -1663,7 +2507,7 @@ mod root {
                 type Value = ::planus::Offset<Weapon>;
                 const STRIDE: usize = 4;
                 #[inline]
-                fn prepare(&self, builder: &mut ::planus::Builder) -> Self::Value {
+                fn prepare(&self, builder: &mut ::planus::BufferBuilder<'_>) -> Self::Value {
                     ::planus::WriteAs::prepare(self, builder)
                 }
 
@@ -1671,20 +2515,99 @@ mod root {
                 unsafe fn write_values(
                     values: &[::planus::Offset<Weapon>],
                     bytes: *mut ::core::mem::MaybeUninit<u8>,
-                    buffer_position: u32,
+                    buffer_position: u64,
                 ) {
                     let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 4];
                     for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
                         ::planus::WriteAsPrimitive::write(
                             v,
                             ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
-                            buffer_position - (Self::STRIDE * i) as u32,
+                            buffer_position - (Self::STRIDE * i) as u64,
                         );
                     }
                 }
             }
 
+            impl<'a> ::planus::WriteAs<::planus::Offset<Weapon>> for WeaponRef<'a> {
+                type Prepared = ::planus::Offset<Weapon>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Weapon> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl<'a> ::planus::WriteAsOptional<::planus::Offset<Weapon>> for WeaponRef<'a> {
+                type Prepared = ::planus::Offset<Weapon>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::core::option::Option<::planus::Offset<Weapon>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl<'a> ::planus::WriteAsOffset<Weapon> for WeaponRef<'a> {
+                #[allow(unreachable_code)]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Weapon> {
+                    let fields = (
+                        match self.name() {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(error) => {
+                                return builder.copy_failed(error)
+                            }
+                        },
+                        match self.damage() {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(error) => {
+                                return builder.copy_failed(error)
+                            }
+                        },
+                    );
+                    Weapon::create(builder, fields.0, fields.1)
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl<'a> ::planus::VectorWrite<::planus::Offset<Weapon>> for WeaponRef<'a> {
+                type Value = ::planus::Offset<Weapon>;
+                const STRIDE: usize = 4;
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::BufferBuilder<'_>) -> Self::Value {
+                    ::planus::WriteAs::prepare(self, builder)
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[::planus::Offset<Weapon>],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u64,
+                ) {
+                    unsafe {
+                        <::planus::Offset<Weapon> as ::planus::VectorWrite<
+                            ::planus::Offset<Weapon>,
+                        >>::write_values(values, bytes, buffer_position);
+                    }
+                }
+            }
+
+            impl ::planus::HasRef for Weapon {
+                type Ref<'a> = WeaponRef<'a>;
+            }
+
             impl<'a> ::planus::ReadAsRoot<'a> for WeaponRef<'a> {
+                const TYPE_NAME: &'static str = "[WeaponRef]";
+
                 fn read_as_root(slice: &'a [u8]) -> ::planus::Result<Self> {
                     ::planus::TableRead::from_buffer(
                         ::planus::SliceWithStartOffset {
@@ -1694,9 +2617,54 @@ mod root {
                         0,
                     )
                     .map_err(|error_kind| {
-                        error_kind.with_error_location("[WeaponRef]", "read_as_root", 0)
+                        error_kind.with_error_location(Self::TYPE_NAME, "read_as_root", 0)
+                    })
+                }
+            }
+
+            /// Mutable view of a serialized [Weapon], for changing the values
+            /// of its scalar and struct fields in place.
+            ///
+            /// Only fields that are present in the buffer can be changed, since adding a
+            /// field would change the layout of the buffer.
+            pub struct WeaponMut<'a>(::planus::table_mutator::TableMut<'a>);
+
+            impl<'a> WeaponMut<'a> {
+                /// Takes a buffer with this table as its root and returns a mutable
+                /// view into it.
+                pub fn read_as_root(buffer: &'a mut [u8]) -> ::planus::Result<Self> {
+                    ::planus::table_mutator::TableMut::from_buffer(buffer, 0)
+                        .map(Self)
+                        .map_err(|error_kind| {
+                            error_kind.with_error_location("[WeaponMut]", "read_as_root", 0)
+                        })
+                }
+
+                /// Returns a read-only view of the table.
+                pub fn to_ref(&self) -> ::planus::Result<WeaponRef<'_>> {
+                    self.0.as_table().map(WeaponRef).map_err(|error_kind| {
+                        error_kind.with_error_location("[WeaponMut]", "to_ref", 0)
                     })
                 }
+
+                /// Sets the value of the [`damage` field](Weapon#structfield.damage) in place.
+                ///
+                /// Returns an error if the field is not present in the buffer.
+                #[inline]
+                pub fn set_damage(&mut self, value: i16) -> ::planus::Result<()> {
+                    self.0.set::<i16, 2>(1, &value, "Weapon", "set_damage")
+                }
+            }
+
+            impl<'a> ::core::fmt::Debug for WeaponMut<'a> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self.to_ref() {
+                        ::core::result::Result::Ok(value) => value.fmt(f),
+                        ::core::result::Result::Err(error) => {
+                            f.debug_tuple("WeaponMut").field(&error).finish()
+                        }
+                    }
+                }
             }
 
             ///  A shield is equipment that can be used for defending
@@ -1740,7 +2708,7 @@ mod root {
 
                 #[allow(clippy::too_many_arguments)]
                 pub fn create(
-                    builder: &mut ::planus::Builder,
+                    builder: &mut ::planus::BufferBuilder<'_>,
                     field_name: impl ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
                     field_armor: impl ::planus::WriteAsDefault<i16, i16>,
                 ) -> ::planus::Offset<Self> {
@@ -1774,7 +2742,10 @@ mod root {
                 type Prepared = ::planus::Offset<Self>;
 
                 #[inline]
-                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Shield> {
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Shield> {
                     ::planus::WriteAsOffset::prepare(self, builder)
                 }
             }
@@ -1785,7 +2756,7 @@ mod root {
                 #[inline]
                 fn prepare(
                     &self,
-                    builder: &mut ::planus::Builder,
+                    builder: &mut ::planus::BufferBuilder<'_>,
                 ) -> ::core::option::Option<::planus::Offset<Shield>> {
                     ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
                 }
@@ -1793,7 +2764,10 @@ mod root {
 
             impl ::planus::WriteAsOffset<Shield> for Shield {
                 #[inline]
-                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Shield> {
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Shield> {
                     Shield::create(builder, &self.name, self.armor)
                 }
             }
@@ -1847,7 +2821,10 @@ mod root {
             impl<T0, T1> ShieldBuilder<(T0, T1)> {
                 /// Finish writing the builder to get an [Offset](::planus::Offset) to a serialized [Shield].
                 #[inline]
-                pub fn finish(self, builder: &mut ::planus::Builder) -> ::planus::Offset<Shield>
+                pub fn finish(
+                    self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Shield>
                 where
                     Self: ::planus::WriteAsOffset<Shield>,
                 {
@@ -1863,7 +2840,10 @@ mod root {
                 type Prepared = ::planus::Offset<Shield>;
 
                 #[inline]
-                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Shield> {
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Shield> {
                     ::planus::WriteAsOffset::prepare(self, builder)
                 }
             }
@@ -1878,7 +2858,7 @@ mod root {
                 #[inline]
                 fn prepare(
                     &self,
-                    builder: &mut ::planus::Builder,
+                    builder: &mut ::planus::BufferBuilder<'_>,
                 ) -> ::core::option::Option<::planus::Offset<Shield>> {
                     ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
                 }
@@ -1890,7 +2870,10 @@ mod root {
                 > ::planus::WriteAsOffset<Shield> for ShieldBuilder<(T0, T1)>
             {
                 #[inline]
-                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Shield> {
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Shield> {
                     let (v0, v1) = &self.0;
                     Shield::create(builder, v0, v1)
                 }
@@ -1910,11 +2893,39 @@ mod root {
                     self.0.access(0, "Shield", "name")
                 }
 
+                /// Getter for the `name` field without error checking.
+                ///
+                /// # Safety
+                ///
+                /// The buffer must have been verified, for instance by reading it with
+                /// [`read_as_root_verified`](::planus::ReadAsRoot::read_as_root_verified).
+                /// Calling this on a buffer where [`name`](Self::name)
+                /// would return an error is undefined behavior.
+                #[inline]
+                pub unsafe fn name_unchecked(
+                    &self,
+                ) -> ::core::option::Option<&'a ::core::primitive::str> {
+                    unsafe { self.0.access_unchecked(0) }
+                }
+
                 /// Getter for the [`armor` field](Shield#structfield.armor).
                 #[inline]
                 pub fn armor(&self) -> ::planus::Result<i16> {
                     ::core::result::Result::Ok(self.0.access(1, "Shield", "armor")?.unwrap_or(0))
                 }
+
+                /// Getter for the `armor` field without error checking.
+                ///
+                /// # Safety
+                ///
+                /// The buffer must have been verified, for instance by reading it with
+                /// [`read_as_root_verified`](::planus::ReadAsRoot::read_as_root_verified).
+                /// Calling this on a buffer where [`armor`](Self::armor)
+                /// would return an error is undefined behavior.
+                #[inline]
+                pub unsafe fn armor_unchecked(&self) -> i16 {
+                    unsafe { self.0.access_unchecked(1) }.unwrap_or(0)
+                }
             }
 
             impl<'a> ::core::fmt::Debug for ShieldRef<'a> {
@@ -1931,15 +2942,56 @@ mod root {
             impl<'a> ::core::convert::TryFrom<ShieldRef<'a>> for Shield {
                 type Error = ::planus::Error;
 
-                #[allow(unreachable_code)]
+                #[allow(unreachable_code, clippy::needless_question_mark)]
                 fn try_from(value: ShieldRef<'a>) -> ::planus::Result<Self> {
                     ::core::result::Result::Ok(Self {
-                        name: value.name()?.map(::core::convert::Into::into),
-                        armor: ::core::convert::TryInto::try_into(value.armor()?)?,
+                        name: ::planus::errors::in_field("name", || {
+                            ::core::result::Result::Ok(
+                                value.name()?.map(::core::convert::Into::into),
+                            )
+                        })?,
+                        armor: ::planus::errors::in_field("armor", || {
+                            ::core::result::Result::Ok(::core::convert::TryInto::try_into(
+                                value.armor()?,
+                            )?)
+                        })?,
                     })
                 }
             }
 
+            impl<'a> ::planus::TryFromBudgeted<ShieldRef<'a>> for Shield {
+                #[allow(unreachable_code, clippy::needless_question_mark)]
+                fn try_from_budgeted(
+                    value: ShieldRef<'a>,
+                    budget: &mut ::planus::ConversionBudget,
+                ) -> ::planus::Result<Self> {
+                    budget.in_table(
+                        <ShieldRef<'a> as ::planus::ReadAsRoot<'a>>::TYPE_NAME,
+                        value.0.offset(),
+                        |budget| {
+                            ::core::result::Result::Ok(Self {
+                                name: ::planus::errors::in_field("name", || {
+                                    ::core::result::Result::Ok(
+                                        ::planus::TryFromBudgeted::try_from_budgeted(
+                                            value.name()?,
+                                            budget,
+                                        )?,
+                                    )
+                                })?,
+                                armor: ::planus::errors::in_field("armor", || {
+                                    ::core::result::Result::Ok(
+                                        ::planus::TryFromBudgeted::try_from_budgeted(
+                                            value.armor()?,
+                                            budget,
+                                        )?,
+                                    )
+                                })?,
+                            })
+                        },
+                    )
+                }
+            }
+
             impl<'a> ::planus::TableRead<'a> for ShieldRef<'a> {
                 #[inline]
                 fn from_buffer(
@@ -1950,6 +3002,27 @@ mod root {
                         buffer, offset,
                     )?))
                 }
+
+                #[inline]
+                unsafe fn from_buffer_unchecked(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> Self {
+                    Self(unsafe {
+                        ::planus::table_reader::Table::from_buffer_unchecked(buffer, offset)
+                    })
+                }
+            }
+
+            impl<'a> ::planus::Verify for ShieldRef<'a> {
+                #[allow(unused_variables)]
+                fn verify(&self, verifier: &mut ::planus::Verifier) -> ::planus::Result<()> {
+                    verifier.verify_table(&self.0, "Shield", |verifier| {
+                        ::planus::Verify::verify(&self.name()?, verifier)?;
+                        ::planus::Verify::verify(&self.armor()?, verifier)?;
+                        ::core::result::Result::Ok(())
+                    })
+                }
             }
 
             impl<'a> ::planus::VectorReadInner<'a> for ShieldRef<'a> {
@@ -1977,7 +3050,7 @@ mod root {
                 type Value = ::planus::Offset<Shield>;
                 const STRIDE: usize = 4;
                 #[inline]
-                fn prepare(&self, builder: &mut ::planus::Builder) -> Self::Value {
+                fn prepare(&self, builder: &mut ::planus::BufferBuilder<'_>) -> Self::Value {
                     ::planus::WriteAs::prepare(self, builder)
                 }
 
@@ -1985,20 +3058,99 @@ mod root {
                 unsafe fn write_values(
                     values: &[::planus::Offset<Shield>],
                     bytes: *mut ::core::mem::MaybeUninit<u8>,
-                    buffer_position: u32,
+                    buffer_position: u64,
                 ) {
                     let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 4];
                     for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
                         ::planus::WriteAsPrimitive::write(
                             v,
                             ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
-                            buffer_position - (Self::STRIDE * i) as u32,
+                            buffer_position - (Self::STRIDE * i) as u64,
                         );
                     }
                 }
             }
 
+            impl<'a> ::planus::WriteAs<::planus::Offset<Shield>> for ShieldRef<'a> {
+                type Prepared = ::planus::Offset<Shield>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Shield> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl<'a> ::planus::WriteAsOptional<::planus::Offset<Shield>> for ShieldRef<'a> {
+                type Prepared = ::planus::Offset<Shield>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::core::option::Option<::planus::Offset<Shield>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl<'a> ::planus::WriteAsOffset<Shield> for ShieldRef<'a> {
+                #[allow(unreachable_code)]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::BufferBuilder<'_>,
+                ) -> ::planus::Offset<Shield> {
+                    let fields = (
+                        match self.name() {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(error) => {
+                                return builder.copy_failed(error)
+                            }
+                        },
+                        match self.armor() {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(error) => {
+                                return builder.copy_failed(error)
+                            }
+                        },
+                    );
+                    Shield::create(builder, fields.0, fields.1)
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl<'a> ::planus::VectorWrite<::planus::Offset<Shield>> for ShieldRef<'a> {
+                type Value = ::planus::Offset<Shield>;
+                const STRIDE: usize = 4;
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::BufferBuilder<'_>) -> Self::Value {
+                    ::planus::WriteAs::prepare(self, builder)
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[::planus::Offset<Shield>],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u64,
+                ) {
+                    unsafe {
+                        <::planus::Offset<Shield> as ::planus::VectorWrite<
+                            ::planus::Offset<Shield>,
+                        >>::write_values(values, bytes, buffer_position);
+                    }
+                }
+            }
+
+            impl ::planus::HasRef for Shield {
+                type Ref<'a> = ShieldRef<'a>;
+            }
+
             impl<'a> ::planus::ReadAsRoot<'a> for ShieldRef<'a> {
+                const TYPE_NAME: &'static str = "[ShieldRef]";
+
                 fn read_as_root(slice: &'a [u8]) -> ::planus::Result<Self> {
                     ::planus::TableRead::from_buffer(
                         ::planus::SliceWithStartOffset {
@@ -2008,9 +3160,54 @@ mod root {
                         0,
                     )
                     .map_err(|error_kind| {
-                        error_kind.with_error_location("[ShieldRef]", "read_as_root", 0)
+                        error_kind.with_error_location(Self::TYPE_NAME, "read_as_root", 0)
+                    })
+                }
+            }
+
+            /// Mutable view of a serialized [Shield], for changing the values
+            /// of its scalar and struct fields in place.
+            ///
+            /// Only fields that are present in the buffer can be changed, since adding a
+            /// field would change the layout of the buffer.
+            pub struct ShieldMut<'a>(::planus::table_mutator::TableMut<'a>);
+
+            impl<'a> ShieldMut<'a> {
+                /// Takes a buffer with this table as its root and returns a mutable
+                /// view into it.
+                pub fn read_as_root(buffer: &'a mut [u8]) -> ::planus::Result<Self> {
+                    ::planus::table_mutator::TableMut::from_buffer(buffer, 0)
+                        .map(Self)
+                        .map_err(|error_kind| {
+                            error_kind.with_error_location("[ShieldMut]", "read_as_root", 0)
+                        })
+                }
+
+                /// Returns a read-only view of the table.
+                pub fn to_ref(&self) -> ::planus::Result<ShieldRef<'_>> {
+                    self.0.as_table().map(ShieldRef).map_err(|error_kind| {
+                        error_kind.with_error_location("[ShieldMut]", "to_ref", 0)
                     })
                 }
+
+                /// Sets the value of the [`armor` field](Shield#structfield.armor) in place.
+                ///
+                /// Returns an error if the field is not present in the buffer.
+                #[inline]
+                pub fn set_armor(&mut self, value: i16) -> ::planus::Result<()> {
+                    self.0.set::<i16, 2>(1, &value, "Shield", "set_armor")
+                }
+            }
+
+            impl<'a> ::core::fmt::Debug for ShieldMut<'a> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self.to_ref() {
+                        ::core::result::Result::Ok(value) => value.fmt(f),
+                        ::core::result::Result::Err(error) => {
+                            f.debug_tuple("ShieldMut").field(&error).finish()
+                        }
+                    }
+                }
             }
         }
     }
@@ -0,0 +1,63 @@
+use std::{env, fmt::Write, fs};
+
+use color_eyre::{
+    eyre::{bail, eyre, WrapErr},
+    Result,
+};
+
+const SCHEMA_DIRS: [&str; 3] = [
+    "../rust-test-2021/api_files",
+    "../rust-test-2021/test_files",
+    "../rust-test-2021/test_files_no_flatc",
+];
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let mut mod_code = String::new();
+
+    for in_dir in SCHEMA_DIRS {
+        println!("cargo:rerun-if-changed={in_dir}");
+        for entry in fs::read_dir(in_dir).wrap_err_with(|| eyre!("Cannot read dir: {}", in_dir))? {
+            let file_path = entry.wrap_err("Error doing readdir")?.path();
+            if file_path.is_dir() || file_path.extension().is_none_or(|extension| extension != "fbs")
+            {
+                continue;
+            }
+            let file_stem = file_path.file_stem().unwrap().to_str().unwrap();
+
+            let Some(declarations) = planus_translation::translate_files(&[&file_path]) else {
+                bail!("Cannot translate code for {}", file_path.display())
+            };
+            let options = planus_codegen::RustOptions {
+                open_enums: file_stem.starts_with("open_enums"),
+                open_unions: file_stem.starts_with("open_unions"),
+                no_alloc: true,
+            };
+            let code = planus_codegen::generate_rust_with_options(&declarations, true, options)
+                .wrap_err_with(|| eyre!("Cannot codegen for {}", file_path.display()))?;
+
+            // The same schema name can be used in several directories
+            let module_name = format!("{}_{file_stem}", in_dir.rsplit('/').next().unwrap());
+            let generated = format!("{module_name}_planus_generated.rs");
+            let generated_full_path = format!("{out_dir}/{generated}");
+            fs::write(&generated_full_path, code)
+                .wrap_err_with(|| eyre!("Cannot write output to {}", generated_full_path))?;
+
+            writeln!(mod_code, "#[path = {generated:?}]").unwrap();
+            writeln!(
+                mod_code,
+                "#[allow(dead_code, unused_imports, clippy::module_inception)]"
+            )
+            .unwrap();
+            writeln!(mod_code, "pub mod {module_name};").unwrap();
+        }
+    }
+
+    fs::write(format!("{out_dir}/mod.rs"), mod_code).wrap_err("Cannot write the glue code")?;
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+//! Checks that code generated with `--no-alloc` compiles without an
+//! allocator, by generating the schemas of the other test crates with it.
+#![no_std]
+
+include!(concat!(env!("OUT_DIR"), "/mod.rs"));
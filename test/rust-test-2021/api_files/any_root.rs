@@ -0,0 +1,45 @@
+use planus::errors::ErrorKind;
+
+let mut builder = planus::Builder::new();
+let request = rpc::Request::finish_root(
+    &mut builder,
+    rpc::Request {
+        id: 1,
+        method: Some("ping".into()),
+    },
+)
+.to_vec();
+let response = Response::finish_root(
+    &mut builder,
+    Response {
+        id: 1,
+        result: Some("pong".into()),
+    },
+)
+.to_vec();
+
+match AnyRoot::identify(&request).unwrap() {
+    AnyRoot::Request(request) => {
+        assert_eq!(request.id().unwrap(), 1);
+        assert_eq!(request.method().unwrap(), Some("ping"));
+    }
+    other => panic!("unexpected root {:?}", other),
+}
+let response = AnyRoot::identify(&response).unwrap();
+assert_eq!(response.file_identifier(), *b"RES0");
+assert!(matches!(response, AnyRoot::Response(response) if response.result().unwrap() == Some("pong")));
+
+// Root types without a file identifier cannot be identified
+let event = rpc::events::Event::finish_root(
+    &mut builder,
+    rpc::events::Event {
+        name: Some("tick".into()),
+    },
+)
+.to_vec();
+let error = AnyRoot::identify(&event).unwrap_err();
+assert!(matches!(error.error_kind, ErrorKind::UnknownFileIdentifier { .. }));
+assert_eq!(error.source_location.method, "identify");
+
+let error = AnyRoot::identify(&request[..7]).unwrap_err();
+assert!(matches!(error.error_kind, ErrorKind::InvalidLength));
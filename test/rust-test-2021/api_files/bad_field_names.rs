@@ -21,6 +21,8 @@ check_type!(Table => tag : u8);
 check_type!(Table => offset_from_start : u8);
 check_type!(Table => slice : u8);
 check_type!(Table => default : u8);
+check_type!(Table => x : u8);
+check_type!(Table => set_x : u16);
 
 check_type!(+['a] TableRef<'a> => &self.value() : planus::Result<u8>);
 check_type!(+['a] TableRef<'a> => &self.value() : planus::Result<u8>);
@@ -46,6 +48,11 @@ check_type!(+['a] TableRef<'a> => &self.tag() : planus::Result<u8>);
 check_type!(+['a] TableRef<'a> => &self.offset_from_start() : planus::Result<u8>);
 check_type!(+['a] TableRef<'a> => &self.slice() : planus::Result<u8>);
 check_type!(+['a] TableRef<'a> => &self.default() : planus::Result<u8>);
+check_type!(+['a] TableRef<'a> => &self.x() : planus::Result<u8>);
+check_type!(+['a] TableRef<'a> => &self.set_x() : planus::Result<u16>);
+
+check_type!(+['a] TableMut<'a> => set_x(&'a mut TableMut<'a>, u8) : planus::Result<()>);
+check_type!(+['a] TableMut<'a> => set_set_x(&'a mut TableMut<'a>, u16) : planus::Result<()>);
 
 assert_traits!(
     Table: !Copy + Clone + Debug + PartialEq + PartialOrd + Eq + Ord + Hash + Default,
@@ -0,0 +1,39 @@
+use planus::ReadAsRoot;
+
+let mut builder = planus::Builder::new();
+let mut entries = Vec::new();
+entries.push(Entry::create(&mut builder, "first", 1));
+
+// Write an entry with a different alignment and roll it back
+let checkpoint = builder.checkpoint();
+let discarded = Entry::create(&mut builder, "second", 0xffff_ffff_ffff);
+let _ = builder.create_vector([discarded]);
+builder.rollback_to(checkpoint);
+
+// Strings and vtables written after the checkpoint must be written again
+entries.push(Entry::create(&mut builder, "second", 2));
+let log = Log::create(&mut builder, entries);
+let data = builder.finish(log, None).to_vec();
+
+let log = LogRef::read_as_root_verified(&data).unwrap();
+let log = Log::try_from(log).unwrap();
+assert_eq!(
+    log,
+    Log {
+        entries: Some(vec![
+            Entry {
+                name: Some("first".into()),
+                value: 1,
+            },
+            Entry {
+                name: Some("second".into()),
+                value: 2,
+            },
+        ]),
+    }
+);
+
+// The result is the same as if the rolled back entry was never written
+let mut builder = planus::Builder::new();
+let expected = builder.finish(&log, None);
+assert_eq!(data, expected);
@@ -0,0 +1,75 @@
+use planus::{errors::ErrorKind, ConversionBudget, ReadAsRoot, TryFromBudgeted};
+
+let node = Node {
+    name: Some("root".into()),
+    colors: Some(vec![Color::Red, Color::Green]),
+    points: Some(vec![Point { x: 1, y: 2 }]),
+    leaf: Some(Box::new(Leaf {
+        name: Some("leaf".into()),
+        data: Some(vec![1, 2, 3]),
+    })),
+    child: Some(Child::Point(Point { x: 3, y: 4 })),
+    children: Some(vec![Node {
+        name: Some("child".into()),
+        colors: None,
+        points: None,
+        leaf: None,
+        child: Some(Child::Name("name".into())),
+        children: None,
+        mixed: None,
+    }]),
+    mixed: Some(vec![
+        Child::Leaf(Box::new(Leaf {
+            name: None,
+            data: None,
+        })),
+        Child::Name("mixed".into()),
+    ]),
+};
+let mut builder = planus::Builder::new();
+let data = builder.finish(&node, None).to_vec();
+let node_ref = NodeRef::read_as_root(&data).unwrap();
+
+// With a sufficient budget, the result is the same as for `TryFrom`
+let mut budget = ConversionBudget::new(usize::MAX);
+let converted = Node::try_from_budgeted(node_ref, &mut budget).unwrap();
+assert_eq!(converted, node);
+let used = budget.used();
+assert!(used > 0);
+
+let mut budget = ConversionBudget::new(used);
+assert_eq!(Node::try_from_budgeted(node_ref, &mut budget).unwrap(), node);
+assert_eq!(budget.remaining(), 0);
+
+for limit in 0..used {
+    let mut budget = ConversionBudget::new(limit);
+    let error = Node::try_from_budgeted(node_ref, &mut budget).unwrap_err();
+    assert!(
+        matches!(error.error_kind, ErrorKind::AllocationLimitExceeded { limit: l } if l == limit),
+        "{}",
+        error
+    );
+    // Errors are located at the table or vector being converted
+    assert!(error.source_location.byte_offset < data.len(), "{}", error);
+}
+
+// A small buffer sharing the same data many times expands into a much
+// larger owned value
+let mut builder = planus::Builder::new();
+let name = builder.create_string("x".repeat(1000));
+let leaf = Node::create(&mut builder, name, (), (), (), (), (), ());
+let level1 = Node::create(&mut builder, (), (), (), (), (), [leaf; 100], ());
+let level2 = Node::create(&mut builder, (), (), (), (), (), [level1; 100], ());
+let data = builder.finish(level2, None).to_vec();
+assert!(data.len() < 4000);
+
+let node_ref = NodeRef::read_as_root(&data).unwrap();
+let mut budget = ConversionBudget::new(1 << 20);
+let error = Node::try_from_budgeted(node_ref, &mut budget).unwrap_err();
+assert!(matches!(
+    error.error_kind,
+    ErrorKind::AllocationLimitExceeded { limit: 1048576 }
+));
+assert_eq!(error.source_location.type_, "[NodeRef]");
+assert_eq!(error.source_location.method, "try_from_budgeted");
+assert!(error.source_location.byte_offset < data.len());
@@ -0,0 +1,122 @@
+use planus::ReadAsRoot;
+
+fn replace(data: &mut [u8], from: &[u8], to: &[u8]) {
+    let position = data
+        .windows(from.len())
+        .position(|window| window == from)
+        .unwrap();
+    data[position..position + to.len()].copy_from_slice(to);
+}
+
+let leaf = Leaf {
+    name: Some("leaf".into()),
+    score: 3,
+};
+let root = Root {
+    id: 1 << 40,
+    kind: Kind::Small,
+    maybe: Some(5),
+    name: Some("name".into()),
+    label: "label".into(),
+    position: Some(Vec2 { x: 1.5, y: -2.5 }),
+    leaf: Some(Box::new(leaf.clone())),
+    bytes: Some(vec![1, 2, 3]),
+    numbers: Some(vec![-1, 0, 1]),
+    flags: Some(vec![true, false]),
+    kinds: Some(vec![Kind::Large, Kind::Small]),
+    positions: Some(vec![Vec2 { x: 0.0, y: 1.0 }, Vec2 { x: 2.0, y: 3.0 }]),
+    names: Some(vec!["name".into(), "other".into()]),
+    leaves: Some(vec![leaf.clone(), Leaf::default()]),
+    node: Some(Node::Vec2(Vec2 { x: 4.0, y: 5.0 })),
+    nodes: Some(vec![
+        Node::Leaf(Box::new(leaf.clone())),
+        Node::Name("node".into()),
+        Node::Vec2(Vec2 { x: 6.0, y: 7.0 }),
+    ]),
+};
+let mut builder = planus::Builder::new();
+let data = builder.finish(&root, None).to_vec();
+let root_ref = RootRef::read_as_root(&data).unwrap();
+
+// Copying a whole table gives the same value
+let mut builder = planus::Builder::new();
+let copied = builder.finish(root_ref, None);
+let copied_ref = RootRef::read_as_root(copied).unwrap();
+assert_eq!(Root::try_from(copied_ref).unwrap(), root);
+
+// Parts of a table can be copied into a new table
+let mut builder = planus::Builder::new();
+let offset = Root::create(
+    &mut builder,
+    root_ref.id().unwrap(),
+    planus::DefaultValue,
+    (),
+    root_ref.name().unwrap(),
+    planus::DefaultValue,
+    (),
+    root_ref.leaf().unwrap(),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    root_ref.leaves().unwrap(),
+    (),
+    root_ref.nodes().unwrap(),
+);
+let data = builder.finish(offset, None);
+let copied = Root::try_from(RootRef::read_as_root(data).unwrap()).unwrap();
+assert_eq!(
+    copied,
+    Root {
+        id: root.id,
+        name: root.name.clone(),
+        leaf: root.leaf.clone(),
+        leaves: root.leaves.clone(),
+        nodes: root.nodes.clone(),
+        ..Default::default()
+    }
+);
+
+// Copied strings and byte vectors go through the builder caches
+#[cfg(all(feature = "string-cache", feature = "bytes-cache"))]
+{
+    let mut builder = planus::Builder::new();
+    builder.create_string("name");
+    builder.create_vector([1u8, 2, 3]);
+    let len = builder.len();
+    builder.create_string(root_ref.name().unwrap().unwrap());
+    builder.create_vector(root_ref.bytes().unwrap().unwrap());
+    assert_eq!(builder.len(), len);
+}
+
+// Copying values that cannot be read returns the error for them, in a table
+// field, a vector element or a union vector element
+for (from, to, method) in [
+    (&b"label"[..], &b"\xffabel"[..], "label"),
+    (b"other", b"\xffther", "get"),
+    (b"node", b"\xffode", "get"),
+] {
+    let mut invalid = planus::Builder::new().finish(&root, None).to_vec();
+    replace(&mut invalid, from, to);
+    let invalid_ref = RootRef::read_as_root(&invalid).unwrap();
+
+    let mut builder = planus::Builder::new();
+    builder.create_string("before");
+    let len = builder.len();
+    let Err(error) = builder.try_copy(invalid_ref) else {
+        panic!("expected an error");
+    };
+    assert_eq!(error.source_location.method, method);
+    assert_eq!(builder.len(), len);
+    assert_eq!(
+        builder.try_finish(invalid_ref, None).unwrap_err(),
+        planus::errors::BuilderError::InvalidCopySource
+    );
+
+    // The builder can still be used after the error
+    let offset = builder.try_copy(root_ref).unwrap();
+    let copied = builder.finish(offset, None);
+    assert_eq!(Root::try_from(RootRef::read_as_root(copied).unwrap()).unwrap(), root);
+}
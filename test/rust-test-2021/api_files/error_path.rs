@@ -0,0 +1,107 @@
+use planus::{errors::PathSegment, ReadAsRoot};
+
+fn replace(data: &mut [u8], from: &[u8], to: &[u8]) {
+    let position = data
+        .windows(from.len())
+        .position(|window| window == from)
+        .unwrap();
+    data[position..position + to.len()].copy_from_slice(to);
+}
+
+let monster = Monster {
+    name: Some("orc".into()),
+    weapons: Some(vec![
+        Weapon {
+            name: Some("axe".into()),
+        },
+        Weapon {
+            name: Some("sword".into()),
+        },
+    ]),
+    pixels: Some(vec![
+        Pixel {
+            x: 0x1234,
+            color: Color::Red,
+        },
+        Pixel {
+            x: 0x5678,
+            color: Color::Green,
+        },
+    ]),
+    boss: None,
+};
+let monster = Monster {
+    name: Some("king".into()),
+    weapons: None,
+    pixels: None,
+    boss: Some(Box::new(monster)),
+};
+let mut builder = planus::Builder::new();
+let data = builder.finish(&monster, None).to_vec();
+
+// Invalid utf-8 in a nested string
+let mut invalid_name = data.clone();
+replace(&mut invalid_name, b"orc", b"\xffrc");
+let error = Monster::try_from(MonsterRef::read_as_root(&invalid_name).unwrap()).unwrap_err();
+assert_eq!(error.source_location.method, "name");
+
+// An unknown enum value in a struct
+let mut invalid_color = data.clone();
+replace(&mut invalid_color, &[0x78, 0x56, 0x01], &[0x78, 0x56, 0x07]);
+let color_error =
+    Monster::try_from(MonsterRef::read_as_root(&invalid_color).unwrap()).unwrap_err();
+
+// Invalid utf-8 in a string inside a vector
+let mut invalid_weapon = data.clone();
+replace(&mut invalid_weapon, b"sword", b"\xffword");
+let weapon_error =
+    Monster::try_from(MonsterRef::read_as_root(&invalid_weapon).unwrap()).unwrap_err();
+
+// The feature may be enabled by another crate in the build, so ask planus
+if planus::errors::ErrorPath::ENABLED {
+    assert_eq!(format!("{}", error.path()), "boss.name");
+    assert_eq!(
+        error.path().segments().collect::<Vec<_>>(),
+        [PathSegment::Field("boss"), PathSegment::Field("name")]
+    );
+    assert!(format!("{}", error).contains(" at boss.name: "));
+
+    assert_eq!(format!("{}", color_error.path()), "boss.pixels[1].color");
+    assert_eq!(format!("{}", weapon_error.path()), "boss.weapons[1].name");
+    assert_eq!(
+        weapon_error.path().segments().collect::<Vec<_>>(),
+        [
+            PathSegment::Field("boss"),
+            PathSegment::Field("weapons"),
+            PathSegment::Index(1),
+            PathSegment::Field("name"),
+        ]
+    );
+} else {
+    assert!(error.path().is_empty());
+    assert!(color_error.path().is_empty());
+    assert!(weapon_error.path().is_empty());
+}
+
+// Long paths are kept in full
+let mut deep = monster.clone();
+for _ in 0..10 {
+    deep = Monster {
+        name: None,
+        weapons: None,
+        pixels: None,
+        boss: Some(Box::new(deep)),
+    };
+}
+let mut builder = planus::Builder::new();
+let mut data = builder.finish(&deep, None).to_vec();
+replace(&mut data, b"sword", b"\xffword");
+let error = Monster::try_from(MonsterRef::read_as_root(&data).unwrap()).unwrap_err();
+if planus::errors::ErrorPath::ENABLED {
+    assert_eq!(
+        format!("{}", error.path()),
+        format!("{}weapons[1].name", "boss.".repeat(11))
+    );
+} else {
+    assert!(error.path().is_empty());
+}
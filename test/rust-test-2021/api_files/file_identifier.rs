@@ -0,0 +1,39 @@
+use planus::{errors::ErrorKind, ReadAsRoot};
+
+let mut builder = planus::Builder::new();
+let ping = builder.finish(Ping { sequence: 7 }, Some(*b"PING")).to_vec();
+let mut builder = planus::Builder::new();
+let pong = builder
+    .finish(
+        Pong {
+            sequence: 7,
+            payload: Some("pong".into()),
+        },
+        Some(*b"PONG"),
+    )
+    .to_vec();
+
+let ping_ref = PingRef::read_as_root_with_identifier(&ping, *b"PING").unwrap();
+assert_eq!(ping_ref.sequence().unwrap(), 7);
+let pong_ref = PongRef::read_as_root_with_identifier(&pong, *b"PONG").unwrap();
+assert_eq!(pong_ref.payload().unwrap(), Some("pong"));
+
+// Reading the wrong message kind gives an error instead of garbage
+let error = PongRef::read_as_root_with_identifier(&ping, *b"PONG").unwrap_err();
+assert!(matches!(
+    error.error_kind,
+    ErrorKind::InvalidFileIdentifier {
+        expected: [b'P', b'O', b'N', b'G'],
+        found: [b'P', b'I', b'N', b'G'],
+    }
+));
+assert_eq!(error.source_location.type_, "[PongRef]");
+assert_eq!(error.source_location.method, "read_as_root_with_identifier");
+
+// Buffers without an identifier are rejected as well
+let mut builder = planus::Builder::new();
+let plain = builder.finish(Ping { sequence: 7 }, None).to_vec();
+assert!(PingRef::read_as_root_with_identifier(&plain, *b"PING").is_err());
+
+let error = PingRef::read_as_root_with_identifier(&ping[..7], *b"PING").unwrap_err();
+assert!(matches!(error.error_kind, ErrorKind::InvalidLength));
@@ -0,0 +1,59 @@
+use planus::{errors::BuilderError, ReadAsRoot};
+
+let root = Root {
+    items: Some(vec![
+        Item {
+            name: Some("first".into()),
+            position: Some(Point { x: 1, y: 2 }),
+        },
+        Item {
+            name: Some("second".into()),
+            position: None,
+        },
+    ]),
+    tags: Some(vec!["a".into(), "a".into(), "b".into()]),
+};
+
+// A large enough buffer gives a buffer that reads back the same
+let mut buffer = [0; 256];
+let mut builder = planus::BufferBuilder::from_buffer(&mut buffer);
+let data = builder.try_finish(&root, Some(*b"FIXD")).unwrap().to_vec();
+assert_eq!(planus::file_identifier(&data), Some(*b"FIXD"));
+assert_eq!(
+    Root::try_from(RootRef::read_as_root(&data).unwrap()).unwrap(),
+    root
+);
+drop(builder);
+assert_eq!(buffer[buffer.len() - data.len()..], data);
+
+// The caches are not used, so the buffer is at least as large as one
+// written by a normal builder
+let mut builder = planus::Builder::new();
+assert!(builder.finish(&root, Some(*b"FIXD")).len() <= data.len());
+
+// A buffer that is too small gives an error and reports the size needed
+let mut buffer = [0; 32];
+let mut builder = planus::BufferBuilder::from_buffer(&mut buffer);
+assert_eq!(
+    builder.try_finish(&root, Some(*b"FIXD")),
+    Err(BuilderError::BufferTooSmall {
+        capacity: 32,
+        needed: data.len(),
+    })
+);
+
+// Rolling back to before the overflow makes the builder usable again
+let mut builder = planus::BufferBuilder::from_buffer(&mut buffer);
+let checkpoint = builder.checkpoint();
+let _ = Root::create(&mut builder, &root.items, &root.tags);
+builder.rollback_to(checkpoint);
+let item = Item::create(&mut builder, "small", ());
+let data = builder.try_finish(item, None).unwrap();
+let item = ItemRef::read_as_root(data).unwrap();
+assert_eq!(item.name().unwrap(), Some("small"));
+
+// An array can be used directly as the buffer
+let mut buffer = [0u8; 64];
+let mut builder = planus::BufferBuilder::from_buffer(&mut buffer);
+let item = Item::create(&mut builder, "array", Point { x: 3, y: 4 });
+builder.finish(item, None);
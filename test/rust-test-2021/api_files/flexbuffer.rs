@@ -0,0 +1,74 @@
+use planus::{
+    flexbuffers::{FlexBufferBuilder, FlexBufferType},
+    ReadAsRoot,
+};
+
+let mut flex = FlexBufferBuilder::new();
+let map = flex.start_map();
+flex.push_key("user");
+flex.push_str("alice");
+flex.push_key("attempts");
+flex.push_uint(3);
+flex.push_key("scores");
+let scores = flex.start_vector();
+flex.push_float(1.5);
+flex.push_float(-2.25);
+flex.end_typed_vector(scores);
+flex.end_map(map);
+let metadata = flex.finish().to_vec();
+
+flex.clear();
+let tags = flex.start_vector();
+flex.push_str("login");
+flex.push_bool(true);
+flex.end_vector(tags);
+let tags = flex.finish().to_vec();
+
+let mut builder = planus::Builder::new();
+let event = Event::create(&mut builder, "login", &metadata, &tags);
+let data = builder.finish(event, None);
+
+let event = EventRef::read_as_root(data).unwrap();
+let metadata = event.metadata_flexbuffer().unwrap().unwrap().as_map().unwrap();
+assert_eq!(metadata.len(), 3);
+assert_eq!(
+    metadata.get("user").unwrap().unwrap().as_str().unwrap(),
+    "alice"
+);
+assert_eq!(
+    metadata.get("attempts").unwrap().unwrap().as_u64().unwrap(),
+    3
+);
+let scores = metadata.get("scores").unwrap().unwrap();
+assert_eq!(scores.flexbuffer_type(), FlexBufferType::VectorFloat);
+let scores = scores
+    .as_vector()
+    .unwrap()
+    .iter()
+    .map(|score| score.unwrap().as_f64().unwrap())
+    .collect::<Vec<_>>();
+assert_eq!(scores, [1.5, -2.25]);
+assert!(metadata.get("missing").unwrap().is_none());
+
+let tags = event.tags_flexbuffer().unwrap().as_vector().unwrap();
+assert_eq!(tags.get(0).unwrap().unwrap().as_str().unwrap(), "login");
+assert!(tags.get(1).unwrap().unwrap().as_bool().unwrap());
+
+let mut builder = planus::Builder::new();
+let event = Event::create(&mut builder, (), (), [0u8; 0]);
+let data = builder.finish(event, None);
+let event = EventRef::read_as_root(data).unwrap();
+assert!(event.metadata_flexbuffer().unwrap().is_none());
+assert!(event.tags_flexbuffer().is_err());
+
+let mut flexbuffer = planus::flexbuffers::FlexBufferBuilder::new();
+flexbuffer.push_int(13);
+let mut builder = planus::Builder::new();
+let collision = Collision::create(&mut builder, flexbuffer.finish(), 7u32);
+let data = builder.finish(collision, None);
+let collision = CollisionRef::read_as_root(data).unwrap();
+assert_eq!(collision.b_flexbuffer().unwrap(), 7);
+assert_eq!(
+    collision.b_flexbuffer_().unwrap().unwrap().as_i64().unwrap(),
+    13
+);
@@ -0,0 +1,85 @@
+use planus::ReadAsRoot;
+
+let mut builder = planus::Builder::new();
+let mut words = vec![
+    Word { text: "pear".into(), count: 3 },
+    Word { text: "apple".into(), count: 1 },
+    Word { text: "zucchini".into(), count: 7 },
+    Word { text: "banana".into(), count: 2 },
+];
+let words = builder.create_vector_sorted_by_key(&mut words);
+let mut points = vec![
+    Point { id: 30, x: 3.0 },
+    Point { id: 10, x: 1.0 },
+    Point { id: 20, x: 2.0 },
+];
+let points = builder.create_vector_sorted_by_key(&mut points);
+let mut pixels = vec![
+    Pixel { color: Color::Red, alpha: 3 },
+    Pixel { color: Color::Blue, alpha: 1 },
+    Pixel { color: Color::Green, alpha: 2 },
+];
+let pixels = builder.create_vector_sorted_by_key(&mut pixels);
+let mut scores = vec![
+    Score { value: 2.5 },
+    Score { value: -1.0 },
+    Score { value: 10.0 },
+    Score { value: -0.0 },
+];
+let scores = builder.create_vector_sorted_by_key(&mut scores);
+let mut tags = vec![
+    Tag { name: Some("fruit".into()), weight: 2 },
+    Tag { name: None, weight: 1 },
+    Tag { name: Some("berry".into()), weight: 3 },
+];
+let tags = builder.create_vector_sorted_by_key(&mut tags);
+let dictionary = Dictionary::create(&mut builder, words, points, pixels, scores, tags);
+let data = builder.finish(dictionary, None);
+
+let dictionary = DictionaryRef::read_as_root(data).unwrap();
+let words = dictionary.words().unwrap().unwrap();
+let texts = words
+    .iter()
+    .map(|word| word.unwrap().text().unwrap())
+    .collect::<Vec<_>>();
+assert_eq!(texts, ["apple", "banana", "pear", "zucchini"]);
+assert_eq!(words.lookup_by_key("pear").unwrap().unwrap().count().unwrap(), 3);
+assert_eq!(words.lookup_by_key("apple").unwrap().unwrap().count().unwrap(), 1);
+assert_eq!(words.lookup_by_key("zucchini").unwrap().unwrap().count().unwrap(), 7);
+assert!(words.lookup_by_key("cherry").unwrap().is_none());
+assert!(words.lookup_by_key("").unwrap().is_none());
+
+let points = dictionary.points().unwrap().unwrap();
+assert_eq!(points.lookup_by_key(&20).unwrap().unwrap().x(), 2.0);
+assert!(points.lookup_by_key(&15).unwrap().is_none());
+assert!(points.lookup_by_key(&40).unwrap().is_none());
+
+// Enums are ordered by their underlying value, not their declaration order
+let pixels = dictionary.pixels().unwrap().unwrap();
+let colors = pixels
+    .iter()
+    .map(|pixel| pixel.color().unwrap())
+    .collect::<Vec<_>>();
+assert_eq!(colors, [Color::Blue, Color::Green, Color::Red]);
+assert_eq!(pixels.lookup_by_key(&Color::Green).unwrap().unwrap().alpha(), 2);
+
+let scores = dictionary.scores().unwrap().unwrap();
+assert!(scores.lookup_by_key(&2.5).unwrap().is_some());
+assert!(scores.lookup_by_key(&3.0).unwrap().is_none());
+// Like flatc, -0.0 and 0.0 compare equal
+assert_eq!(scores.lookup_by_key(&0.0).unwrap().unwrap().value().unwrap(), 0.0);
+
+// Missing string keys are ordered like empty strings
+let tags = dictionary.tags().unwrap().unwrap();
+let weights = tags
+    .iter()
+    .map(|tag| tag.unwrap().weight().unwrap())
+    .collect::<Vec<_>>();
+assert_eq!(weights, [1, 3, 2]);
+assert_eq!(tags.lookup_by_key("").unwrap().unwrap().weight().unwrap(), 1);
+assert!(tags.lookup_by_key("").unwrap().unwrap().name().unwrap().is_none());
+assert_eq!(tags.lookup_by_key("fruit").unwrap().unwrap().weight().unwrap(), 2);
+
+// Lookups in an empty vector find nothing
+let empty: planus::Vector<'_, Result<WordRef<'_>, planus::Error>> = planus::Vector::new_empty();
+assert!(empty.lookup_by_key("pear").unwrap().is_none());
@@ -0,0 +1,52 @@
+use planus::ReadAsRoot;
+
+let mut nested_builder = planus::Builder::new();
+let nested = nested_builder
+    .finish(
+        payloads::Payload {
+            id: 1 << 40,
+            name: Some("inner".into()),
+        },
+        None,
+    )
+    .to_vec();
+let nested_alignment = nested_builder.alignment();
+assert_eq!(nested_alignment, 8);
+
+let mut builder = planus::Builder::new();
+// Misalign the builder, so the nested buffer has to be padded
+builder.create_string("x");
+let payload = builder.create_nested_flatbuffer(&nested, nested_alignment);
+let required_payload = builder.create_nested_flatbuffer(&nested, nested_alignment);
+let envelope = messages::Envelope::create(&mut builder, payload, required_payload);
+let data = builder.finish(envelope, None);
+
+let envelope = messages::EnvelopeRef::read_as_root(data).unwrap();
+let payload_bytes = envelope.payload().unwrap().unwrap();
+assert_eq!(payload_bytes, nested);
+assert_eq!(
+    (payload_bytes.as_ptr() as usize - data.as_ptr() as usize) % nested_alignment,
+    0
+);
+
+let payload = envelope.payload_nested().unwrap().unwrap();
+assert_eq!(payload.id().unwrap(), 1 << 40);
+assert_eq!(payload.name().unwrap(), Some("inner"));
+
+let required_payload = envelope.required_payload_nested().unwrap();
+assert_eq!(required_payload.id().unwrap(), 1 << 40);
+
+let mut builder = planus::Builder::new();
+let required_payload = builder.create_nested_flatbuffer(&nested, nested_alignment);
+let envelope = messages::Envelope::create(&mut builder, (), required_payload);
+let data = builder.finish(envelope, None);
+let envelope = messages::EnvelopeRef::read_as_root(data).unwrap();
+assert!(envelope.payload_nested().unwrap().is_none());
+
+let mut builder = planus::Builder::new();
+let b = builder.create_nested_flatbuffer(&nested, nested_alignment);
+let collision = messages::Collision::create(&mut builder, b, 7u32);
+let data = builder.finish(collision, None);
+let collision = messages::CollisionRef::read_as_root(data).unwrap();
+assert_eq!(collision.b_nested().unwrap(), 7);
+assert_eq!(collision.b_nested_().unwrap().unwrap().id().unwrap(), 1 << 40);
@@ -0,0 +1,74 @@
+use planus::{BufferBuilder, ReadAsRoot};
+
+// Tables and unions have no owned types without an allocator, so the buffer
+// is written into a fixed buffer using their builders
+let mut buffer = [0; 256];
+let mut builder = BufferBuilder::from_buffer(&mut buffer);
+let axe = no_alloc::Item::builder()
+    .name("axe")
+    .count(1)
+    .finish(&mut builder);
+let gold = no_alloc::Item::builder()
+    .name("gold")
+    .count(30)
+    .finish(&mut builder);
+let items = builder.create_vector([axe, gold]);
+let inventory = no_alloc::Inventory::builder()
+    .owner("Orc")
+    .color(no_alloc::Color::Green)
+    .position(no_alloc::Vec2 { x: 1.0, y: 2.0 })
+    .items(items)
+    .payload(no_alloc::Payload::builder().vec2(no_alloc::Vec2 { x: 3.0, y: 4.0 }))
+    .finish(&mut builder);
+let data: &[u8] = builder
+    .try_finish(inventory, Some(no_alloc::InventoryRef::FILE_IDENTIFIER))
+    .unwrap();
+
+// The file identifier lives on the `Ref` type, as the table has no owned type
+assert_eq!(no_alloc::InventoryRef::FILE_IDENTIFIER, *b"NOAL");
+let inventory = no_alloc::InventoryRef::read_root(data).unwrap();
+no_alloc::InventoryRef::read_as_root_verified(data).unwrap();
+
+assert_eq!(inventory.owner().unwrap(), "Orc");
+assert_eq!(inventory.color().unwrap(), no_alloc::Color::Green);
+let position = inventory.position().unwrap().unwrap();
+assert_eq!((position.x(), position.y()), (1.0, 2.0));
+
+// Structs are plain values, so their owned type is still generated
+let position: no_alloc::Vec2 = position.into();
+assert_eq!(position, no_alloc::Vec2 { x: 1.0, y: 2.0 });
+
+let items = inventory.items().unwrap().unwrap();
+let mut counts = [0; 2];
+for (count, item) in counts.iter_mut().zip(items.iter()) {
+    *count = item.unwrap().count().unwrap();
+}
+assert_eq!(counts, [1, 30]);
+assert_eq!(items.lookup_by_key("gold").unwrap().unwrap().count().unwrap(), 30);
+
+let Some(no_alloc::PayloadRef::Vec2(payload)) = inventory.payload().unwrap() else {
+    panic!("unexpected payload");
+};
+assert_eq!((payload.x(), payload.y()), (3.0, 4.0));
+
+let AnyRoot::Inventory(inventory) = AnyRoot::identify(data).unwrap();
+assert_eq!(inventory.owner().unwrap(), "Orc");
+
+// Fields can still be changed in place
+let mut copy = [0; 256];
+let copy = &mut copy[..data.len()];
+copy.copy_from_slice(data);
+let mut inventory = no_alloc::InventoryMut::read_as_root(copy).unwrap();
+inventory.set_color(no_alloc::Color::Red).unwrap();
+assert_eq!(inventory.to_ref().unwrap().color().unwrap(), no_alloc::Color::Red);
+
+// Copies write their vectors of tables without allocating as well
+let mut buffer = [0; 256];
+let mut builder = BufferBuilder::from_buffer(&mut buffer);
+let inventory = builder
+    .try_copy(no_alloc::InventoryRef::read_root(data).unwrap())
+    .unwrap();
+let copy = builder.try_finish(inventory, Some(*b"NOAL")).unwrap();
+let inventory = no_alloc::InventoryRef::read_as_root_verified(copy).unwrap();
+let items = inventory.items().unwrap().unwrap();
+assert_eq!(items.lookup_by_key("gold").unwrap().unwrap().count().unwrap(), 30);
@@ -0,0 +1,55 @@
+use planus::ReadAsRoot;
+
+let points = [
+    Point { x: 1.0, y: 2.0, z: 3.0 },
+    Point { x: -4.0, y: 5.5, z: 0.25 },
+];
+
+let mut builder = planus::Builder::new();
+let labels = builder.create_vector64::<u16, u16>(&[7, 8, 9]);
+let cloud = PointCloud::create(
+    &mut builder,
+    "scan",
+    points,
+    [10u8, 20, 30].as_slice(),
+    labels,
+    "a small scan",
+);
+let data = builder.finish(cloud, None).to_vec();
+
+let cloud = PointCloudRef::read_as_root(&data).unwrap();
+assert_eq!(cloud.name().unwrap(), Some("scan"));
+let read_points = cloud
+    .points()
+    .unwrap()
+    .unwrap()
+    .iter()
+    .map(Point::from)
+    .collect::<Vec<_>>();
+assert_eq!(read_points, points);
+assert_eq!(cloud.intensities().unwrap(), Some([10u8, 20, 30].as_slice()));
+assert_eq!(cloud.labels().unwrap().to_vec::<u16>().unwrap(), [7, 8, 9]);
+assert_eq!(cloud.description().unwrap(), Some("a small scan"));
+
+// Vectors with the vector64 attribute are prefixed by a 64-bit length
+let mut points_with_length = 2u64.to_le_bytes().to_vec();
+points_with_length.extend_from_slice(&1.0f32.to_le_bytes());
+assert!(data
+    .windows(points_with_length.len())
+    .any(|window| window == points_with_length));
+
+let owned = PointCloud::try_from(cloud).unwrap();
+let mut builder = planus::Builder::new();
+let data = builder.finish(&owned, None);
+assert_eq!(
+    PointCloud::try_from(PointCloudRef::read_as_root(data).unwrap()).unwrap(),
+    owned
+);
+
+let mut builder = planus::Builder::new();
+let cloud = PointCloud::create(&mut builder, (), (), (), Vec::<u16>::new(), ());
+let data = builder.finish(cloud, None);
+let cloud = PointCloudRef::read_as_root(data).unwrap();
+assert!(cloud.name().unwrap().is_none());
+assert!(cloud.points().unwrap().is_none());
+assert!(cloud.labels().unwrap().is_empty());
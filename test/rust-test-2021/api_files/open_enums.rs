@@ -0,0 +1,37 @@
+use planus::ReadAsRoot;
+
+assert_eq!(Color::ENUM_VALUES, [Color::Red, Color::Green, Color::Blue]);
+assert_eq!(Color::try_from(2).unwrap(), Color::Green);
+assert_eq!(Color::try_from(7).unwrap(), Color::Unknown(7));
+assert_eq!(u8::from(Color::Unknown(7)), 7);
+assert_eq!(u8::from(Color::Blue), 3);
+// The added variant does not clash with variants from the schema
+assert_eq!(Unknown::try_from(9).unwrap(), Unknown::Unknown_(9));
+assert_eq!(i16::from(Unknown::Unknown), 0);
+
+let canvas = Canvas {
+    background: Color::Unknown(42),
+    pixels: Some(vec![
+        Pixel {
+            color: Color::Unknown(200),
+            x: 1,
+        },
+        Pixel {
+            color: Color::Green,
+            x: 2,
+        },
+    ]),
+    palette: Some(vec![Color::Red, Color::Unknown(4)]),
+    marker: Unknown::Unknown_(-5),
+};
+let mut builder = planus::Builder::new();
+let data = builder.finish(&canvas, None);
+
+let canvas_ref = CanvasRef::read_as_root(data).unwrap();
+assert_eq!(canvas_ref.background().unwrap(), Color::Unknown(42));
+let pixel = canvas_ref.pixels().unwrap().unwrap().get(0).unwrap();
+assert_eq!(pixel.color().unwrap(), Color::Unknown(200));
+let palette = canvas_ref.palette().unwrap().unwrap();
+assert_eq!(palette.get(1).unwrap().unwrap(), Color::Unknown(4));
+assert_eq!(canvas_ref.marker().unwrap(), Unknown::Unknown_(-5));
+assert_eq!(Canvas::try_from(canvas_ref).unwrap(), canvas);
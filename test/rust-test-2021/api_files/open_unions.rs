@@ -0,0 +1,121 @@
+use planus::ReadAsRoot;
+
+let image = v2::Image {
+    id: 1 << 40,
+    caption: Some("cat".into()),
+    pixels: Some(vec![1, 2, 3]),
+};
+let message = v2::Message {
+    payload: Some(v2::Payload::Image(Box::new(image.clone()))),
+    payloads: Some(vec![
+        v2::Payload::Point(v2::Point { x: 1, y: 2 }),
+        v2::Payload::Image(Box::new(image.clone())),
+    ]),
+};
+let mut builder = planus::Builder::new();
+let data = builder.finish(&message, None).to_vec();
+
+// An older schema reads the new variant as an unknown value
+let message_ref = v1::MessageRef::read_as_root_verified(&data).unwrap();
+let v1::PayloadRef::Unknown(payload_ref) = message_ref.payload().unwrap().unwrap() else {
+    panic!("expected an unknown payload");
+};
+assert_eq!(payload_ref.tag(), 3);
+let bytes = payload_ref.to_bytes();
+let image_ref = v2::ImageRef::read_as_root(&bytes).unwrap();
+assert_eq!(v2::Image::try_from(image_ref).unwrap(), image);
+
+let old_message = v1::Message::try_from(message_ref).unwrap();
+assert!(matches!(
+    old_message.payload,
+    Some(v1::Payload::Unknown { tag: 3, .. })
+));
+let payloads = old_message.payloads.as_ref().unwrap();
+assert_eq!(payloads[0], v1::Payload::Point(v1::Point { x: 1, y: 2 }));
+assert!(matches!(payloads[1], v1::Payload::Unknown { tag: 3, .. }));
+
+// Writing the old message keeps the unknown values intact, also when the
+// builder is not aligned beforehand
+let mut builder = planus::Builder::new();
+builder.create_string("x");
+let data = builder.finish(&old_message, None);
+let message_ref = v2::MessageRef::read_as_root(data).unwrap();
+assert_eq!(v2::Message::try_from(message_ref).unwrap(), message);
+
+// Copying the written values again keeps them intact
+let reread = v1::Message::try_from(v1::MessageRef::read_as_root(data).unwrap()).unwrap();
+let Some(v1::Payload::Unknown { tag: 3, bytes: reread_bytes }) = reread.payload else {
+    panic!("expected an unknown payload");
+};
+let image_ref = v2::ImageRef::read_as_root(&reread_bytes).unwrap();
+assert_eq!(v2::Image::try_from(image_ref).unwrap(), image);
+
+// Copying directly from the old reference type keeps the unknown values too
+let mut builder = planus::Builder::new();
+let data = builder.finish(v1::MessageRef::read_as_root(data).unwrap(), None);
+let message_ref = v2::MessageRef::read_as_root(data).unwrap();
+assert_eq!(v2::Message::try_from(message_ref).unwrap(), message);
+
+let forward = |data: &[u8]| {
+    let message_ref = v1::MessageRef::read_as_root(data).unwrap();
+    let mut builder = planus::Builder::new();
+    builder.finish(message_ref, None).to_vec()
+};
+let mut builder = planus::Builder::new();
+let data = builder.finish(&message, None).to_vec();
+let forwarded_twice = forward(&forward(&data));
+let message_ref = v2::MessageRef::read_as_root(&forwarded_twice).unwrap();
+assert_eq!(v2::Message::try_from(message_ref).unwrap(), message);
+
+// The values of a vector are copied along with the data of the first one
+// instead of each copying the rest of the buffer
+let many = v2::Message {
+    payload: None,
+    payloads: Some(vec![v2::Payload::Image(Box::new(image.clone())); 100]),
+};
+let mut builder = planus::Builder::new();
+let data = builder.finish(&many, None).to_vec();
+let forwarded = forward(&data);
+assert!(forwarded.len() < 3 * data.len());
+let message_ref = v2::MessageRef::read_as_root(&forwarded).unwrap();
+assert_eq!(v2::Message::try_from(message_ref).unwrap(), many);
+
+// Bytes in the values are never taken for a part of the copy
+let marker = v2::Image {
+    id: u64::from_le_bytes(*b"UNKV\x04\0\0\0"),
+    caption: Some("UNKV\x04\0\0\0UNKV\x01\0\0\0".into()),
+    pixels: Some(b"UNKV\x04\0\0\0UNKV\x01\0\0\0".to_vec()),
+};
+let message = v2::Message {
+    payload: Some(v2::Payload::Image(Box::new(marker.clone()))),
+    payloads: Some(vec![v2::Payload::Image(Box::new(marker.clone())); 3]),
+};
+let mut builder = planus::Builder::new();
+let data = builder.finish(&message, None).to_vec();
+let old_message = v1::Message::try_from(v1::MessageRef::read_as_root(&data).unwrap()).unwrap();
+let Some(v1::Payload::Unknown { bytes, .. }) = &old_message.payload else {
+    panic!("expected an unknown payload");
+};
+let image_ref = v2::ImageRef::read_as_root(bytes).unwrap();
+assert_eq!(v2::Image::try_from(image_ref).unwrap(), marker);
+let mut builder = planus::Builder::new();
+let data = builder.finish(&old_message, None).to_vec();
+for data in [forward(&data), forward(&forward(&data))] {
+    let message_ref = v2::MessageRef::read_as_root(&data).unwrap();
+    assert_eq!(v2::Message::try_from(message_ref).unwrap(), message);
+}
+
+// Buffers without a valid root offset are rejected
+let mut builder = planus::Builder::new();
+assert!(matches!(
+    builder.create_unknown_union_value(&[8, 0, 0, 0]),
+    Err(planus::errors::BuilderError::InvalidUnknownUnionValue)
+));
+let invalid = v1::Message {
+    payload: Some(v1::Payload::Unknown { tag: 3, bytes: vec![1, 2] }),
+    ..old_message.clone()
+};
+assert_eq!(
+    builder.try_finish(&invalid, None).unwrap_err(),
+    planus::errors::BuilderError::InvalidUnknownUnionValue
+);
@@ -0,0 +1,34 @@
+use alloc::sync::Arc;
+
+use planus::OwnedRoot;
+
+let item = Item {
+    name: Some("hammer".into()),
+    tags: Some(vec!["tool".into(), "heavy".into()]),
+};
+let mut builder = planus::Builder::new();
+let data = builder.finish(&item, None).to_vec();
+
+// The owned root can be kept around and moved to other threads
+#[cfg(feature = "std")]
+{
+    let owned: OwnedRoot<Item> = OwnedRoot::new(data.clone()).unwrap();
+    let handle = std::thread::spawn(move || {
+        let item_ref = owned.get();
+        assert_eq!(item_ref.name().unwrap(), Some("hammer"));
+        Item::try_from(item_ref).unwrap()
+    });
+    assert_eq!(handle.join().unwrap(), item);
+}
+
+let shared: OwnedRoot<Item, Arc<[u8]>> = OwnedRoot::new(data.clone().into()).unwrap();
+let cloned = shared.clone();
+assert_eq!(shared.as_bytes(), cloned.as_bytes());
+assert_eq!(cloned.get().tags().unwrap().unwrap().len(), 2);
+assert_eq!(&*shared.into_inner(), &data[..]);
+
+// Invalid buffers are rejected up front
+let mut truncated = data.clone();
+truncated.truncate(data.len() - 4);
+assert!(OwnedRoot::<Item>::new(truncated).is_err());
+assert!(OwnedRoot::<Item>::new(vec![]).is_err());
@@ -0,0 +1,34 @@
+use planus::errors::ErrorKind;
+
+check_type!(+['a] ExampleRef<'a> => &self.read_root_() : planus::Result<bool>);
+
+assert_eq!(Example::FILE_IDENTIFIER, *b"RTYP");
+assert_eq!(Example::FILE_EXTENSION, "rt");
+
+let example = Example {
+    value: 42,
+    read_root_: true,
+};
+
+let mut builder = planus::Builder::new();
+let data = Example::finish_root(&mut builder, &example).to_vec();
+assert_eq!(&data[4..8], b"RTYP");
+
+let example_ref = ExampleRef::read_root(&data).unwrap();
+assert_eq!(Example::try_from(example_ref).unwrap(), example);
+
+let mut builder = planus::Builder::new();
+let data = builder
+    .finish(Other { value: 42 }, Some(*b"OTHR"))
+    .to_vec();
+let error = ExampleRef::read_root(&data).unwrap_err();
+assert!(matches!(
+    error.error_kind,
+    ErrorKind::InvalidFileIdentifier {
+        expected: [b'R', b'T', b'Y', b'P'],
+        found: [b'O', b'T', b'H', b'R'],
+    }
+));
+
+let error = ExampleRef::read_root(&data[..6]).unwrap_err();
+assert!(matches!(error.error_kind, ErrorKind::InvalidLength));
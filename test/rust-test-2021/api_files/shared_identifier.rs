@@ -0,0 +1,9 @@
+use planus::ReadAsRoot;
+
+let mut builder = planus::Builder::new();
+let data = builder.finish(Ping { id: 3 }, Some(Ping::FILE_IDENTIFIER)).to_vec();
+assert_eq!(Ping::FILE_IDENTIFIER, Pong::FILE_IDENTIFIER);
+let ping = PingRef::read_as_root_with_identifier(&data, *b"PING").unwrap();
+assert_eq!(ping.id().unwrap(), 3);
+let pong = PongRef::read_as_root_with_identifier(&data, *b"PING").unwrap();
+assert_eq!(pong.id().unwrap(), 3);
@@ -0,0 +1,46 @@
+use planus::{errors::ErrorKind, ReadAsRoot};
+
+let example = Example {
+    value: 0x1234_5678,
+    name: Some("hello".into()),
+};
+
+let mut builder = planus::Builder::new();
+let plain = builder.finish(&example, None).to_vec();
+
+let mut builder = planus::Builder::new();
+let data = builder.finish_size_prefixed(&example, None).to_vec();
+assert_eq!(data[..4], ((data.len() - 4) as u32).to_le_bytes());
+let example_ref = ExampleRef::read_as_root_size_prefixed(&data).unwrap();
+assert_eq!(Example::try_from(example_ref).unwrap(), example);
+
+let mut builder = planus::Builder::new();
+let data_with_identifier = builder
+    .finish_size_prefixed(&example, Some(*b"EXMP"))
+    .to_vec();
+assert_eq!(
+    data_with_identifier[..4],
+    ((data_with_identifier.len() - 4) as u32).to_le_bytes()
+);
+assert_eq!(&data_with_identifier[8..12], b"EXMP");
+let example_ref = ExampleRef::read_as_root_size_prefixed(&data_with_identifier).unwrap();
+assert_eq!(Example::try_from(example_ref).unwrap(), example);
+
+// The file identifier follows the root offset
+let mut builder = planus::Builder::new();
+let data_with_identifier = builder.finish(&example, Some(*b"EXMP")).to_vec();
+assert_eq!(&data_with_identifier[4..8], b"EXMP");
+let example_ref = ExampleRef::read_as_root(&data_with_identifier).unwrap();
+assert_eq!(Example::try_from(example_ref).unwrap(), example);
+
+// The prefix must match the length of the rest of the slice
+for slice in [&data[..data.len() - 1], &data[..2], &[][..], &plain[..]] {
+    let error = ExampleRef::read_as_root_size_prefixed(slice).unwrap_err();
+    assert!(matches!(error.error_kind, ErrorKind::InvalidLength));
+}
+let mut longer = data.clone();
+longer.extend_from_slice(&[0; 4]);
+let error = ExampleRef::read_as_root_size_prefixed(&longer).unwrap_err();
+assert!(matches!(error.error_kind, ErrorKind::InvalidLength));
+assert_eq!(error.source_location.type_, "[ExampleRef]");
+assert_eq!(error.source_location.method, "read_as_root_size_prefixed");
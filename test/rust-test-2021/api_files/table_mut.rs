@@ -0,0 +1,65 @@
+use planus::{errors::ErrorKind, ReadAsRoot};
+
+let message = Message {
+    sequence: 1,
+    retries: 3,
+    priority: Some(7),
+    ratio: 0.5,
+    urgent: false,
+    status: Status::Pending,
+    sent_at: Some(Timestamp {
+        seconds: 100,
+        nanos: 200,
+    }),
+    payload: Some("hello".into()),
+};
+let mut builder = planus::Builder::new();
+let mut data = builder.finish(&message, None).to_vec();
+let original_len = data.len();
+
+let mut message_mut = MessageMut::read_as_root(&mut data).unwrap();
+message_mut.set_sequence(2).unwrap();
+message_mut.set_priority(-1).unwrap();
+message_mut.set_ratio(1.5).unwrap();
+message_mut
+    .set_sent_at(Timestamp {
+        seconds: 300,
+        nanos: 400,
+    })
+    .unwrap();
+
+// Fields with default values are not stored, so they can not be set
+for error in [
+    message_mut.set_retries(4).unwrap_err(),
+    message_mut.set_urgent(true).unwrap_err(),
+    message_mut.set_status(Status::Sent).unwrap_err(),
+] {
+    assert!(matches!(error.error_kind, ErrorKind::FieldNotPresent));
+}
+
+let message_ref = message_mut.to_ref().unwrap();
+assert_eq!(message_ref.sequence().unwrap(), 2);
+assert_eq!(message_ref.payload().unwrap(), Some("hello"));
+
+// The buffer keeps its layout, and only the changed fields are different
+assert_eq!(data.len(), original_len);
+let message = Message::try_from(MessageRef::read_as_root(&data).unwrap()).unwrap();
+assert_eq!(
+    message,
+    Message {
+        sequence: 2,
+        retries: 3,
+        priority: Some(-1),
+        ratio: 1.5,
+        urgent: false,
+        status: Status::Pending,
+        sent_at: Some(Timestamp {
+            seconds: 300,
+            nanos: 400,
+        }),
+        payload: Some("hello".into()),
+    }
+);
+
+// Invalid buffers give an error instead of a view
+assert!(MessageMut::read_as_root(&mut [0xff; 8]).is_err());
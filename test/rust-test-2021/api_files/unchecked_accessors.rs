@@ -0,0 +1,82 @@
+use planus::ReadAsRoot;
+
+let scene = Scene {
+    name: "scene".into(),
+    size: Some(Size {
+        width: 640,
+        height: 480,
+    }),
+    visible: false,
+    data: Some(vec![1, 2, 3]),
+    points: Some(vec![
+        Point {
+            x: 1,
+            y: 2,
+            label: Some("a".into()),
+        },
+        Point {
+            x: 3,
+            y: 5,
+            label: None,
+        },
+    ]),
+    origin: None,
+    shape: Some(Shape::Point(Box::new(Point {
+        x: 7,
+        y: 8,
+        label: None,
+    }))),
+    weights: Some(vec![0.5, 1.5]),
+};
+let mut builder = planus::Builder::new();
+let data = builder.finish(&scene, None);
+
+let scene_ref = SceneRef::read_as_root_verified(data).unwrap();
+// SAFETY: the buffer was verified above
+unsafe {
+    assert_eq!(scene_ref.name_unchecked(), "scene");
+    assert!(scene_ref.origin_unchecked().is_none());
+    let size = scene_ref.size_unchecked().unwrap();
+    assert_eq!((size.width(), size.height()), (640, 480));
+    assert!(!scene_ref.visible_unchecked());
+    assert_eq!(scene_ref.data_unchecked(), Some(&[1, 2, 3][..]));
+    let weights: Vec<f32> = scene_ref.weights_unchecked().unwrap().iter().collect();
+    assert_eq!(weights, vec![0.5, 1.5]);
+
+    let points = scene_ref.points_unchecked().unwrap();
+    assert_eq!(points.iter_unchecked().len(), 2);
+    let sum: i32 = points
+        .iter_unchecked()
+        .map(|point| point.x_unchecked() + point.y_unchecked())
+        .sum();
+    assert_eq!(sum, 11);
+    let labels: Vec<Option<&str>> = points
+        .iter_unchecked()
+        .rev()
+        .map(|point| point.label_unchecked())
+        .collect();
+    assert_eq!(labels, vec![None, Some("a")]);
+
+    let ShapeRef::Point(point) = scene_ref.shape_unchecked().unwrap();
+    assert_eq!((point.x_unchecked(), point.y_unchecked()), (7, 8));
+}
+
+// The checked accessors return the same values
+assert_eq!(scene_ref.name().unwrap(), "scene");
+assert_eq!(Scene::try_from(scene_ref).unwrap(), scene);
+
+let mut builder = planus::Builder::new();
+let data = builder.finish(
+    Collision {
+        a: 1,
+        a_unchecked: 2,
+    },
+    None,
+);
+let collision = CollisionRef::read_as_root_verified(data).unwrap();
+assert_eq!(collision.a_unchecked().unwrap(), 2);
+// SAFETY: the buffer was verified above
+unsafe {
+    assert_eq!(collision.a_unchecked_(), 1);
+    assert_eq!(collision.a_unchecked_unchecked(), 2);
+}
@@ -0,0 +1,38 @@
+use planus::ReadAsRoot;
+
+let mut builder = planus::Builder::new();
+let numbers = builder.create_vector_from_iter((1..5u32).map(|i| u64::from(i * i)));
+let points = builder.create_vector_from_iter((0..3).map(|i| Point { x: i, y: -i }));
+let names = builder.create_vector_from_iter((0..3).map(|i| format!("item {i}")));
+let items = builder.create_vector_from_iter((0..2).map(|i| Item {
+    name: Some(format!("item {i}")),
+}));
+let shapes = builder.create_union_vector_from_iter((0..4).map(|i| {
+    if i % 2 == 0 {
+        Shape::Point(Point { x: i, y: i })
+    } else {
+        Shape::Item(Box::new(Item {
+            name: Some(format!("item {i}")),
+        }))
+    }
+}));
+let root = Root::create(&mut builder, numbers, points, names, items, shapes);
+let data = builder.finish(root, None).to_vec();
+
+// The result is the same as when writing the vectors from slices
+let root = Root::try_from(RootRef::read_as_root(&data).unwrap()).unwrap();
+assert_eq!(root.numbers.as_deref(), Some(&[1, 4, 9, 16][..]));
+assert_eq!(root.points.as_ref().unwrap()[2], Point { x: 2, y: -2 });
+assert_eq!(root.names.as_ref().unwrap()[1], "item 1");
+assert_eq!(root.items.as_ref().unwrap().len(), 2);
+assert_eq!(root.shapes.as_ref().unwrap().len(), 4);
+let mut builder = planus::Builder::new();
+assert_eq!(builder.finish(&root, None), data);
+
+// Empty iterators produce empty vectors
+let mut builder = planus::Builder::new();
+let numbers = builder.create_vector_from_iter(core::iter::empty::<u64>());
+let root = Root::create(&mut builder, numbers, (), (), (), ());
+let data = builder.finish(root, None).to_vec();
+let root = RootRef::read_as_root(&data).unwrap();
+assert!(root.numbers().unwrap().unwrap().is_empty());
@@ -0,0 +1,133 @@
+use planus::{errors::ErrorKind, ReadAsRoot, Verifier, VerifierOptions, Verify};
+
+fn chain(depth: usize) -> Node {
+    let mut node = Node {
+        name: Some("leaf".into()),
+        color: Color::Blue,
+        points: Some(vec![Point {
+            x: 0x1234_5678,
+            color: Color::Green,
+        }]),
+        children: None,
+        payload: Some(Payload::Leaf(Box::new(Leaf {
+            name: Some("payload".into()),
+        }))),
+        label: None,
+    };
+    for _ in 1..depth {
+        node = Node {
+            name: None,
+            color: Color::Red,
+            points: None,
+            children: Some(vec![node]),
+            payload: None,
+            label: None,
+        };
+    }
+    node
+}
+
+let mut builder = planus::Builder::new();
+let data = builder.finish(chain(4), None).to_vec();
+
+let root = NodeRef::read_as_root_verified(&data).unwrap();
+assert_eq!(Node::try_from(root).unwrap(), chain(4));
+
+let mut verifier = Verifier::default();
+NodeRef::read_as_root(&data)
+    .unwrap()
+    .verify(&mut verifier)
+    .unwrap();
+assert_eq!(verifier.tables_visited(), 5);
+assert!(verifier.bytes_visited() > 0);
+
+let mut options = VerifierOptions::default();
+options.max_depth = 3;
+let error = NodeRef::read_as_root_verified_with_options(&data, options).unwrap_err();
+assert!(matches!(
+    error.error_kind,
+    ErrorKind::DepthLimitExceeded { limit: 3 }
+));
+assert_eq!(error.source_location.type_, "Node");
+assert_eq!(error.source_location.method, "verify");
+
+let mut options = VerifierOptions::default();
+options.max_tables = 4;
+let error = NodeRef::read_as_root_verified_with_options(&data, options).unwrap_err();
+assert!(matches!(
+    error.error_kind,
+    ErrorKind::TableLimitExceeded { limit: 4 }
+));
+
+let mut options = VerifierOptions::default();
+options.max_bytes = 16;
+let error = NodeRef::read_as_root_verified_with_options(&data, options).unwrap_err();
+assert!(matches!(
+    error.error_kind,
+    ErrorKind::ByteLimitExceeded { limit: 16 }
+));
+
+// Errors in a table after a nested table are reported at the outer table
+let mut root = chain(2);
+root.label = Some("label".into());
+let data_with_label = planus::Builder::new().finish(&root, None).to_vec();
+let mut verifier = Verifier::default();
+NodeRef::read_as_root(&data_with_label)
+    .unwrap()
+    .verify(&mut verifier)
+    .unwrap();
+let mut options = VerifierOptions::default();
+options.max_bytes = verifier.bytes_visited() - 1;
+let error = NodeRef::read_as_root_verified_with_options(&data_with_label, options).unwrap_err();
+assert!(matches!(
+    error.error_kind,
+    ErrorKind::ByteLimitExceeded { .. }
+));
+assert_eq!(error.source_location.type_, "str");
+let root_offset = u32::from_le_bytes(data_with_label[..4].try_into().unwrap());
+assert_eq!(error.source_location.byte_offset, root_offset as usize);
+
+// The depth is restored when verification fails, so the verifier can be
+// used for other tables afterwards
+let mut options = VerifierOptions::default();
+options.max_depth = 3;
+let mut verifier = Verifier::new(options);
+let error = NodeRef::read_as_root(&data)
+    .unwrap()
+    .verify(&mut verifier)
+    .unwrap_err();
+assert!(matches!(
+    error.error_kind,
+    ErrorKind::DepthLimitExceeded { .. }
+));
+NodeRef::read_as_root(&data_with_label)
+    .unwrap()
+    .verify(&mut verifier)
+    .unwrap();
+
+// A corrupted string deep inside the buffer is only noticed by the verifier
+let mut corrupted = data.clone();
+let position = corrupted
+    .windows(7)
+    .position(|window| window == b"payload")
+    .unwrap();
+corrupted[position] = 0xff;
+assert!(NodeRef::read_as_root(&corrupted).is_ok());
+let error = NodeRef::read_as_root_verified(&corrupted).unwrap_err();
+assert!(matches!(error.error_kind, ErrorKind::InvalidUtf8 { .. }));
+assert_eq!(error.source_location.type_, "Leaf");
+assert_eq!(error.source_location.method, "name");
+
+// So is an invalid enum value inside a struct
+let mut corrupted = data.clone();
+let point_offset = corrupted
+    .windows(4)
+    .position(|window| window == 0x1234_5678i32.to_le_bytes())
+    .unwrap();
+corrupted[point_offset + 4] = 42;
+assert!(NodeRef::read_as_root(&corrupted).is_ok());
+let error = NodeRef::read_as_root_verified(&corrupted).unwrap_err();
+assert!(matches!(error.error_kind, ErrorKind::UnknownEnumTag { .. }));
+
+// Truncated buffers are rejected
+assert!(NodeRef::read_as_root_verified(&data[..data.len() / 2]).is_err());
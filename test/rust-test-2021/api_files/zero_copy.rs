@@ -0,0 +1,75 @@
+use planus::ReadAsRoot;
+
+// Copies `data` to an address aligned to 16 bytes, with `shift` bytes of
+// extra offset
+fn aligned_copy<'a>(data: &[u8], shift: usize, storage: &'a mut Vec<u128>) -> &'a [u8] {
+    storage.clear();
+    storage.resize(data.len().div_ceil(16) + 1, 0);
+    // SAFETY: the storage has room for `shift + data.len()` bytes
+    let bytes = unsafe {
+        core::slice::from_raw_parts_mut(storage.as_mut_ptr().cast::<u8>(), shift + data.len())
+    };
+    bytes[shift..].copy_from_slice(data);
+    &bytes[shift..]
+}
+
+assert_eq!(core::mem::size_of::<Sample>(), 16);
+assert_eq!(core::mem::size_of::<Pair>(), 24);
+assert_eq!(core::mem::align_of::<Aligned>(), 16);
+
+let sample = |i: u16| Sample {
+    time: i as f64 * 0.5,
+    value: i as f32,
+    channel: i,
+};
+let series = Series {
+    values: Some(vec![0.5, 1.5, 2.5]),
+    counts: Some(vec![-1, 2, -3, 4]),
+    samples: Some((0..5).map(sample).collect()),
+    pairs: Some(vec![Pair {
+        first: -7,
+        sample: sample(9),
+    }]),
+    aligned: Some(vec![Aligned { x: 1 }, Aligned { x: 2 }]),
+};
+let mut builder = planus::Builder::new();
+let data = builder.finish(&series, None);
+
+let mut storage = Vec::new();
+let buffer = aligned_copy(data, 0, &mut storage);
+let series_ref = SeriesRef::read_as_root(buffer).unwrap();
+if cfg!(target_endian = "little") {
+    let values = series_ref.values().unwrap().unwrap().as_slice().unwrap();
+    assert_eq!(values, &[0.5, 1.5, 2.5]);
+    let counts = series_ref.counts().unwrap().unwrap().as_slice().unwrap();
+    assert_eq!(counts, &[-1, 2, -3, 4]);
+    let samples = series_ref.samples().unwrap().unwrap();
+    assert_eq!(samples.as_slice().unwrap(), series.samples.as_deref().unwrap());
+    assert_eq!(samples.get(1..3).unwrap().as_slice().unwrap(), &series.samples.as_deref().unwrap()[1..3]);
+    let pairs = series_ref.pairs().unwrap().unwrap().as_slice().unwrap();
+    assert_eq!(pairs, series.pairs.as_deref().unwrap());
+    let aligned = series_ref.aligned().unwrap().unwrap().as_slice().unwrap();
+    assert_eq!(aligned, series.aligned.as_deref().unwrap());
+} else {
+    assert!(series_ref.values().unwrap().unwrap().as_slice().is_none());
+}
+
+// Empty vectors always give a slice
+let empty = Series {
+    values: Some(Vec::new()),
+    ..series.clone()
+};
+let mut builder = planus::Builder::new();
+let data = builder.finish(&empty, None);
+let series_ref = SeriesRef::read_as_root(aligned_copy(data, 1, &mut storage)).unwrap();
+assert_eq!(series_ref.values().unwrap().unwrap().as_slice(), Some(&[][..]));
+
+// Misaligned data gives `None` instead of a slice
+let data = builder.finish(&series, None);
+let series_ref = SeriesRef::read_as_root(aligned_copy(data, 1, &mut storage)).unwrap();
+assert!(series_ref.values().unwrap().unwrap().as_slice().is_none());
+assert!(series_ref.samples().unwrap().unwrap().as_slice().is_none());
+assert_eq!(
+    series_ref.samples().unwrap().unwrap().to_vec::<Sample>().unwrap(),
+    series.samples.unwrap()
+);
@@ -128,7 +128,14 @@ fn generate_test_code(
             let Some(declarations) = planus_translation::translate_files(&[&file_path]) else {
                 bail!("Cannot translate code for {}", file_path.display())
             };
-            let code = planus_codegen::generate_rust(&declarations, true)
+            // Schemas whose name starts with `open_enums`, `open_unions` or
+            // `no_alloc` test the codegen option of the same name
+            let options = planus_codegen::RustOptions {
+                open_enums: file_stem.starts_with("open_enums"),
+                open_unions: file_stem.starts_with("open_unions"),
+                no_alloc: file_stem.starts_with("no_alloc"),
+            };
+            let code = planus_codegen::generate_rust_with_options(&declarations, true, options)
                 .wrap_err_with(|| eyre!("Cannot codegen for {}", file_path.display()))?;
             std::fs::write(&generated_full_path, code)
                 .wrap_err_with(|| eyre!("Cannot write output to {}", generated_full_path))?;